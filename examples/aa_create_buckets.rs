@@ -40,9 +40,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .execute()
                 .await
             {
-                Ok(Ok(_)) => println!("create {}@{} is success", &bucket, &region),
-                Ok(Err(error)) => println!("{:#?}", error.content()),
-                Err(error) => println!("reqwest error: {}", error),
+                Ok(_) => println!("create {}@{} is success", &bucket, &region),
+                Err(oss::Error::Service(error)) => println!("{:#?}", error.content()),
+                Err(error) => println!("error: {}", error),
             }
         }
     });
@@ -4,7 +4,7 @@
 //! 并使用异步编程模型来提高效率。
 //!
 //! 以下是代码的主要逻辑：
-//! 
+//!
 //! 1. 导入所需的库和模块。
 //! 2. 定义了一个异步函数 del_all()，用于删除指定存储桶中的所有对象。
 //! 3. 在 main() 函数中，先打印警告信息，然后提示用户是否继续操作。
@@ -26,15 +26,7 @@ async fn del_all() -> Result<(), Box<dyn std::error::Error>> {
     let options = util::options_from_env();
     let client = Arc::new(oss::Client::new(options));
     loop {
-        match client
-            .ListObjectsV2()
-            .with_max_keys(5)
-            .execute()
-            .await
-            .unwrap_or_else(|error| {
-                println!("reqwest error: {}", error);
-                process::exit(-1);
-            }) {
+        match client.ListObjectsV2().with_max_keys(5).execute().await {
             Ok(data) => {
                 let data = data.content();
                 if let Some(objects) = data.contents {
@@ -43,9 +35,11 @@ async fn del_all() -> Result<(), Box<dyn std::error::Error>> {
                         async move {
                             let result = client.DeleteObject(object.key.as_str()).execute().await;
                             match result {
-                                Ok(Ok(_)) => println!("delete object key: {}", object.key),
-                                Ok(Err(message)) => println!("{}", message.content()),
-                                Err(error) => println!("reqwest error: {}", error),
+                                Ok(_) => println!("delete object key: {}", object.key),
+                                Err(oss::Error::Service(message)) => {
+                                    println!("{}", message.content())
+                                }
+                                Err(error) => println!("error: {}", error),
                             }
                         }
                     });
@@ -55,9 +49,13 @@ async fn del_all() -> Result<(), Box<dyn std::error::Error>> {
                     return Ok(());
                 }
             }
-            Err(message) => {
+            Err(oss::Error::Service(message)) => {
                 println!("oss error: {}", message.content())
             }
+            Err(error) => {
+                println!("error: {}", error);
+                process::exit(-1);
+            }
         }
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! 删除`xtoss-`开头的测试`bucket``
 
-//! 这段代码是一个示例程序，用于删除所有以 xtoss- 开头的测试存储桶（bucket）。它演示了如何使用 
+//! 这段代码是一个示例程序，用于删除所有以 xtoss- 开头的测试存储桶（bucket）。它演示了如何使用
 //! Rust 语言与 OSS 进行存储桶管理操作。
 //!
 //! 以下是代码的主要逻辑：
@@ -28,7 +28,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_prefix("xtoss-")
         .execute()
         .await?
-        .unwrap()
         .content();
 
     if let Some(bucktes) = all_buckets.buckets.bucket {
@@ -38,14 +37,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .with_bucket(&bucket.name)
                 .with_region(&bucket.location)
                 .execute()
-                .await
-                .unwrap_or_else(|error| {
-                    println!("reqwest error: {}", error);
-                    std::process::exit(-1);
-                });
+                .await;
             match result {
                 Ok(_) => println!("delete {} {}", &bucket.location, &bucket.name),
-                Err(error) => println!("{}", error.content()),
+                Err(oss::Error::Service(error)) => println!("{}", error.content()),
+                Err(error) => {
+                    println!("error: {}", error);
+                    std::process::exit(-1);
+                }
             }
         }
     } else {
@@ -1,6 +1,6 @@
 //! `cargo run --example aa_get_big_object -q`
 //!
-//! 这段代码是一个示例程序，用于从 OSS 下载大文件，并将其保存到本地。它演示了如何使用 Rust 语言与 OSS 
+//! 这段代码是一个示例程序，用于从 OSS 下载大文件，并将其保存到本地。它演示了如何使用 Rust 语言与 OSS
 //! 进行文件的分段下载，以及如何将下载的文件分段保存到本地。
 //!
 //! > 以下是代码的主要逻辑：
@@ -46,13 +46,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 分段size
     let chunk_size = 1024 * 1024;
-    let size = if let Ok(data) = client.GetObjectMeta(object).execute().await? {
+    let size = if let Ok(data) = client.GetObjectMeta(object).execute().await {
         // 从header获取目标文件的size
         data.content_length()
     } else {
         None
     };
-    assert!(size.is_some(), "Failed to retrieve file size, perhaps the file does not exist.");
+    assert!(
+        size.is_some(),
+        "Failed to retrieve file size, perhaps the file does not exist."
+    );
     println!(
         " - total file size: {:.2} MB",
         size.unwrap() as f64 / chunk_size as f64
@@ -68,10 +71,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .with_range(byte_range.clone())
             .execute()
             .await
-            .unwrap_or_else(|error| {
-                println!("reqwest error: {}", error);
-                process::exit(-1);
-            }) {
+        {
             Ok(data) => {
                 let rate = format!(
                     " - complete {:5.2}%",
@@ -86,7 +86,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
                 bytes.extend(data.content())
             }
-            Err(message) => println!("oss error: {}", message.content()),
+            Err(oss::Error::Service(message)) => println!("oss error: {}", message.content()),
+            Err(error) => {
+                println!("error: {}", error);
+                process::exit(-1);
+            }
         }
     }
 
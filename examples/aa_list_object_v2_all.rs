@@ -37,10 +37,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .with_continuation_token(token.as_deref())
             .execute()
             .await
-            .unwrap_or_else(|error| {
-                println!("reqwest error: {}", error);
-                process::exit(-1);
-            }) {
+        {
             Ok(data) => {
                 let objects = data.content();
                 if objects.key_count == Some(0) {
@@ -56,11 +53,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 count = count + objects.key_count.unwrap();
                 page = page + 1;
             }
-            Err(message) => {
+            Err(oss::Error::Service(message)) => {
                 println!("oss error {}:", message.url());
                 println!("oss error {:#?}:", message.content());
                 break;
             }
+            Err(error) => {
+                println!("error: {}", error);
+                process::exit(-1);
+            }
         }
         if token.is_none() {
             break;
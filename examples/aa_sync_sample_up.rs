@@ -12,7 +12,7 @@
 //! 4. 遍历示例文件目录下的所有文件，并过滤出文件。
 //! 5. 为每个文件构建上传请求，并执行上传操作。
 //! 6. 处理上传结果，如果成功则打印上传成功的消息，如果失败则打印错误信息。
-//! 代码中使用了 `WalkDir` 库来遍历目录，并使用 `mime_guess` 库来推断文件的 MIME 类型。在上传文件时，使用了各种 
+//! 代码中使用了 `WalkDir` 库来遍历目录，并使用 `mime_guess` 库来推断文件的 MIME 类型。在上传文件时，使用了各种
 //! OSS 参数设置，如禁止覆盖、设置内容编码、缓存控制、内容类型等。此外，还设置了对象的元数据、加密方式、过期时间以及标签。
 //! 整体来说，这段代码是一个完整的示例，展示了如何使用 Rust 语言与 OSS 进行文件上传操作，并对上传过程中的错误进行了简单
 //! 的处理和打印。
@@ -96,9 +96,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .execute()
             .await
         {
-            Ok(Ok(_)) => (),
-            Ok(Err(message)) => println!("oss error: {}", message.content()),
-            Err(error) => println!("reqwest error: {}", error),
+            Ok(_) => (),
+            Err(oss::Error::Service(message)) => println!("oss error: {}", message.content()),
+            Err(error) => println!("error: {}", error),
         }
     }
 
@@ -1,6 +1,6 @@
 //! `cargo run --example aa_sync_sample_down -q`
 //!
-//! 这段代码是一个示例程序，用于从 OSS（对象存储服务）中下载文件到本地目录。代码的逻辑是不断地向 OSS 
+//! 这段代码是一个示例程序，用于从 OSS（对象存储服务）中下载文件到本地目录。代码的逻辑是不断地向 OSS
 //! 发送请求，获取文件列表，并逐个下载文件到本地目录。
 //!
 //! 以下是代码的主要逻辑：
@@ -47,10 +47,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .with_continuation_token(token.as_deref())
             .execute()
             .await
-            .unwrap_or_else(|error| {
-                println!("reqwest error: {}", error);
-                std::process::exit(-1);
-            }) {
+        {
             Ok(data) => {
                 let objects = data.content();
                 if let Some(0) = objects.key_count {
@@ -60,7 +57,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     for object in objects.contents.unwrap() {
                         // 下载文件
                         match client.GetObject(&object.key).execute().await {
-                            Ok(Ok(data)) => {
+                            Ok(data) => {
                                 let target_file = down_dir.clone().join(&object.key);
                                 if let Some(dirname) = target_file.parent() {
                                     if !dirname.is_dir() {
@@ -72,16 +69,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 file.write_all(&data.content())?;
                                 println!("down file: {}", &object.key);
                             }
-                            Ok(Err(message)) => println!("oss error: {}", message.content()),
-                            Err(error) => println!("reqwest oss: {}", error),
+                            Err(oss::Error::Service(message)) => {
+                                println!("oss error: {}", message.content())
+                            }
+                            Err(error) => println!("error: {}", error),
                         }
                     }
                 }
             }
-            Err(message) => {
+            Err(oss::Error::Service(message)) => {
                 println!("oss error {:#?}:", message.content());
                 break;
             }
+            Err(error) => {
+                println!("error: {}", error);
+                std::process::exit(-1);
+            }
         }
         if token.is_none() {
             break;
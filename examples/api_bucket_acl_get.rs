@@ -16,22 +16,19 @@ async fn main() {
     let options = util::options_from_env();
     let client = oss::Client::new(options);
 
-    match client
-        .GetBucketAcl()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        }) {
+    match client.GetBucketAcl().execute().await {
         Ok(oss_data) => {
             println!(
                 "{}",
                 serde_json::to_string_pretty(&oss_data.content()).unwrap()
             );
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 }
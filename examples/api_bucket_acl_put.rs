@@ -14,19 +14,16 @@ async fn main() {
     let options = util::options_from_env();
     let client = oss::Client::new(options);
 
-    match client
-        .PutBucketAcl(OssAcl::PublicRead)
-        .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("{}", error);
-            process::exit(-1);
-        }) {
+    match client.PutBucketAcl(OssAcl::PublicRead).execute().await {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             println!("{:#?}", message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
 }
@@ -15,21 +15,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = oss::Client::new(options);
     // 测试域名改成自己的域名
     let cname_domain = "xtoss-web.example.com";
-    let result = client
-        .DeleteCname(cname_domain)
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.DeleteCname(cname_domain).execute().await;
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -13,14 +13,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    let result = client
-        .ListCname()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.ListCname().execute().await;
     match result {
         Ok(oss_data) => {
             println!(
@@ -28,9 +21,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 serde_json::to_string_pretty(&oss_data.content()).unwrap()
             );
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("oss error: {}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -28,22 +28,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_domain(cname_domain)
         .build();
 
-    let result = client
-        .PutCname()
-        .with_config(config)
-        .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+    let result = client.PutCname().with_config(config).execute().await;
     match result {
         Ok(data) => {
             println!("{:#?}", data.content())
         }
-        Err(error) => {
+        Err(oss::Error::Service(error)) => {
             println!("{:#?}", error.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_encryption_del -q`
-//! 
+//!
 //! DeleteBucketEncryption接口用于删除Bucket加密规则。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketencryption)
@@ -13,22 +13,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    let result = client
-        .DeleteBucketEncryption()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.DeleteBucketEncryption().execute().await;
 
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 
     Ok(())
@@ -19,19 +19,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_data_encryption("SM4")
         // .with_master_key_id("--your value --")
         .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
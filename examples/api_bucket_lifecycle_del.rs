@@ -17,20 +17,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let options = util::options_from_env();
     let client = oss::Client::new(options);
 
-    match client
-        .DeleteBucketLifecycle()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    match client.DeleteBucketLifecycle().execute().await {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.headers())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
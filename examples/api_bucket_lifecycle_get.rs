@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_lifecycle_get -q`
-//! 
+//!
 //! 调用GetBucketLifecycle接口查看存储空间`Bucket`的生命周期规则`Lifecycle`。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketlifecycle)
@@ -14,20 +14,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let options = util::options_from_env();
     let client = oss::Client::new(options);
 
-    match client
-        .GetBucketLifecycle()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    match client.GetBucketLifecycle().execute().await {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
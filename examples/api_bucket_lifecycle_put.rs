@@ -51,16 +51,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_config(config)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("{}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             println!("{:#?}", message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
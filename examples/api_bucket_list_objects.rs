@@ -20,19 +20,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_prefix("course/video")
         // .with_marker("course/video/00518bfd279de57ea6a8b26a5af2c0fc/content.mp4")
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(data) => {
             println!("{:#?}", data.content())
         }
-        Err(error) => {
+        Err(oss::Error::Service(error)) => {
             println!("{:#?}", error.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
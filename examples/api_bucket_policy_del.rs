@@ -1,4 +1,4 @@
-//! cargo run --package xt-oss --example api_bucket_policy_del 
+//! cargo run --package xt-oss --example api_bucket_policy_del
 //!
 //! DeleteBucketPolicy用于删除指定存储空间`Bucket`的权限策略`Policy`。
 //!
@@ -13,22 +13,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    let result = client
-        .DeleteBucketPolicy()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.DeleteBucketPolicy().execute().await;
 
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
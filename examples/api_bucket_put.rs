@@ -26,19 +26,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_storage_class(StorageClass::Standard)
         // .with_data_redundancy_type(DataRedundancyType::LRS)
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(error) => {
+        Err(oss::Error::Service(error)) => {
             println!("{:#?}", error)
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
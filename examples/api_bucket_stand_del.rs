@@ -18,19 +18,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_region("oss-cn-beijing")
         // .with_bucket("xtoss-t1")
         .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
         }
-        Err(oss_error) => {
+        Err(oss::Error::Service(oss_error)) => {
             println!("oss error:{}", oss_error.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
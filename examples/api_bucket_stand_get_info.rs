@@ -19,11 +19,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .GetBucketInfo()
         // .with_bucket("xtoss-ex1")
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(oss_data) => {
@@ -32,9 +28,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 serde_json::to_string_pretty(&oss_data.content()).unwrap()
             );
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
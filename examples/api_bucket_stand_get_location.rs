@@ -18,21 +18,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .GetBucketLocation()
         .with_bucket("xtoss-ex11")
         .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(oss_data) => {
             let LocationConstraint(location) = oss_data.content();
             println!("location: {}", location);
         }
-        Err(oss_error) => {
+        Err(oss::Error::Service(oss_error)) => {
             println!("{}", oss_error.url());
             println!("{:#?}", oss_error.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -20,20 +20,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_region("oss-cn-shanghai")
         .with_bucket("xtoss-ex10")
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(data) => {
             println!("{}", serde_json::to_string_pretty(&data.content()).unwrap());
         }
-        Err(error) => {
+        Err(oss::Error::Service(error)) => {
             println!("{}", error.url());
             println!("{:#?}", error.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -1,4 +1,4 @@
-//! cargo run --package xt-oss --example api_bucket_stand_list_object 
+//! cargo run --package xt-oss --example api_bucket_stand_list_object
 //!
 //! GetBucket (ListObjects)接口用于列举存储空间`Bucket`中所有文件
 //! `Object`的信息。
@@ -23,15 +23,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_prefix("prefix")
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            eprint!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             let result = oss_data.content();
             println!("{}", serde_json::to_string_pretty(&result).unwrap());
         }
-        Err(error_message) => println!("oss error: {}", error_message.content()),
+        Err(oss::Error::Service(error_message)) => {
+            println!("oss error: {}", error_message.content())
+        }
+        Err(error) => {
+            eprint!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -7,10 +7,7 @@
 //! - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_list_object_v2.rs)
 use dotenv;
 use std::process;
-use xt_oss::{
-    prelude::*,
-    util,
-};
+use xt_oss::{prelude::*, util};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -24,17 +21,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_delimiter("/")
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             let result = oss_data.content();
             println!("{:#?}", result);
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("oss error message: {}", oss_error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -26,10 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_storage_class(StorageClass::Standard)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             let location = oss_data
                 .headers()
@@ -39,9 +36,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .unwrap();
             println!("location: {}", location);
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("oss error message: {}", oss_error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
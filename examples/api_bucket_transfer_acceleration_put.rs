@@ -18,16 +18,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .PutBucketTransferAcceleration(false)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(_) => {
             println!("success")
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
@@ -16,10 +16,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .GetBucketVersioning()
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             let version_config = oss_data.content();
             if let Some(status) = version_config.status {
@@ -28,9 +25,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("Version feature not enabled");
             }
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
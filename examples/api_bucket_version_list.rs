@@ -21,10 +21,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_version_id_marker(value)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(data) => {
             let version_objects = data.content();
             // println!("{:#?}", version_objects);
@@ -33,9 +30,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 serde_json::to_string_pretty(&version_objects).unwrap()
             );
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
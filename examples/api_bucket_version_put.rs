@@ -16,16 +16,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .PutBucketVersioning(VersioningStatus::Suspended)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(_) => {
             println!("success")
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
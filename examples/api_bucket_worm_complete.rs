@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_worm_complete -q`
-//! 
+//!
 //! CompleteBucketWorm用于锁定合规保留策略。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/completebucketworm)
@@ -17,16 +17,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .CompleteBucketWorm("933141599A8941FD9592F24F9862A5DE")
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content());
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
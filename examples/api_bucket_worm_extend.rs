@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_worm_extend -q`
-//! 
+//!
 //! ExtendBucketWorm用于延长已锁定的合规保留策略对应Bucket中Object的保留天数。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/extendbucketworm)
@@ -18,16 +18,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_days(2)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
         }
-        Err(oss_error_message) => {
+        Err(oss::Error::Service(oss_error_message)) => {
             println!("{:#?}", oss_error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     };
     Ok(())
 }
@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_worm_get -q`
-//! 
+//!
 //! GetBucketWorm用于获取指定存储空间`Bucket`的合规保留策略信息。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketworm)
@@ -13,21 +13,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    let result = client
-        .GetBucketWorm()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.GetBucketWorm().execute().await;
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content());
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("oss error: {}", error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 
     Ok(())
@@ -1,5 +1,5 @@
 //! `cargo run --example api_bucket_worm_init -q`
-//! 
+//!
 //! 调用InitiateBucketWorm接口新建一条合规保留策略。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/initiatebucketworm)
@@ -13,22 +13,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    let result = client
-        .InitiateBucketWorm()
-        .with_days(1)
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest {}", reqwest_error);
-            process::exit(-1);
-        });
+    let result = client.InitiateBucketWorm().with_days(1).execute().await;
     match result {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("oss error: {}", error_message.content());
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 
     Ok(())
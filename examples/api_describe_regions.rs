@@ -1,5 +1,5 @@
 //! `cargo run --example api_describe_regions -q`
-//! 
+//!
 //! 调用DescribeRegions接口查询所有支持地域或者指定地域对应的Endpoint信息，
 //! 包括外网Endpoint、内网Endpoint和传输加速Endpoint。
 //!
@@ -19,10 +19,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_region("oss-us-east-1")
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqweset error: {}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             oss_data
                 .content()
@@ -32,7 +29,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("{:>20} | {}", entry.region, entry.internet_endpoint);
                 });
         }
-        Err(oss_error_message) => println!("oss error: {}", oss_error_message.content()),
+        Err(oss::Error::Service(oss_error_message)) => {
+            println!("oss error: {}", oss_error_message.content())
+        }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -1,5 +1,5 @@
 //! `cargo run --example api_object_acl_get -q`
-//! 
+//!
 //! 调用GetObjectACL接口获取存储空间`Bucket`下某个文件`Object`的访问权限`ACL`。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobjectacl)
@@ -19,16 +19,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_version_id("CAEQmgEYgYDA9I_smO0YIiBhOGJmMTczNzY0ZmM0NTE1YTA5MDJlOWE1YmI1ZTZlNQ--")
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("{}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
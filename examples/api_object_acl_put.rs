@@ -1,5 +1,5 @@
 //! `cargo run --example api_object_acl_put -q`
-//! 
+//!
 //! 调用PutObjectACL接口修改文件`Object`的访问权限`ACL`。
 //! 此操作只有Bucket Owner有权限执行,且需对Object有读写权限。
 //!
@@ -21,16 +21,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_version_id("CAEQmgEYgYDA9I_smO0YIiBhOGJmMTczNzY0ZmM0NTE1YTA5MDJlOWE1YmI1ZTZlNQ--")
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            println!("{}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             println!("{:#?}", message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
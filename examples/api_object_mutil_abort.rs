@@ -1,5 +1,5 @@
 //! `cargo run --example api_object_mutil_abort -q`
-//! 
+//!
 //! AbortMultipartUpload接口用于取消MultipartUpload事件并删除对应的Part数据。
 //!
 //! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload)
@@ -17,16 +17,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_upload_id("EC83F9BA90DB4636BB26ECEAE205D6A8")
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            eprintln!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -22,16 +22,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_upload_id(upload_id)
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            eprintln!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
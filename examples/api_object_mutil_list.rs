@@ -14,20 +14,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
     let options = util::options_from_env();
     let client = oss::Client::new(options);
-    match client
-        .ListMultipartUploads()
-        .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            eprintln!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    match client.ListMultipartUploads().execute().await {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
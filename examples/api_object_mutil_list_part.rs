@@ -18,16 +18,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_upload_id("E71E2C09F952430F93700A3167F74685")
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            eprintln!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.content())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
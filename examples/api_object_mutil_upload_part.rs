@@ -45,11 +45,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .execute()
         .await
         .unwrap_or_else(|error| {
-            eprintln!("reqwest error: {}", error);
-            process::exit(-1);
-        })
-        .unwrap_or_else(|message| {
-            eprintln!("oss error: {}", message.content());
+            eprintln!("{}", error);
             process::exit(-1);
         })
         .content()
@@ -73,7 +69,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .with_content(content)
             .execute()
             .await
-            .unwrap()
             .unwrap();
         println!("part {:#?}", result.headers())
     }
@@ -86,8 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .execute()
         .await
     {
-        Ok(Ok(data)) => println!("{:#?}", data.content()),
-        Ok(Err(message)) => println!("{:#?}", message.content()),
+        Ok(data) => println!("{:#?}", data.content()),
+        Err(oss::Error::Service(message)) => println!("{:#?}", message.content()),
         Err(error) => println!("{}", error),
     }
 
@@ -0,0 +1,41 @@
+//! `cargo run --example api_object_post_policy -q`
+//!
+//! 生成PostObject表单上传所需的Policy、签名与表单域,
+//! 浏览器通过HTML表单直接上传文件`Object`到Bucket
+//!
+//! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/postobject)
+//! - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_post_policy.rs)
+use dotenv;
+use xt_oss::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    let options = util::options_from_env();
+    let client = oss::Client::new(options);
+
+    let form = client
+        .PostObjectPolicy()
+        .with_expires(600)
+        .with_key_prefix("upload/")
+        .with_content_length_range(1, 10 * 1024 * 1024)
+        .with_success_action_status(201)
+        .execute()
+        .await
+        .unwrap();
+
+    println!(
+        r#"<form action="{}" method="post" enctype="multipart/form-data">"#,
+        form.url
+    );
+    println!(r#"  <input type="text" name="key" value="upload/test.txt">"#);
+    for (name, value) in &form.fields {
+        println!(
+            r#"  <input type="hidden" name="{}" value="{}">"#,
+            name, value
+        );
+    }
+    println!(r#"  <input type="file" name="file">"#);
+    println!(r#"  <input type="submit" value="Upload">"#);
+    println!("</form>");
+}
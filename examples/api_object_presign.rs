@@ -0,0 +1,34 @@
+//! `cargo run --example api_object_presign -q`
+//!
+//! 生成携带签名的url, 可直接交给浏览器或第三方下载、上传文件`Object`
+//!
+//! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/ddd-signatures-to-urls)
+//! - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_presign.rs)
+use dotenv;
+use xt_oss::prelude::*;
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    let options = util::options_from_env();
+    let client = oss::Client::new(options);
+
+    let download_url = client
+        .PresignObject("mp3/Audio_0.4mb.mp3")
+        .with_expires(600)
+        // .with_version_id("version_id")
+        .with_response_content_disposition("attachment;filename=\"audio.mp3\"")
+        .execute()
+        .await
+        .unwrap();
+    println!("GET {}", download_url);
+
+    let upload_url = client
+        .PresignObject("upload/test.txt")
+        .with_method(http::Method::PUT)
+        .with_content_type("text/plain")
+        .execute()
+        .await
+        .unwrap();
+    println!("PUT {}", upload_url);
+}
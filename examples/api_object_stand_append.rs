@@ -13,7 +13,7 @@ use std::{
 use xt_oss::prelude::*;
 
 async fn append_upload<'a>(
-    client: &'a oss::Client<'a>,
+    client: &'a oss::Client,
     object: &'a str,
     position: usize,
     data: oss::Bytes,
@@ -25,18 +25,19 @@ async fn append_upload<'a>(
         .with_content(data)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            eprintln!("reqwest error: {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(data) => {
             let pos = data.headers().get("x-oss-next-append-position").unwrap();
             pos.to_str().unwrap().parse::<usize>().unwrap()
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             eprintln!("iss error: {}", message.content());
             process::exit(-1);
         }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(-1);
+        }
     };
     content_length
 }
@@ -57,16 +58,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let object = "tmp/append_ex.csv";
 
     // 如果object存在先删除
-    if client.HeadObject(object).execute().await?.is_ok() {
+    if client.HeadObject(object).execute().await.is_ok() {
         println!("删除已存在的文件\n");
-        client
-            .DeleteObject(object)
-            .execute()
-            .await?
-            .unwrap_or_else(|error| {
-                eprint!("oss error: {}", error.content());
-                process::exit(-1);
-            });
+        client.DeleteObject(object).execute().await?;
     }
 
     let scv_file = fs::File::open(target_scv_file)?;
@@ -17,15 +17,16 @@ async fn main() {
         .with_version_id("CAEQ2AEYgYCA1v6ot.sYIiBmZjU2NTQwOGEwZDc0MTMyYTU5ZjhlMmUyNGYwMjc3NA--")
         .execute()
         .await
-        .unwrap_or_else(|reqwest_error| {
-            eprintln!("{}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(-1);
+        }
     }
 }
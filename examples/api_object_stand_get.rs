@@ -33,17 +33,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_timeout(120)
         .execute()
         .await
-        .unwrap_or_else(|error| {
-            eprintln!("reqwest error: {}", error);
-            process::exit(-1);
-        }) {
+    {
         Ok(oss_data) => {
             println!("{:#?}", oss_data.headers());
             println!("content len: {}", oss_data.content().len())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -0,0 +1,59 @@
+//! `cargo run --example api_object_stand_get_stream -q`
+//!
+//! 以数据流方式下载对象, 收到响应头后立即返回, 不在内存中缓存整个文件
+//!
+//! - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobject)
+//! - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_get_stream.rs)
+use dotenv;
+use futures::TryStreamExt;
+use std::process;
+use xt_oss::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv::dotenv().ok();
+    let options = util::options_from_env();
+    let client = oss::Client::new(options);
+    let object = "images/JPGImage_30mbmb.jpg";
+
+    // 逐块读取
+    match client
+        .GetObject(object)
+        .with_timeout(600)
+        .execute_stream()
+        .await
+    {
+        Ok(data) => {
+            println!("content length: {:?}", data.content_length());
+            let mut stream = data.content();
+            let mut received = 0;
+            while let Some(chunk) = stream.try_next().await? {
+                received += chunk.len();
+            }
+            println!("received: {}", received);
+        }
+        Err(oss::Error::Service(error_message)) => {
+            println!("{:#?}", error_message.content())
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(-1);
+        }
+    }
+
+    // 直接写入本地文件
+    let save_path = std::env::temp_dir().join("JPGImage_30mbmb.jpg");
+    match client
+        .GetObject(object)
+        .with_timeout(600)
+        .download_to_file(&save_path)
+        .await
+    {
+        Ok(data) => println!("{} bytes saved to {}", data.content(), save_path.display()),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(-1);
+        }
+    }
+    Ok(())
+}
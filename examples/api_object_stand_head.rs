@@ -18,17 +18,17 @@ async fn main() {
         .HeadObject("mp3/Audio_0.4mb.mp3")
         // .with_version_id("version_id")
         .execute()
-        .await
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqwest error: {}", reqwest_error);
-            process::exit(-1);
-        });
+        .await;
     match resp {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", error_message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 }
@@ -22,17 +22,17 @@ async fn main() {
         // .with_none_match(value)
         // .with_unmodified_since(value)
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
     match resp {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             println!("{:#?}", message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 }
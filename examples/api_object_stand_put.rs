@@ -21,11 +21,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let target_file = {
         let mut target_file = env::current_dir()?;
         // ["examples", "samples", "zip", "ZIPFile_10mbmb.zip"]
-        ["assets", "samples", "index.html"]
-            .iter()
-            .for_each(|e| {
-                target_file.push(e);
-            });
+        ["assets", "samples", "index.html"].iter().for_each(|e| {
+            target_file.push(e);
+        });
         target_file.display().to_string()
     };
     // md5值
@@ -55,16 +53,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_oss_tagging(vec![("k100", "v100"), ("k200", "v200")])
         // .with_timeout(120)
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            eprint!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     // 处理响应结果
     match resp {
         Ok(data) => println!("{:#?}", data.headers()),
-        Err(message) => println!("{:#?}", message.content()),
+        Err(oss::Error::Service(message)) => println!("{:#?}", message.content()),
+        Err(error) => {
+            eprint!("error: {}", error);
+            process::exit(-1);
+        }
     }
 
     Ok(())
@@ -17,17 +17,17 @@ async fn main() {
         .RestoreObject("mp3/Audio_0.4mb.mp3")
         .with_days(1)
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
     match resp {
         Ok(data) => {
             println!("{:#?}", data.headers())
         }
-        Err(message) => {
+        Err(oss::Error::Service(message)) => {
             println!("{:#?}", message.content())
         }
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
 }
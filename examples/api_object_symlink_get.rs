@@ -14,19 +14,16 @@ async fn main() {
     let options = util::options_from_env();
     let client = oss::Client::new(options);
 
-    match client
-        .GetSymlink("tmp/test.xls")
-        .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("{}", error);
-            process::exit(-1);
-        }) {
+    match client.GetSymlink("tmp/test.xls").execute().await {
         Ok(oss_data) => {
             println!("{:#?}", &oss_data.headers())
         }
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             println!("{:#?}", &error_message.content())
         }
+        Err(error) => {
+            println!("{}", error);
+            process::exit(-1);
+        }
     }
 }
@@ -18,19 +18,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .execute()
         .await
     {
-        Ok(Ok(data)) => {
+        Ok(data) => {
             // data:ApiData<Tagging>
-            println!("{}", data.request_id());
+            println!("{:?}", data.request_id());
             println!("{:#?}", data.headers());
             println!("{:#?}", data.content());
         }
-        Ok(Err(message)) => {
+        Err(oss::Error::Service(message)) => {
             // message: ApiData<ErrorMessage>
-            println!("{}", message.request_id());
+            println!("{:?}", message.request_id());
             println!("{:#?}", message.headers());
             println!("{:#?}", message.content());
         }
-        Err(reqwest_error) => println!("{}", reqwest_error),
+        Err(error) => println!("{}", error),
     }
     Ok(())
 }
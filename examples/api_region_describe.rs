@@ -7,10 +7,7 @@
 //! - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_describe_regions.rs)
 use dotenv;
 use std::process;
-use xt_oss::{
-    oss::entities::region::RegionInfo,
-    prelude::*,
-};
+use xt_oss::{oss::entities::region::RegionInfo, prelude::*};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -30,11 +27,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // .with_region("oss-us-east-1")
         .execute()
         .await
-        // 处理可能的reqwest错误
-        .unwrap_or_else(|reqwest_error| {
-            println!("reqweset error: {}", reqwest_error);
-            process::exit(-1);
-        }) {
+    {
         // 请求正常返回结果
         Ok(oss_data) => {
             let regions: Vec<RegionInfo> = oss_data.content().region_info;
@@ -43,11 +36,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
         // 请求正常，返回oss错误消息
-        Err(error_message) => {
+        Err(oss::Error::Service(error_message)) => {
             // let message = error_message.content();
-            println!("request id: {}", &error_message.request_id());
+            println!("request id: {:?}", &error_message.request_id());
             println!("oss error: {}", &error_message.content());
         }
+        // 网络传输等其他错误
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
@@ -22,11 +22,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_prefix("xtoss")
         // .with_resource_group_id("group_id")
         .execute()
-        .await
-        .unwrap_or_else(|error| {
-            println!("reqwest error: {}", error);
-            process::exit(-1);
-        });
+        .await;
 
     match result {
         Ok(data) => {
@@ -39,7 +35,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!(" - storage_class: {}", bucket.storage_class);
                     println!(" - creation_date: {}", bucket.creation_date);
                     println!(" -      location: {}", bucket.location);
-                    println!(" -       comment: {}",
+                    println!(
+                        " -       comment: {}",
                         bucket.comment.as_deref().unwrap_or_default()
                     );
                     println!()
@@ -49,7 +46,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("no buckets");
             }
         }
-        Err(message) => println!("oss error: {}", message.content()),
+        Err(oss::Error::Service(message)) => println!("oss error: {}", message.content()),
+        Err(error) => {
+            println!("error: {}", error);
+            process::exit(-1);
+        }
     }
     Ok(())
 }
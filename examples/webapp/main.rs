@@ -18,14 +18,8 @@ mod pages {
         name: String,
     }
 
-    pub(crate) async fn index(State(state): State<Arc<AppState<'_>>>) -> Html<String> {
-        let result = state
-            .oss_client
-            .ListBuckets()
-            .execute()
-            .await
-            .unwrap()
-            .unwrap();
+    pub(crate) async fn index(State(state): State<Arc<AppState>>) -> Html<String> {
+        let result = state.oss_client.ListBuckets().execute().await.unwrap();
         let buckets = result.content().buckets.bucket;
         let mut context = Context::new();
         context.insert("buckets", &buckets);
@@ -33,9 +27,9 @@ mod pages {
     }
 
     pub(super) async fn describe_regions(
-        State(state): State<Arc<AppState<'_>>>,
+        State(state): State<Arc<AppState>>,
     ) -> Json<RegionInfoList> {
-        let result = state.oss_client.DescribeRegions().execute().await.unwrap();
+        let result = state.oss_client.DescribeRegions().execute().await;
         if let Ok(data) = result {
             Json(data.content())
         } else {
@@ -44,9 +38,9 @@ mod pages {
     }
 
     pub(super) async fn buckets(
-        State(state): State<Arc<AppState<'_>>>,
+        State(state): State<Arc<AppState>>,
     ) -> Json<ListAllMyBucketsResult> {
-        let result = state.oss_client.ListBuckets().execute().await.unwrap();
+        let result = state.oss_client.ListBuckets().execute().await;
         if let Ok(data) = result {
             Json(data.content())
         } else {
@@ -56,12 +50,12 @@ mod pages {
 }
 
 #[derive(Debug)]
-struct AppState<'a> {
-    oss_client: oss::Client<'a>,
+struct AppState {
+    oss_client: oss::Client,
     template: Tera,
 }
 
-impl<'a> AppState<'a> {
+impl AppState {
     fn new() -> Self {
         let template_dir = {
             let mut root_dir = env::current_dir().unwrap();
@@ -6,39 +6,111 @@ pub const DEFAULT_CONNECT_TIMEOUT: u64 = 180;
 pub const DEFAULT_TIMEOUT: u64 = 60;
 pub const GMT_DATE_FMT: &str = "%a, %d %b %Y %H:%M:%S GMT";
 pub const XML_CONTENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+const CONTENT_MD5: &str = "content-md5";
 
 pub use bytes::{Bytes, BytesMut};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 pub mod api;
 pub(super) mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod body;
+pub(crate) mod clock;
+pub mod concurrency;
+pub mod crc64;
+pub mod credentials;
+pub mod endpoint;
 pub mod entities;
 pub mod http;
+pub mod interceptor;
+pub mod limiter;
+pub mod metrics;
+pub mod progress;
+pub mod retry;
+pub mod router;
+pub(crate) mod telemetry;
+pub mod transport;
+
+pub use api::Error;
+pub use auth::SignatureVersion;
+pub use body::UploadBody;
+pub use concurrency::Plane;
+pub use crc64::{Crc64, Crc64Mismatch};
+pub use endpoint::EndpointType;
+pub use interceptor::Interceptor;
+pub use limiter::RateLimiter;
+pub use metrics::Metrics;
+pub use progress::{MultipartProgress, Progress, ProgressListener};
+pub use retry::RetryPolicy;
+pub use router::BucketRouter;
+pub use transport::{Response, Transport};
 
 use super::oss::{
     self,
-    http::header::{AUTHORIZATION, CONTENT_TYPE, DATE},
+    clock::Clock,
+    concurrency::ConcurrencyLimit,
+    credentials::{Credentials, CredentialsProvider, CredentialsResult},
+    http::header::{
+        AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, DATE, HOST, USER_AGENT as USER_AGENT_HEADER,
+    },
+    http::Url,
+    progress::ProgressSink,
+    telemetry::Telemetry,
+    transport::{Activity, Body, ClientOptions, HttpRequest, ReqwestTransport, TlsVersion},
+};
+use chrono::{DateTime, Utc};
+use futures::{StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
 };
-use chrono::Utc;
-use reqwest::{header::HeaderMap, Response, Result};
+
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+/// 请求超时
+#[derive(Debug, Clone, Copy)]
+enum Timeout {
+    /// 整个请求(包括发送请求体与读取响应体)的超时(秒)
+    Total(u64),
+    /// 连续该时长(秒)未发送或收到数据时失败, 不限制总时长
+    Idle(u64),
+}
 
 pub struct RequestTask<'a> {
-    request: &'a oss::Request<'a>,
+    request: &'a oss::Request,
     url: &'a str,
     resource: Option<&'a str>,
+    region: Option<&'a str>,
     method: http::Method,
     headers: http::HeaderMap,
-    body: Bytes,
+    body: UploadBody,
+    retry_policy: Option<RetryPolicy>,
+    idempotent: Option<bool>,
+    operation: Option<&'a str>,
+    plane: Plane,
+    crc64: Option<u64>,
+    content_md5: bool,
+    progress: Option<ProgressSink>,
 }
 
 impl<'a> RequestTask<'a> {
-    pub(crate) fn new(request: &'a oss::Request<'a>) -> Self {
+    pub(crate) fn new(request: &'a oss::Request) -> Self {
         Self {
             request,
             url: Default::default(),
             resource: None,
+            region: None,
             method: http::Method::GET,
             headers: http::HeaderMap::new(),
-            body: Bytes::new(),
+            body: UploadBody::default(),
+            retry_policy: None,
+            idempotent: None,
+            operation: None,
+            plane: Plane::default(),
+            crc64: None,
+            content_md5: false,
+            progress: None,
         }
     }
 
@@ -52,6 +124,12 @@ impl<'a> RequestTask<'a> {
         self
     }
 
+    /// 覆盖V4签名使用的区域, 用于访问非`Options`中配置区域的Bucket
+    pub fn with_region(mut self, value: &'a str) -> Self {
+        self.region = Some(value);
+        self
+    }
+
     pub fn with_headers(mut self, value: http::HeaderMap) -> Self {
         self.headers = value;
         self
@@ -63,121 +141,763 @@ impl<'a> RequestTask<'a> {
     }
 
     pub fn with_body(mut self, value: Bytes) -> Self {
+        self.body = value.into();
+        self
+    }
+
+    /// 数据流请求体, 不可重新读取时请求失败后不重试
+    pub fn with_upload_body(mut self, value: UploadBody) -> Self {
         self.body = value;
         self
     }
 
+    /// 覆盖客户端的重试策略, `None`时使用客户端的重试策略
+    pub fn with_retry_policy(mut self, value: Option<RetryPolicy>) -> Self {
+        self.retry_policy = value.or(self.retry_policy);
+        self
+    }
+
+    /// 上传成功后校验响应头`x-oss-hash-crc64ecma`, 值为请求体之前已有数据的CRC64
+    /// (例如追加上传前对象的CRC64, 新对象为0), `None`时不校验
+    pub fn with_crc64_check(mut self, value: Option<u64>) -> Self {
+        self.crc64 = value;
+        self
+    }
+
+    /// 发送前计算请求体的`Content-MD5`, 已设置该请求头时不计算。
+    /// 服务端据此拒绝传输中损坏的数据, 只能读取一次的请求体返回`Error::Config`
+    pub fn with_compute_content_md5(mut self, value: bool) -> Self {
+        self.content_md5 = value;
+        self
+    }
+
+    /// 报告请求体的发送进度
+    pub(crate) fn with_progress(mut self, value: Option<ProgressSink>) -> Self {
+        self.progress = value;
+        self
+    }
+
+    /// API名称, 例如`PutObject`, 用于日志与拦截器
+    pub fn with_operation(mut self, value: &'a str) -> Self {
+        self.operation = Some(value);
+        self
+    }
+
+    /// 请求类别, 用于并发请求数限制, 默认`Plane::Control`
+    pub fn with_plane(mut self, value: Plane) -> Self {
+        self.plane = value;
+        self
+    }
+
+    /// 请求是否幂等, 默认除`POST`外均为幂等
+    pub fn with_idempotent(mut self, value: bool) -> Self {
+        self.idempotent = Some(value);
+        self
+    }
+
     pub async fn execute(&self) -> oss::Result<Response> {
-        self.inner_execute(None).await
+        self.inner_execute(Timeout::Total(oss::DEFAULT_TIMEOUT))
+            .await
     }
 
     pub async fn execute_timeout(&self, value: u64) -> oss::Result<Response> {
-        self.inner_execute(Some(value)).await
+        self.inner_execute(Timeout::Total(value)).await
+    }
+
+    /// 不限制总时长, 连续`value`秒未发送或收到数据时失败, 用于大文件数据流传输
+    pub async fn execute_idle_timeout(&self, value: u64) -> oss::Result<Response> {
+        self.inner_execute(Timeout::Idle(value)).await
     }
 
-    fn authorization(&self, headers: &HeaderMap, date: &String) -> String {
-        let access_key_id = self.request.access_key_id.unwrap_or_default();
-        let access_key_secret = self.request.access_key_secret.unwrap_or_default();
-        let sts_token = self.request.sts_token;
+    /// 请求体以数据流发送(文件、`AsyncRead`等)时为空闲超时, 内存中的数据为总超时
+    pub(crate) async fn execute_upload_timeout(&self, value: u64) -> oss::Result<Response> {
+        match self.body.as_bytes() {
+            Some(_) => self.execute_timeout(value).await,
+            None => self.execute_idle_timeout(value).await,
+        }
+    }
+
+    fn authorization(
+        &self,
+        headers: &HeaderMap,
+        datetime: &DateTime<Utc>,
+        credentials: &Credentials,
+    ) -> String {
+        let access_key_id = &credentials.access_key_id;
+        let access_key_secret = &credentials.access_key_secret;
+        let sts_token = credentials.security_token.as_deref();
         let resourse = self.resource;
-        auth::SingerV1 {
-            access_key_id,
-            access_key_secret,
-            sts_token,
-            headers: &headers,
+        match self.request.signature_version {
+            SignatureVersion::V1 => auth::SingerV1 {
+                access_key_id,
+                access_key_secret,
+                sts_token,
+                headers,
+                method: &self.method,
+                date: &datetime.format(oss::GMT_DATE_FMT).to_string(),
+                resourse,
+            }
+            .complute(),
+            SignatureVersion::V4 => {
+                let query = self.url.split_once('?').map(|(_, query)| query);
+                auth::SingerV4 {
+                    access_key_id,
+                    access_key_secret,
+                    headers,
+                    method: &self.method,
+                    datetime,
+                    region: self.region.unwrap_or(&self.request.region),
+                    resourse,
+                    query,
+                    additional_headers: &self.request.additional_headers,
+                }
+                .complute()
+            }
+        }
+    }
+
+    /// 生成url签名, `expires`为有效时长(秒)
+    ///
+    /// - V1: 追加`OSSAccessKeyId`、`Expires`、`Signature`参数
+    /// - V4: 追加`x-oss-signature-version`、`x-oss-credential`、`x-oss-date`、
+    ///   `x-oss-expires`、`x-oss-signature`参数
+    ///
+    /// 凭证携带STS令牌时同时追加`security-token`(V1)或`x-oss-security-token`(V4)
+    pub async fn presign(&self, expires: u64) -> oss::Result<String> {
+        let now = self.request.now();
+        let credentials = self.request.credentials().await?;
+        let access_key_id = &credentials.access_key_id;
+        let access_key_secret = &credentials.access_key_secret;
+        let sts_token = credentials.security_token.as_deref();
+        let mut params: Vec<(&str, String)> = Vec::new();
+        let url = match self.request.signature_version {
+            SignatureVersion::V1 => {
+                let expires = (now.timestamp() as u64 + expires).to_string();
+                let resource = match sts_token {
+                    Some(sts_token) => {
+                        let (path, query) = self
+                            .resource
+                            .unwrap_or("/")
+                            .split_once('?')
+                            .unwrap_or((self.resource.unwrap_or("/"), ""));
+                        let token = format!("security-token={}", urlencoding::encode(sts_token));
+                        let mut subresources: Vec<&str> =
+                            query.split('&').filter(|v| !v.is_empty()).collect();
+                        subresources.push(&token);
+                        subresources.sort();
+                        format!("{}?{}", path, subresources.join("&"))
+                    }
+                    None => self.resource.unwrap_or("/").to_string(),
+                };
+                let signature = auth::SingerV1 {
+                    access_key_id,
+                    access_key_secret,
+                    sts_token,
+                    headers: &self.headers,
+                    method: &self.method,
+                    date: &expires,
+                    resourse: Some(&resource),
+                }
+                .presign_signature();
+                params.push(("OSSAccessKeyId", access_key_id.to_string()));
+                params.push(("Expires", expires));
+                params.push(("Signature", signature));
+                if let Some(sts_token) = sts_token {
+                    params.push(("security-token", sts_token.to_string()));
+                }
+                Self::append_query(self.url, &params)
+            }
+            SignatureVersion::V4 => {
+                let mut singer = auth::SingerV4 {
+                    access_key_id,
+                    access_key_secret,
+                    headers: &self.headers,
+                    method: &self.method,
+                    datetime: &now,
+                    region: self.region.unwrap_or(&self.request.region),
+                    resourse: self.resource,
+                    query: None,
+                    additional_headers: &self.request.additional_headers,
+                };
+                params.push(("x-oss-signature-version", auth::V4_ALGORITHM.to_string()));
+                params.push((
+                    "x-oss-credential",
+                    format!("{}/{}", access_key_id, singer.scope()),
+                ));
+                params.push(("x-oss-date", now.format(auth::V4_DATETIME_FMT).to_string()));
+                params.push(("x-oss-expires", expires.to_string()));
+                let additional_headers = singer.additional_headers();
+                if !additional_headers.is_empty() {
+                    params.push(("x-oss-additional-headers", additional_headers.join(";")));
+                }
+                if let Some(sts_token) = sts_token {
+                    params.push(("x-oss-security-token", sts_token.to_string()));
+                }
+                let url = Self::append_query(self.url, &params);
+                singer.query = url.split_once('?').map(|(_, query)| query);
+                let signature = singer.presign_signature();
+                Self::append_query(&url, &[("x-oss-signature", signature)])
+            }
+        };
+        Ok(url)
+    }
+
+    /// 错误消息中不包含头部的值, 避免泄露令牌或签名
+    fn header_value(name: &str, value: &str) -> oss::Result<http::HeaderValue> {
+        value
+            .parse()
+            .map_err(|_| Error::Config(format!("invalid value of header `{}`", name)))
+    }
+
+    fn append_query(url: &str, params: &[(&str, String)]) -> String {
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect::<Vec<String>>()
+            .join("&");
+        match url.contains('?') {
+            true => format!("{}&{}", url, query),
+            false => format!("{}?{}", url, query),
+        }
+    }
+
+    async fn inner_execute(&self, timeout: Timeout) -> oss::Result<Response> {
+        let policy = self.retry_policy.unwrap_or(self.request.retry_policy);
+        let idempotent = self.idempotent.unwrap_or(self.method != http::Method::POST);
+        let telemetry = Telemetry::new(
+            self.request.metrics.clone(),
+            self.operation,
+            &self.method,
+            self.resource,
+            self.body.len() as usize,
+        );
+        let start = Instant::now();
+        // 只计算一次, 重试时复用
+        let content_md5 = match self.content_md5 && !self.headers.contains_key(CONTENT_MD5) {
+            true => Some(Self::header_value(
+                "content-md5",
+                &self.body.content_md5().await?,
+            )?),
+            false => None,
+        };
+        let mut attempt = 1;
+        // 时钟偏差导致的重试次数, 最多一次且不计入重试策略
+        let mut skew_retries = 0;
+        // 只能读取一次的请求体发送后无法重试
+        let rewindable = self.body.is_rewindable();
+        let result = telemetry
+            .instrument(async {
+                loop {
+                    let permit = self.request.concurrency.acquire(self.plane).await;
+                    let attempt_start = Instant::now();
+                    let result = match permit {
+                        Some((permit, wait)) => {
+                            telemetry.record_queue_wait(self.plane, wait);
+                            let result = self.send(timeout, attempt, content_md5.as_ref()).await;
+                            result.map(|resp| resp.with_permit(permit))
+                        }
+                        None => self.send(timeout, attempt, content_md5.as_ref()).await,
+                    };
+                    let (result, skewed) = match result {
+                        Ok(resp) if skew_retries == 0 => match clock::check_skewed(resp).await {
+                            Ok((resp, skewed)) => (Ok(resp), skewed),
+                            Err(e) => (Err(e.into()), false),
+                        },
+                        result => (result, false),
+                    };
+                    telemetry.record_attempt(
+                        &self.method,
+                        attempt,
+                        &result,
+                        attempt_start.elapsed(),
+                        self.body.len() as usize,
+                    );
+                    // 时钟已根据该响应的`Date`校正, 立即重新签名
+                    if skewed && rewindable {
+                        skew_retries += 1;
+                        telemetry.record_retry(attempt, Duration::ZERO);
+                        attempt += 1;
+                        continue;
+                    }
+                    if !rewindable || attempt >= policy.max_attempts() + skew_retries {
+                        return result;
+                    }
+                    let (result, code) = match result {
+                        Ok(resp) if idempotent => match retry::error_code(resp).await {
+                            Ok((resp, code)) => (Ok(resp), code),
+                            Err(e) => (Err(e.into()), None),
+                        },
+                        result => (result, None),
+                    };
+                    if !policy.should_retry(&result, code.as_ref(), idempotent) {
+                        return result;
+                    }
+                    // 释放响应持有的并发许可与连接, 退避期间不占用
+                    drop(result);
+                    let delay = policy.delay(attempt);
+                    telemetry.record_retry(attempt, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            })
+            .await;
+        telemetry.record_result(&result, start.elapsed(), attempt - 1);
+        result.map(|resp| resp.with_telemetry(telemetry))
+    }
+
+    /// 发送一次请求, 每次调用都重新获取凭证并签名
+    async fn send(
+        &self,
+        timeout: Timeout,
+        attempt: u32,
+        content_md5: Option<&http::HeaderValue>,
+    ) -> oss::Result<Response> {
+        let context = interceptor::Context {
+            operation: self.operation,
             method: &self.method,
-            date: &date,
-            resourse,
+            url: self.url,
+            resource: self.resource,
+            attempt,
+        };
+        let mut response = self.sign_and_send(&context, timeout, content_md5).await?;
+        for interceptor in self.request.interceptors.iter().rev() {
+            interceptor.after_response(&context, &mut response)?;
         }
-        .complute()
+        Ok(response)
     }
 
-    async fn inner_execute(&self, timeout: Option<u64>) -> oss::Result<Response> {
-        let date = Utc::now().format(oss::GMT_DATE_FMT).to_string();
+    async fn sign_and_send(
+        &self,
+        context: &interceptor::Context<'_>,
+        timeout: Timeout,
+        content_md5: Option<&http::HeaderValue>,
+    ) -> oss::Result<Response> {
+        if let Some(message) = &self.request.config_error {
+            return Err(Error::Config(message.to_owned()));
+        }
+        let credentials = self.request.credentials().await?;
+        let now = self.request.now();
+        let date = now.format(oss::GMT_DATE_FMT).to_string();
         let mut headers = http::HeaderMap::new();
         headers.insert(DATE, date.parse().unwrap());
-        if let Some(sts_token) = self.request.sts_token {
-            headers.insert("x-oss-security-token", sts_token.parse().unwrap());
+        if self.request.signature_version == SignatureVersion::V4 {
+            let datetime = now.format(auth::V4_DATETIME_FMT).to_string();
+            headers.insert(auth::V4_DATE, datetime.parse().unwrap());
+            headers.insert(
+                auth::V4_CONTENT_SHA256,
+                http::HeaderValue::from_static(auth::V4_UNSIGNED_PAYLOAD),
+            );
         }
+        if let Some(sts_token) = &credentials.security_token {
+            headers.insert(
+                "x-oss-security-token",
+                Self::header_value("x-oss-security-token", sts_token)?,
+            );
+        }
+        headers.insert(
+            CONTENT_TYPE,
+            http::HeaderValue::from_static(DEFAULT_CONTENT_TYPE),
+        );
+        headers.insert(
+            USER_AGENT_HEADER,
+            http::HeaderValue::from_static(USER_AGENT),
+        );
         headers.extend(self.headers.to_owned());
-        let auth = self.authorization(&headers, &date);
-        headers.insert(AUTHORIZATION, auth.parse().unwrap());
-        // dbg!(&headers);
-        let timeout = Duration::from_secs(timeout.unwrap_or(oss::DEFAULT_TIMEOUT));
-        self.request
-            .client
-            .request(self.method.to_owned(), self.url)
-            .headers(headers)
-            .timeout(timeout)
-            .body(self.body.to_owned())
-            .send()
-            .await
+        if let Some(content_md5) = content_md5 {
+            headers.insert(CONTENT_MD5, content_md5.clone());
+        }
+        let limiter = self
+            .request
+            .upload_limiter
+            .as_ref()
+            .filter(|_| !self.body.is_empty());
+        // 以数据流发送时由`Content-Length`给出长度
+        if limiter.is_some()
+            || self.progress.is_some()
+            || self.body.as_bytes().is_none()
+            || matches!(timeout, Timeout::Idle(_))
+        {
+            headers
+                .entry(CONTENT_LENGTH)
+                .or_insert_with(|| self.body.len().into());
+        }
+        for interceptor in &self.request.interceptors {
+            if let Some(response) = interceptor.before_sign(context, &mut headers)? {
+                return Ok(response);
+            }
+        }
+        if self.request.additional_headers.iter().any(|k| k == "host") {
+            if let Some(host) = Url::parse(self.url).ok().and_then(|url| {
+                url.host_str().map(|host| match url.port() {
+                    Some(port) => format!("{}:{}", host, port),
+                    None => host.to_string(),
+                })
+            }) {
+                headers.insert(HOST, Self::header_value("host", &host)?);
+            }
+        }
+        let auth = self.authorization(&headers, &now, &credentials);
+        headers.insert(AUTHORIZATION, Self::header_value("authorization", &auth)?);
+        let url = Url::parse(self.url)
+            .map_err(|e| Error::Config(format!("invalid url `{}`: {}", self.url, e)))?;
+        // 边发送边计算CRC64, 从已有数据的CRC64继续计算即为合并后的值
+        let crc = self
+            .crc64
+            .map(|init| Arc::new(Mutex::new(Crc64::from_checksum(init, 0))));
+        let body = match (self.body.open().await?, &crc) {
+            (Body::Bytes(bytes), Some(crc)) => {
+                crc.lock().unwrap_or_else(|e| e.into_inner()).update(&bytes);
+                Body::Bytes(bytes)
+            }
+            (Body::Stream(stream), Some(crc)) => {
+                let crc = crc.clone();
+                let stream = stream.inspect_ok(move |chunk| {
+                    crc.lock().unwrap_or_else(|e| e.into_inner()).update(chunk)
+                });
+                Body::Stream(stream.boxed())
+            }
+            (body, None) => body,
+        };
+        let body = match (body, limiter) {
+            (Body::Bytes(bytes), Some(limiter)) => {
+                Body::Stream(limiter.clone().throttle(limiter::chunks(bytes)))
+            }
+            (Body::Stream(stream), Some(limiter)) => Body::Stream(limiter.clone().throttle(stream)),
+            (body, None) => body,
+        };
+        let body = match (body, &self.progress) {
+            (Body::Bytes(bytes), Some(progress)) if !bytes.is_empty() => Body::Stream(
+                progress
+                    .clone()
+                    .track(limiter::chunks(bytes), Some(self.body.len())),
+            ),
+            (Body::Stream(stream), Some(progress)) => {
+                Body::Stream(progress.clone().track(stream, Some(self.body.len())))
+            }
+            (body, _) => body,
+        };
+        // 空闲超时: 记录传输层读取请求体的时间
+        let activity = Activity::new();
+        let body = match (body, timeout) {
+            (Body::Bytes(bytes), Timeout::Idle(_)) if !bytes.is_empty() => {
+                Body::Stream(activity.track(limiter::chunks(bytes)))
+            }
+            (Body::Stream(stream), Timeout::Idle(_)) => Body::Stream(activity.track(stream)),
+            (body, _) => body,
+        };
+        let mut request = HttpRequest {
+            method: self.method.to_owned(),
+            url,
+            headers,
+            body,
+            timeout: match timeout {
+                Timeout::Total(value) => Some(Duration::from_secs(value)),
+                Timeout::Idle(_) => None,
+            },
+        };
+        for interceptor in &self.request.interceptors {
+            if let Some(response) = interceptor.after_sign(context, &mut request)? {
+                return Ok(response);
+            }
+        }
+        let mut response = match timeout {
+            Timeout::Total(_) => self.request.transport.send(request).await?,
+            Timeout::Idle(value) => {
+                let send = self.request.transport.send(request);
+                activity.timeout(Duration::from_secs(value), send).await?
+            }
+        };
+        // 只根据服务端的响应校正时钟, 拦截器返回的响应可能没有或带有过期的`Date`
+        self.request.clock.update(response.headers());
+        if let Some(crc) = crc {
+            let crc = *crc.lock().unwrap_or_else(|e| e.into_inner());
+            // 传输层未读取完请求体时无法比较
+            if response.status().is_success() && crc.len() == self.body.len() {
+                crc64::verify(crc.value(), response.headers())?;
+            }
+        }
+        let read_timeout = match timeout {
+            Timeout::Idle(value) => self
+                .request
+                .read_timeout
+                .or(Some(Duration::from_secs(value))),
+            Timeout::Total(_) => self.request.read_timeout,
+        };
+        if let Some(timeout) = read_timeout {
+            response = response.with_read_timeout(timeout);
+        }
+        Ok(match &self.request.download_limiter {
+            Some(limiter) => response.with_limiter(limiter.clone()),
+            None => response,
+        })
     }
 }
 
-#[derive(Debug, Default, Clone)]
-pub struct Request<'a> {
-    access_key_id: Option<&'a str>,
-    access_key_secret: Option<&'a str>,
-    sts_token: Option<&'a str>,
-    client: reqwest::Client,
+#[derive(Debug, Clone)]
+pub struct Request {
+    access_key_id: Option<String>,
+    access_key_secret: Option<String>,
+    sts_token: Option<String>,
+    credentials_provider: Option<Arc<dyn CredentialsProvider>>,
+    region: String,
+    signature_version: SignatureVersion,
+    additional_headers: Vec<String>,
+    retry_policy: RetryPolicy,
+    transport: Arc<dyn Transport>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    upload_limiter: Option<Arc<RateLimiter>>,
+    download_limiter: Option<Arc<RateLimiter>>,
+    concurrency: ConcurrencyLimit,
+    clock: Arc<Clock>,
+    /// 创建HTTP客户端失败等无效配置, 所有请求返回`Error::Config`
+    config_error: Option<String>,
+    read_timeout: Option<Duration>,
 }
 
-impl<'a> Request<'a> {
-    pub fn new() -> Self {
-        let mut headers = http::HeaderMap::new();
-        headers.insert(
-            CONTENT_TYPE,
-            http::HeaderValue::from_static(DEFAULT_CONTENT_TYPE),
-        );
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .user_agent(oss::USER_AGENT)
-            .connect_timeout(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT))
-            .build()
-            .unwrap();
+impl Default for Request {
+    fn default() -> Self {
         Self {
-            client,
-            ..Self::default()
+            access_key_id: None,
+            access_key_secret: None,
+            sts_token: None,
+            credentials_provider: None,
+            region: String::new(),
+            signature_version: SignatureVersion::default(),
+            additional_headers: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            transport: Arc::new(ReqwestTransport::default()),
+            interceptors: Vec::new(),
+            metrics: None,
+            upload_limiter: None,
+            download_limiter: None,
+            concurrency: ConcurrencyLimit::default(),
+            clock: Arc::default(),
+            config_error: None,
+            read_timeout: None,
         }
     }
+}
+
+impl Request {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_access_key_id(mut self, value: impl Into<String>) -> Self {
+        self.access_key_id = Some(value.into());
+        self
+    }
+
+    pub fn with_access_key_secret(mut self, value: impl Into<String>) -> Self {
+        self.access_key_secret = Some(value.into());
+        self
+    }
+
+    pub fn with_sts_token(mut self, value: Option<impl Into<String>>) -> Self {
+        self.sts_token = value.map(Into::into);
+        self
+    }
+
+    /// 设置后优先于`access_key_id`、`access_key_secret`、`sts_token`
+    pub fn with_credentials_provider(
+        mut self,
+        value: Option<Arc<dyn CredentialsProvider>>,
+    ) -> Self {
+        self.credentials_provider = value;
+        self
+    }
+
+    pub fn with_region(mut self, value: impl Into<String>) -> Self {
+        self.region = value.into();
+        self
+    }
+
+    pub fn with_signature_version(mut self, value: SignatureVersion) -> Self {
+        self.signature_version = value;
+        self
+    }
+
+    pub fn with_additional_headers<T: AsRef<str>>(mut self, value: &[T]) -> Self {
+        self.additional_headers = value.iter().map(|v| v.as_ref().to_lowercase()).collect();
+        self
+    }
+
+    pub fn with_retry_policy(mut self, value: RetryPolicy) -> Self {
+        self.retry_policy = value;
+        self
+    }
+
+    pub fn with_transport(mut self, value: Arc<dyn Transport>) -> Self {
+        self.transport = value;
+        self
+    }
+
+    pub fn with_interceptors(mut self, value: Vec<Arc<dyn Interceptor>>) -> Self {
+        self.interceptors = value;
+        self
+    }
+
+    pub fn with_metrics(mut self, value: Option<Arc<dyn Metrics>>) -> Self {
+        self.metrics = value;
+        self
+    }
 
-    pub fn with_access_key_id(mut self, value: &'a str) -> Self {
-        self.access_key_id = Some(value);
+    pub fn with_upload_limiter(mut self, value: Option<Arc<RateLimiter>>) -> Self {
+        self.upload_limiter = value;
         self
     }
 
-    pub fn with_access_key_secret(mut self, value: &'a str) -> Self {
-        self.access_key_secret = Some(value);
+    pub fn with_download_limiter(mut self, value: Option<Arc<RateLimiter>>) -> Self {
+        self.download_limiter = value;
         self
     }
 
-    pub fn with_sts_token(mut self, value: Option<&'a str>) -> Self {
-        self.sts_token = value;
+    /// 数据面、管控面同时进行的请求数上限, `None`时不限制
+    pub fn with_concurrency_limit(mut self, data: Option<usize>, control: Option<usize>) -> Self {
+        self.concurrency = ConcurrencyLimit::new(data, control);
         self
     }
 
+    /// 读取响应体时两次收到数据之间的最长间隔, `None`时不限制
+    pub fn with_read_timeout(mut self, value: Option<Duration>) -> Self {
+        self.read_timeout = value;
+        self
+    }
+
+    pub(crate) fn with_config_error(mut self, value: Option<String>) -> Self {
+        self.config_error = value;
+        self
+    }
+
+    /// 本地时钟与OSS服务端时钟的偏差(服务端减本地), 根据最近一次响应的`Date`更新
+    pub fn clock_offset(&self) -> chrono::Duration {
+        self.clock.offset()
+    }
+
+    /// 按服务端时钟校正后的当前时间, 用于签名
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// 当前有效的访问凭证
+    pub async fn credentials(&self) -> CredentialsResult {
+        match &self.credentials_provider {
+            Some(provider) => provider.credentials().await,
+            None => {
+                let credentials = Credentials::new(
+                    self.access_key_id.clone().unwrap_or_default(),
+                    self.access_key_secret.clone().unwrap_or_default(),
+                );
+                Ok(match &self.sts_token {
+                    Some(sts_token) => credentials.with_security_token(sts_token),
+                    None => credentials,
+                })
+            }
+        }
+    }
+
+    /// PostObject表单中与签名相关、需写入Policy的表单域
+    pub(crate) fn post_fields(
+        &self,
+        datetime: &DateTime<Utc>,
+        credentials: &Credentials,
+    ) -> Vec<(String, String)> {
+        let access_key_id = &credentials.access_key_id;
+        let mut fields = match self.signature_version {
+            SignatureVersion::V1 => vec![("OSSAccessKeyId".to_string(), access_key_id.to_string())],
+            SignatureVersion::V4 => {
+                let headers = http::HeaderMap::new();
+                let scope = self.singer_v4(&headers, datetime, credentials).scope();
+                vec![
+                    (
+                        "x-oss-signature-version".to_string(),
+                        auth::V4_ALGORITHM.to_string(),
+                    ),
+                    (
+                        "x-oss-credential".to_string(),
+                        format!("{}/{}", access_key_id, scope),
+                    ),
+                    (
+                        auth::V4_DATE.to_string(),
+                        datetime.format(auth::V4_DATETIME_FMT).to_string(),
+                    ),
+                ]
+            }
+        };
+        if let Some(sts_token) = &credentials.security_token {
+            fields.push(("x-oss-security-token".to_string(), sts_token.to_string()));
+        }
+        fields
+    }
+
+    /// PostObject表单签名, 返回签名表单域名称与签名
+    pub(crate) fn post_signature(
+        &self,
+        policy: &str,
+        datetime: &DateTime<Utc>,
+        credentials: &Credentials,
+    ) -> (&'static str, String) {
+        match self.signature_version {
+            SignatureVersion::V1 => (
+                "Signature",
+                auth::post_signature_v1(&credentials.access_key_secret, policy),
+            ),
+            SignatureVersion::V4 => {
+                let headers = http::HeaderMap::new();
+                (
+                    "x-oss-signature",
+                    self.singer_v4(&headers, datetime, credentials)
+                        .post_signature(policy),
+                )
+            }
+        }
+    }
+
+    fn singer_v4<'b>(
+        &'b self,
+        headers: &'b HeaderMap,
+        datetime: &'b DateTime<Utc>,
+        credentials: &'b Credentials,
+    ) -> auth::SingerV4<'b> {
+        auth::SingerV4 {
+            access_key_id: &credentials.access_key_id,
+            access_key_secret: &credentials.access_key_secret,
+            headers,
+            method: &http::Method::POST,
+            datetime,
+            region: &self.region,
+            resourse: None,
+            query: None,
+            additional_headers: &[],
+        }
+    }
+
     pub fn task(&self) -> RequestTask<'_> {
         RequestTask::new(&self)
     }
 }
 
-#[derive(Debug, Clone, Default, Copy)]
-pub struct Options<'a> {
+#[derive(Debug, Clone, Default)]
+pub struct Options {
     /// 通过阿里云控制台创建的AccessKey ID
-    access_key_id: &'a str,
+    access_key_id: String,
     /// 通过阿里云控制台创建的AccessKey Secret
-    access_key_secret: &'a str,
+    access_key_secret: String,
     /// 使用临时授权方式
-    sts_token: &'a str,
+    sts_token: String,
     /// 通过控制台或PutBucket创建的Bucket
-    bucket: &'a str,
+    bucket: String,
     /// OSS访问域名。
-    endpoint: &'a str,
+    endpoint: String,
     /// Bucket所在的区域,默认值为oss-cn-hangzhou
-    region: &'a str,
-    /// 是否使用阿里云内网访问,默认值为false
-    internal: bool,
+    region: String,
+    /// 根据区域生成的域名类型,默认为外网域名
+    endpoint_type: EndpointType,
+    /// 基础域名,默认值为aliyuncs.com
+    base_domain: String,
+    /// 是否使用路径方式(`host/bucket/object`)访问,默认值为false
+    path_style: bool,
     /// 是否支持上传自定义域名,默认值为false
     cname: bool,
     // /// Bucket是否开启请求者付费模,默认值为false
@@ -186,13 +906,43 @@ pub struct Options<'a> {
     secure: bool,
     /// 超时时间,默认值为60秒
     timeout: u64,
+    /// 读取响应体时两次收到数据之间的最长间隔
+    read_timeout: Option<Duration>,
+    /// 签名版本,默认值为V1
+    signature_version: SignatureVersion,
+    /// V4签名时额外参与签名的头部,例如`host`
+    additional_headers: Vec<String>,
+    /// 访问凭证提供者,设置后优先于`access_key_id`、`access_key_secret`、`sts_token`
+    credentials_provider: Option<Arc<dyn CredentialsProvider>>,
+    /// 重试策略,默认最多请求3次
+    retry_policy: RetryPolicy,
+    /// HTTP传输层,默认使用`ReqwestTransport`
+    transport: Option<Arc<dyn Transport>>,
+    /// 请求拦截器,按添加顺序调用
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    /// 请求指标
+    metrics: Option<Arc<dyn Metrics>>,
+    /// 上传总带宽限制
+    upload_limiter: Option<Arc<RateLimiter>>,
+    /// 下载总带宽限制
+    download_limiter: Option<Arc<RateLimiter>>,
+    /// 同时进行的数据面请求数上限
+    max_data_requests: Option<usize>,
+    /// 同时进行的管控面请求数上限
+    max_control_requests: Option<usize>,
+    /// 默认传输层的代理与TLS配置, 设置`transport`时忽略
+    client_options: ClientOptions,
+    /// 是否校验上传、下载数据的CRC64,默认值为false
+    crc64_check: bool,
+    /// 上传时是否自动计算`Content-MD5`,默认值为false
+    compute_content_md5: bool,
 }
 
-impl<'a> Options<'a> {
+impl Options {
     pub fn new() -> Self {
         Self {
-            region: oss::DEFAULT_REGION,
-            internal: false,
+            region: oss::DEFAULT_REGION.to_string(),
+            base_domain: oss::BASE_URL.to_string(),
             cname: false,
             // is_request_pay: false,
             secure: false,
@@ -201,44 +951,68 @@ impl<'a> Options<'a> {
         }
     }
 
-    pub fn with_access_key_id(mut self, value: &'a str) -> Self {
-        self.access_key_id = value;
+    pub fn with_access_key_id(mut self, value: impl Into<String>) -> Self {
+        self.access_key_id = value.into();
         self
     }
 
-    pub fn with_access_key_secret(mut self, value: &'a str) -> Self {
-        self.access_key_secret = value;
+    pub fn with_access_key_secret(mut self, value: impl Into<String>) -> Self {
+        self.access_key_secret = value.into();
         self
     }
 
-    pub fn with_bucket(mut self, value: &'a str) -> Self {
-        self.bucket = value;
+    pub fn with_bucket(mut self, value: impl Into<String>) -> Self {
+        self.bucket = value.into();
         self
     }
 
-    pub fn with_region(mut self, value: &'a str) -> Self {
-        self.region = value;
+    pub fn with_region(mut self, value: impl Into<String>) -> Self {
+        self.region = value.into();
         self
     }
 
-    pub fn with_sts_token(mut self, value: &'a str) -> Self {
-        self.sts_token = value;
+    pub fn with_sts_token(mut self, value: impl Into<String>) -> Self {
+        self.sts_token = value.into();
         self
     }
 
-    pub fn with_endpoint(mut self, value: &'a str) -> Self {
+    pub fn with_endpoint(mut self, value: impl Into<String>) -> Self {
+        let value = value.into();
         self.endpoint = if let Some(v) = value.strip_prefix("http://") {
-            v
+            v.to_string()
         } else if let Some(v) = value.strip_prefix("https://") {
-            v
+            v.to_string()
         } else {
             value
         };
         self
     }
 
+    /// 使用阿里云内网访问, 等同于`with_endpoint_type(EndpointType::Internal)`
     pub fn with_internal(mut self, value: bool) -> Self {
-        self.internal = value;
+        if value {
+            self.endpoint_type = EndpointType::Internal;
+        } else if self.endpoint_type == EndpointType::Internal {
+            self.endpoint_type = EndpointType::Public;
+        }
+        self
+    }
+
+    /// 根据区域生成的域名类型, 非外网类型时忽略`cname`与`endpoint`
+    pub fn with_endpoint_type(mut self, value: EndpointType) -> Self {
+        self.endpoint_type = value;
+        self
+    }
+
+    /// 基础域名, 用于其他云或专有云环境, 例如`aliyuncs.com`
+    pub fn with_base_domain(mut self, value: impl Into<String>) -> Self {
+        self.base_domain = value.into();
+        self
+    }
+
+    /// 使用路径方式访问, 即`host/bucket/object`
+    pub fn with_path_style(mut self, value: bool) -> Self {
+        self.path_style = value;
         self
     }
 
@@ -247,6 +1021,21 @@ impl<'a> Options<'a> {
         self
     }
 
+    /// 校验上传、下载数据的CRC64(`x-oss-hash-crc64ecma`), 不一致时返回`Error::Crc64Mismatch`。
+    /// 适用于`PutObject`、`AppendObject`、`UploadPart`、`CompleteMultipartUpload`与不指定范围的`GetObject`
+    pub fn with_crc64_check(mut self, value: bool) -> Self {
+        self.crc64_check = value;
+        self
+    }
+
+    /// `PutObject`、`AppendObject`、`UploadPart`未指定`Content-MD5`时自动计算,
+    /// 服务端据此拒绝传输中损坏的数据。文件等数据流在发送前额外读取一次,
+    /// 只能读取一次的`AsyncRead`与`Stream`返回`Error::Config`。各接口可通过`with_compute_content_md5`单独设置
+    pub fn with_compute_content_md5(mut self, value: bool) -> Self {
+        self.compute_content_md5 = value;
+        self
+    }
+
     // pub fn with_is_request_pay(mut self, value: bool) -> Self {
     //     self.is_request_pay = value;
     //     self
@@ -256,40 +1045,248 @@ impl<'a> Options<'a> {
         self.secure = value;
         self
     }
+    /// 单个请求的总超时时间(秒), 包括发送请求体与读取响应体, 可在builder中单独设置。
+    /// 以数据流下载(`GetObject::execute_stream`等)时不限制总时长, 为连续未收到数据的时长
     pub fn with_timeout(mut self, value: u64) -> Self {
         self.timeout = value;
         self
     }
 
-    pub fn root_url(&self) -> String {
-        format!(
-            "{}://{}{}.{}",
-            self.schema(),
-            oss::DEFAULT_REGION,
-            if self.internal == true {
-                "-internal"
-            } else {
-                ""
-            },
-            oss::BASE_URL
-        )
+    /// 读超时: 读取响应体时连续该时长未收到数据则失败, 与总超时同时生效。
+    /// 大文件传输可设置较长的总超时与较短的读超时, 及时发现停滞的连接
+    pub fn with_read_timeout(mut self, value: Duration) -> Self {
+        self.read_timeout = Some(value);
+        self
     }
 
-    pub fn base_url(&self) -> String {
-        if self.internal == true {
-            format!("{}://{}.{}", self.schema(), self.bucket, self.host())
-        } else if self.cname == true {
-            format!("{}://{}", self.schema(), self.host())
-        } else {
-            if self.bucket.is_empty() {
-                panic!("Bucket parameter must be provided.");
+    /// 建立连接(包括TLS握手)的超时时间, 默认180秒
+    pub fn with_connect_timeout(mut self, value: Duration) -> Self {
+        self.client_options = self.client_options.with_connect_timeout(value);
+        self
+    }
+
+    /// 连接池中每个主机保留的最大空闲连接数, 默认不限制
+    pub fn with_pool_max_idle_per_host(mut self, value: usize) -> Self {
+        self.client_options = self.client_options.with_pool_max_idle_per_host(value);
+        self
+    }
+
+    /// 空闲连接的保留时间, 默认90秒
+    pub fn with_pool_idle_timeout(mut self, value: Duration) -> Self {
+        self.client_options = self.client_options.with_pool_idle_timeout(value);
+        self
+    }
+
+    /// TCP keepalive探测间隔, 默认关闭
+    pub fn with_tcp_keepalive(mut self, value: Duration) -> Self {
+        self.client_options = self.client_options.with_tcp_keepalive(value);
+        self
+    }
+
+    /// 是否设置`TCP_NODELAY`, 默认`true`; 小请求较多时保持开启可降低延迟
+    pub fn with_tcp_nodelay(mut self, value: bool) -> Self {
+        self.client_options = self.client_options.with_tcp_nodelay(value);
+        self
+    }
+
+    /// 不经协商直接使用HTTP/2, 仅在确认服务端(或代理)支持时开启
+    pub fn with_http2_prior_knowledge(mut self, value: bool) -> Self {
+        self.client_options = self.client_options.with_http2_prior_knowledge(value);
+        self
+    }
+
+    /// HTTP/2自适应流控窗口, 可提高高延迟链路上的吞吐量
+    pub fn with_http2_adaptive_window(mut self, value: bool) -> Self {
+        self.client_options = self.client_options.with_http2_adaptive_window(value);
+        self
+    }
+
+    pub fn with_signature_version(mut self, value: SignatureVersion) -> Self {
+        self.signature_version = value;
+        self
+    }
+
+    pub fn with_credentials_provider(mut self, value: impl CredentialsProvider + 'static) -> Self {
+        self.credentials_provider = Some(Arc::new(value));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, value: RetryPolicy) -> Self {
+        self.retry_policy = value;
+        self
+    }
+
+    /// 替换HTTP传输层, 例如传入预先配置好的`ReqwestTransport::new(reqwest_client)`
+    pub fn with_transport(mut self, value: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(value));
+        self
+    }
+
+    /// 设置请求指标, 例如`metrics::PrometheusMetrics`
+    pub fn with_metrics(mut self, value: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(value));
+        self
+    }
+
+    /// 限制该客户端所有请求的上传总带宽, 单位字节/秒
+    pub fn with_upload_limit(mut self, bytes_per_sec: u64) -> Self {
+        self.upload_limiter = Some(Arc::new(RateLimiter::new(bytes_per_sec)));
+        self
+    }
+
+    /// 限制该客户端所有请求的下载总带宽, 单位字节/秒
+    pub fn with_download_limit(mut self, bytes_per_sec: u64) -> Self {
+        self.download_limiter = Some(Arc::new(RateLimiter::new(bytes_per_sec)));
+        self
+    }
+
+    /// 限制同时进行的数据面请求(`GetObject`、`PutObject`、`AppendObject`、`UploadPart`、
+    /// `CopyObject`)数, 超出时排队等待
+    pub fn with_max_data_requests(mut self, value: usize) -> Self {
+        self.max_data_requests = Some(value);
+        self
+    }
+
+    /// 限制同时进行的管控面请求(数据面以外的请求)数, 超出时排队等待
+    pub fn with_max_control_requests(mut self, value: usize) -> Self {
+        self.max_control_requests = Some(value);
+        self
+    }
+
+    /// 代理所有请求, 例如`http://proxy.example.com:8080`;
+    /// 启用`socks`特性后支持`socks5://`、`socks5h://`。未设置时读取`HTTP_PROXY`、`HTTPS_PROXY`等环境变量
+    pub fn with_proxy(mut self, value: impl Into<String>) -> Self {
+        self.client_options = self.client_options.with_proxy(value.into());
+        self
+    }
+
+    /// 仅代理`http://`请求
+    pub fn with_http_proxy(mut self, value: impl Into<String>) -> Self {
+        self.client_options = self.client_options.with_http_proxy(value.into());
+        self
+    }
+
+    /// 仅代理`https://`请求
+    pub fn with_https_proxy(mut self, value: impl Into<String>) -> Self {
+        self.client_options = self.client_options.with_https_proxy(value.into());
+        self
+    }
+
+    /// 代理的Basic认证
+    pub fn with_proxy_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.client_options = self
+            .client_options
+            .with_proxy_auth(username.into(), password.into());
+        self
+    }
+
+    /// 不使用代理的主机, 以逗号分隔, 支持域名后缀与IP网段,
+    /// 例如`localhost,.internal.example.com,10.0.0.0/8`
+    pub fn with_no_proxy(mut self, value: impl Into<String>) -> Self {
+        self.client_options = self.client_options.with_no_proxy(value.into());
+        self
+    }
+
+    /// 添加PEM格式的根证书(可包含多个证书), 例如企业内部CA, 可多次调用
+    pub fn with_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.client_options = self.client_options.with_root_certificate(pem.into());
+        self
+    }
+
+    /// 是否信任TLS库内置的根证书, 默认`true`; 设为`false`时只信任`with_root_certificate`添加的证书
+    pub fn with_builtin_root_certificates(mut self, value: bool) -> Self {
+        self.client_options = self.client_options.with_builtin_roots(value);
+        self
+    }
+
+    /// 双向TLS认证的客户端证书与PKCS#8私钥, 均为PEM格式
+    pub fn with_client_certificate(
+        mut self,
+        cert_pem: impl Into<Vec<u8>>,
+        key_pem: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.client_options = self
+            .client_options
+            .with_client_certificate(cert_pem.into(), key_pem.into());
+        self
+    }
+
+    /// TLS最低版本, `rustls`不支持低于TLS 1.2的版本
+    pub fn with_min_tls_version(mut self, value: TlsVersion) -> Self {
+        self.client_options = self.client_options.with_min_tls_version(value);
+        self
+    }
+
+    /// 按代理、TLS与连接配置创建`reqwest::Client`, 例如供获取临时凭证使用
+    ///
+    /// ```no_run
+    /// # use xt_oss::oss::{self, credentials::*};
+    /// # fn run() -> Result<(), oss::Error> {
+    /// let options = oss::Options::new().with_proxy("http://proxy.example.com:3128");
+    /// let provider = StsAssumeRoleCredentialsProvider::new(
+    ///     EnvironmentCredentialsProvider::new(),
+    ///     "acs:ram::123456789:role/oss-role",
+    ///     "xtoss",
+    /// )
+    /// .with_client(options.http_client()?);
+    /// let client = options.with_credentials_provider(provider).client();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn http_client(&self) -> oss::Result<reqwest::Client> {
+        self.client_options.build().map_err(Error::Config)
+    }
+
+    /// 添加请求拦截器, 可多次调用
+    pub fn with_interceptor(mut self, value: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(value));
+        self
+    }
+
+    pub fn with_additional_headers<T: AsRef<str>>(mut self, value: &[T]) -> Self {
+        self.additional_headers = value.iter().map(|v| v.as_ref().to_string()).collect();
+        self
+    }
+
+    /// 服务级接口(例如`ListBuckets`、`DescribeRegions`)的地址, 使用默认区域
+    ///
+    /// 加速域名不支持服务级接口, 此时使用外网域名
+    pub fn root_url(&self) -> oss::Result<String> {
+        let host = match self.endpoint_type {
+            EndpointType::Accelerate | EndpointType::AccelerateOverseas => {
+                EndpointType::Public.host(oss::DEFAULT_REGION, self.base_domain())?
             }
-            format!("{}://{}.{}", self.schema(), self.bucket, self.host())
+            _ => self.region_host(oss::DEFAULT_REGION)?.0,
+        };
+        Ok(format!("{}://{}", self.schema(), host))
+    }
+
+    /// Bucket的地址, 未使用`cname`时必须设置`bucket`
+    pub fn base_url(&self) -> oss::Result<String> {
+        match self.endpoint_type == EndpointType::Public && self.cname {
+            true => Ok(format!("{}://{}", self.schema(), self.host()?)),
+            false => self.bucket_url(&self.bucket, &self.region),
         }
     }
 
-    pub fn object_url(&self, object: &'a str) -> String {
-        format!("{}/{}", self.base_url(), object)
+    /// 指定Bucket与区域的地址, 不使用`cname`
+    pub(crate) fn bucket_url(&self, bucket: &str, region: &str) -> oss::Result<String> {
+        if bucket.is_empty() {
+            return Err(Error::Config("bucket must be provided".to_string()));
+        }
+        let (host, path_style) = self.region_host(region)?;
+        Ok(match path_style || self.path_style {
+            true => format!("{}://{}/{}", self.schema(), host, bucket),
+            false => format!("{}://{}.{}", self.schema(), bucket, host),
+        })
+    }
+
+    pub fn object_url(&self, object: &str) -> oss::Result<String> {
+        Ok(format!("{}/{}", self.base_url()?, object))
     }
 
     fn schema(&self) -> String {
@@ -299,74 +1296,206 @@ impl<'a> Options<'a> {
         }
     }
 
-    // 当`cname`为true时,`endpoint`,`bucket`为必填,否则产生panic错误.
-    // 当internal为true时，忽略cname与endpoint
+    fn base_domain(&self) -> &str {
+        match self.base_domain.is_empty() {
+            true => oss::BASE_URL,
+            false => &self.base_domain,
+        }
+    }
+
+    // 当`cname`为true时,`endpoint`为必填,否则返回错误.
+    // 非外网域名类型(例如内网)时忽略cname与endpoint
     // 无论是否使用cname正确的设置region(location)与bucket
-    fn host(&self) -> String {
-        if self.internal == true {
-            format!(
-                "{}{}.{}",
-                self.region,
-                if self.internal { "-internal" } else { "" },
-                oss::BASE_URL
-            )
-        } else if self.cname == true {
+    fn host(&self) -> oss::Result<String> {
+        if self.endpoint_type == EndpointType::Public && self.cname {
             if self.endpoint.is_empty() {
-                panic!("Endpoint parameter must be provided.");
+                return Err(Error::Config(
+                    "endpoint must be provided when cname is enabled".to_string(),
+                ));
             }
-            self.endpoint.to_string()
-        } else {
-            format!("{}.{}", self.region, oss::BASE_URL)
+            endpoint::parse(&self.schema(), &self.endpoint)?;
+            return Ok(self.endpoint.to_string());
+        }
+        Ok(self.region_host(&self.region)?.0)
+    }
+
+    /// 区域对应的域名(不使用cname), 设置`endpoint`时使用`endpoint`;
+    /// 同时返回是否只能使用路径方式访问
+    fn region_host(&self, region: &str) -> oss::Result<(String, bool)> {
+        if self.endpoint_type == EndpointType::Public && !self.cname && !self.endpoint.is_empty() {
+            let path_style = endpoint::parse(&self.schema(), &self.endpoint)?;
+            return Ok((self.endpoint.to_string(), path_style));
         }
+        Ok((self.endpoint_type.host(region, self.base_domain())?, false))
     }
 
-    pub fn client(self) -> oss::Client<'a> {
+    pub fn client(self) -> oss::Client {
         oss::Client::new(self)
     }
+
+    /// 同步客户端, 需启用`blocking`特性
+    #[cfg(feature = "blocking")]
+    pub fn blocking_client(self) -> blocking::Client {
+        blocking::Client::from_options(self)
+    }
+}
+
+/// 异步执行模式(默认), builder的`execute`返回`Future`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Async;
+
+/// 为builder生成`execute`方法, 异步与同步(`blocking`特性)两种模式共用同一个异步实现
+macro_rules! builder_execute {
+    ($builder:ident, $(fn $name:ident => $inner:ident -> $output:ty;)+) => {
+        impl<'a> $builder<'a, $crate::oss::Async> {
+            $(
+                pub async fn $name(&self) -> $output {
+                    self.$inner().await
+                }
+            )+
+        }
+
+        #[cfg(feature = "blocking")]
+        impl<'a> $builder<'a, $crate::oss::blocking::Blocking> {
+            $(
+                /// 同步执行, 不能在异步运行时中调用
+                pub fn $name(&self) -> $output {
+                    $crate::oss::blocking::block_on(self.$inner())
+                }
+            )+
+        }
+    };
 }
+pub(crate) use builder_execute;
 
+/// 客户端自身持有配置, 克隆开销很小(仅增加引用计数), 可在线程、任务间共享
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # async fn run() {
+/// let client = oss::Options::new().with_bucket("xtoss-ex").client();
+/// let handle = tokio::spawn({
+///     let client = client.clone();
+///     async move { client.GetObject("example/ex1.txt").execute().await }
+/// });
+/// # }
+/// ```
+///
+/// 类型参数`M`为执行模式, 默认`Async`; 启用`blocking`特性后可通过`Options::blocking_client`
+/// 创建同步客户端`blocking::Client`, 二者的builder接口相同
 #[derive(Debug, Default, Clone)]
-pub struct Client<'a> {
-    options: Options<'a>,
-    request: Request<'a>,
+pub struct Client<M = Async> {
+    options: Arc<Options>,
+    request: Arc<Request>,
+    mode: PhantomData<M>,
+}
+
+impl Client {
+    pub fn new(options: Options) -> Self {
+        Self::from_options(options)
+    }
 }
 
-impl<'a> Client<'a> {
-    pub fn new(options: Options<'a>) -> Self {
-        let request = self::Request::new()
-            .with_access_key_id(options.access_key_id)
-            .with_access_key_secret(options.access_key_secret)
-            .with_sts_token((!options.sts_token.is_empty()).then_some(options.sts_token));
-        Self { options, request }
+impl<M> Client<M> {
+    fn from_options(options: Options) -> Self {
+        let mut request = self::Request::new()
+            .with_access_key_id(&options.access_key_id)
+            .with_access_key_secret(&options.access_key_secret)
+            .with_sts_token((!options.sts_token.is_empty()).then_some(&options.sts_token))
+            .with_credentials_provider(options.credentials_provider.clone())
+            .with_region(&options.region)
+            .with_signature_version(options.signature_version)
+            .with_additional_headers(&options.additional_headers)
+            .with_retry_policy(options.retry_policy)
+            .with_interceptors(options.interceptors.clone())
+            .with_metrics(options.metrics.clone())
+            .with_upload_limiter(options.upload_limiter.clone())
+            .with_download_limiter(options.download_limiter.clone())
+            .with_concurrency_limit(options.max_data_requests, options.max_control_requests)
+            .with_read_timeout(options.read_timeout);
+        match &options.transport {
+            Some(transport) => request = request.with_transport(transport.clone()),
+            None => match options.client_options.build() {
+                Ok(client) => {
+                    request = request.with_transport(Arc::new(ReqwestTransport::new(client)))
+                }
+                Err(message) => request = request.with_config_error(Some(message)),
+            },
+        }
+        Self {
+            options: Arc::new(options),
+            request: Arc::new(request),
+            mode: PhantomData,
+        }
     }
 
     pub fn options(&self) -> &Options {
         &self.options
     }
 
-    pub fn region(&self) -> &'a str {
-        self.options.region
+    pub fn region(&self) -> &str {
+        &self.options.region
     }
 
-    pub fn bucket(&self) -> &'a str {
-        self.options.bucket
+    pub fn bucket(&self) -> &str {
+        &self.options.bucket
     }
 
-    pub fn root_url(&self) -> String {
+    pub fn root_url(&self) -> oss::Result<String> {
         self.options.root_url()
     }
 
-    pub fn base_url(&self) -> String {
+    pub fn base_url(&self) -> oss::Result<String> {
         self.options.base_url()
     }
 
-    pub fn object_url(&self, object: &'a str) -> String {
+    pub fn object_url(&self, object: &str) -> oss::Result<String> {
         self.options.object_url(object)
     }
 
     pub fn timeout(&self) -> u64 {
         self.options.timeout
     }
+
+    pub fn signature_version(&self) -> SignatureVersion {
+        self.options.signature_version
+    }
+
+    /// 本地时钟与OSS服务端时钟的偏差, 签名时自动校正
+    pub fn clock_offset(&self) -> chrono::Duration {
+        self.request.clock_offset()
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.options.retry_policy
+    }
+
+    pub fn crc64_check(&self) -> bool {
+        self.options.crc64_check
+    }
+
+    pub fn compute_content_md5(&self) -> bool {
+        self.options.compute_content_md5
+    }
+
+    /// 访问指定Bucket与区域的客户端, 共享连接池、凭证、限速、并发限制与时钟偏差
+    ///
+    /// 新客户端不使用`cname`
+    pub fn bucket_client(&self, bucket: impl Into<String>, region: impl Into<String>) -> Self {
+        let region = region.into();
+        let mut options = (*self.options).clone();
+        options.bucket = bucket.into();
+        options.region = region.clone();
+        if options.cname {
+            options.cname = false;
+            options.endpoint.clear();
+        }
+        Self {
+            options: Arc::new(options),
+            request: Arc::new((*self.request).clone().with_region(region)),
+            mode: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -385,11 +1514,11 @@ pub mod tests {
             .with_internal(true)
             .with_secret(true);
         assert_eq!(
-            options.root_url(),
+            options.root_url().unwrap(),
             "https://oss-cn-hangzhou-internal.aliyuncs.com"
         );
         assert_eq!(
-            options.base_url(),
+            options.base_url().unwrap(),
             "https://xuetube.oss-cn-shanghai-internal.aliyuncs.com"
         );
     }
@@ -408,9 +1537,35 @@ pub mod tests {
         let root_url = "https://oss-cn-hangzhou.aliyuncs.com";
         let base_url = "https://xtoss-ex.oss-cn-shanghai.aliyuncs.com";
 
-        assert_eq!(options.host(), host);
-        assert_eq!(options.root_url(), root_url);
-        assert_eq!(options.base_url(), base_url);
+        assert_eq!(options.host().unwrap(), host);
+        assert_eq!(options.root_url().unwrap(), root_url);
+        assert_eq!(options.base_url().unwrap(), base_url);
+    }
+
+    #[tokio::test]
+    async fn client_send_static() {
+        fn assert_send_static<T: Send + 'static>(value: T) -> T {
+            value
+        }
+        let client = oss::Options::new()
+            .with_access_key_id(String::from("access_key_id"))
+            .with_access_key_secret(String::from("access_key_secret"))
+            .with_bucket(String::from("xtoss-ex"))
+            .client();
+        let future = assert_send_static({
+            let client = client.clone();
+            async move { client.GetObject("example/ex1.txt").execute().await }
+        });
+        drop(future);
+        let url = tokio::spawn({
+            let client = client.clone();
+            async move { client.PresignObject("example/ex1.txt").execute().await }
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(url.starts_with("http://xtoss-ex.oss-cn-hangzhou.aliyuncs.com/example/ex1.txt?"));
+        assert_eq!(client.bucket(), "xtoss-ex");
     }
 
     #[test]
@@ -431,8 +1586,8 @@ pub mod tests {
         let root_url = "https://oss-cn-hangzhou.aliyuncs.com";
         let base_url = "https://cdn.xuetube.com";
 
-        assert_eq!(options.host(), host);
-        assert_eq!(options.root_url(), root_url);
-        assert_eq!(options.base_url(), base_url);
+        assert_eq!(options.host().unwrap(), host);
+        assert_eq!(options.root_url().unwrap(), root_url);
+        assert_eq!(options.base_url().unwrap(), base_url);
     }
 }
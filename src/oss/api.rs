@@ -1,5 +1,9 @@
 use super::{
+    crc64::{self, Crc64Mismatch},
+    credentials::CredentialsError,
+    entities::error::ErrorCode,
     http::{self, HeaderMap, StatusCode, Url},
+    transport::{ByteStream, TransportError},
     Bytes, Response,
 };
 use base64::{engine::general_purpose, Engine as _};
@@ -16,6 +20,9 @@ pub struct ErrorMessage {
     pub request_id: String,
     #[serde(rename(deserialize = "HostId"))]
     pub host_id: String,
+    /// 访问域名与Bucket所在区域不符时, 应使用的域名, 例如`oss-cn-shanghai.aliyuncs.com`
+    #[serde(rename(deserialize = "Endpoint"))]
+    pub endpoint: Option<String>,
     #[serde(rename(deserialize = "EC"))]
     pub ec: Option<String>,
     #[serde(rename(deserialize = "RecommendDoc"))]
@@ -30,6 +37,18 @@ pub struct ErrorMessage {
     pub string_to_sign_bytes: Option<String>,
 }
 
+impl ErrorMessage {
+    /// 错误码, `Code`为空时根据`EC`解析
+    pub fn error_code(&self) -> ErrorCode {
+        match (self.code.is_empty(), &self.ec) {
+            (true, Some(ec)) => {
+                ErrorCode::from_ec(ec).unwrap_or(ErrorCode::Unknown(ec.to_string()))
+            }
+            _ => ErrorCode::from(self.code.as_str()),
+        }
+    }
+}
+
 impl fmt::Display for ErrorMessage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[{}]: {}", self.code, self.message)
@@ -62,13 +81,10 @@ impl<T> ApiData<T> {
         self.content
     }
 
-    pub fn request_id(&self) -> String {
+    pub fn request_id(&self) -> Option<&str> {
         self.headers
             .get("x-oss-request-id")
-            .unwrap()
-            .to_str()
-            .unwrap()
-            .into()
+            .and_then(|value| value.to_str().ok())
     }
 
     pub fn content_length(&self) -> Option<u64> {
@@ -77,69 +93,243 @@ impl<T> ApiData<T> {
             .and_then(|value| value.to_str().ok())
             .and_then(|value| value.parse().ok())
     }
+
+    /// 响应头`x-oss-hash-crc64ecma`中对象的CRC64
+    pub fn crc64(&self) -> Option<u64> {
+        crc64::from_headers(&self.headers)
+    }
+}
+
+/// 请求错误
+#[derive(Debug)]
+pub enum Error {
+    /// 网络传输错误
+    Transport(TransportError),
+    /// OSS返回的错误消息
+    Service(Box<ApiData<ErrorMessage>>),
+    /// 响应内容解析失败
+    Decode(quick_xml::DeError),
+    /// 配置或请求参数无效
+    Config(String),
+    /// 获取访问凭证失败
+    Credentials(CredentialsError),
+    /// 读写本地文件等IO错误
+    Io(std::io::Error),
+    /// 数据的CRC64与OSS返回的不一致
+    Crc64Mismatch(Crc64Mismatch),
+}
+
+impl Error {
+    /// OSS返回的错误消息, 其他错误返回`None`
+    pub fn service(&self) -> Option<&ApiData<ErrorMessage>> {
+        match self {
+            Self::Service(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// OSS错误码, 其他错误返回`None`
+    pub fn code(&self) -> Option<ErrorCode> {
+        self.service().map(|data| data.content.error_code())
+    }
+
+    /// 目标资源不存在, `HEAD`请求没有错误消息时根据状态码判断
+    pub fn is_not_found(&self) -> bool {
+        match self.code() {
+            Some(ErrorCode::Unknown(_)) | None => self.status() == Some(StatusCode::NOT_FOUND),
+            Some(code) => code.is_not_found(),
+        }
+    }
+
+    /// 请求被限流
+    pub fn is_throttled(&self) -> bool {
+        self.code().is_some_and(|code| code.is_throttled())
+            || matches!(
+                self.status(),
+                Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+            )
+    }
+
+    /// 临时性错误, 包括网络超时、连接失败、限流与服务端错误
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Transport(e) => e.is_timeout() || e.is_connect(),
+            Self::Service(data) => {
+                data.content.error_code().is_retryable()
+                    || data.status.is_server_error()
+                    || data.status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+
+    /// 身份验证或授权失败
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, Self::Credentials(_)) || self.code().is_some_and(|code| code.is_auth_error())
+    }
+
+    /// OSS提示的正确访问域名, 例如`oss-cn-shanghai.aliyuncs.com`
+    pub fn endpoint(&self) -> Option<&str> {
+        self.service()
+            .and_then(|data| data.content.endpoint.as_deref())
+            .filter(|endpoint| !endpoint.is_empty())
+    }
+
+    /// 响应状态码, 未收到响应时返回`None`
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Service(data) => Some(data.status),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "transport error: {}", e),
+            Self::Service(data) => write!(f, "oss error {}: {}", data.status, data.content),
+            Self::Decode(e) => write!(f, "decode error: {}", e),
+            Self::Config(message) => write!(f, "config error: {}", message),
+            Self::Credentials(e) => write!(f, "{}", e),
+            Self::Io(e) => write!(f, "io error: {}", e),
+            Self::Crc64Mismatch(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(e) => Some(e),
+            Self::Decode(e) => Some(e),
+            Self::Credentials(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Crc64Mismatch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransportError> for Error {
+    fn from(value: TransportError) -> Self {
+        // 下载数据流中的CRC64校验失败
+        match value.into_crc64_mismatch() {
+            Ok(mismatch) => Self::Crc64Mismatch(mismatch),
+            Err(value) => Self::Transport(value),
+        }
+    }
+}
+
+impl From<Crc64Mismatch> for Error {
+    fn from(value: Crc64Mismatch) -> Self {
+        Self::Crc64Mismatch(value)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        Self::Transport(value.into())
+    }
+}
+
+impl From<quick_xml::DeError> for Error {
+    fn from(value: quick_xml::DeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
 }
 
+impl From<CredentialsError> for Error {
+    fn from(value: CredentialsError) -> Self {
+        Self::Credentials(value)
+    }
+}
 
-pub type ApiResponse<T> = Result<ApiData<T>, ApiData<ErrorMessage>>;
 // api 返回体， 包含请求错误， 和api返回数据
-pub type ApiResult<T = ()> = Result<ApiResponse<T>, reqwest::Error>;
+pub type ApiResult<T = ()> = Result<ApiData<T>, Error>;
 
-pub(crate) struct ApiResponseFrom(reqwest::Response);
+pub(crate) struct ApiResponseFrom(Response);
 
 impl ApiResponseFrom {
-    pub(crate) async fn fail_message(resp: Response) -> ApiData<ErrorMessage> {
-        assert!(!resp.status().is_success());
+    pub(crate) async fn fail_message(resp: Response) -> Error {
+        let telemetry = resp.telemetry().clone();
         let url = resp.url().clone();
-        let status = resp.status().clone();
+        let status = resp.status();
         let headers = resp.headers().clone();
-        let info = match resp.headers().contains_key("x-oss-err") {
-            true => {
-                let info = resp.headers().get("x-oss-err").unwrap();
-                general_purpose::STANDARD.decode(info).unwrap().to_vec()
-            }
-            false => resp.bytes().await.unwrap().to_vec(),
+        let info = match headers
+            .get("x-oss-err")
+            .and_then(|info| general_purpose::STANDARD.decode(info).ok())
+        {
+            Some(info) => info,
+            None => match resp.bytes().await {
+                Ok(info) => info.to_vec(),
+                Err(e) => return e.into(),
+            },
         };
         let content = String::from_utf8_lossy(&info);
-        let content = if content.is_empty() {
-            ErrorMessage::default()
-        } else {
-            quick_xml::de::from_str(&content).unwrap()
+        let content = match quick_xml::de::from_str(&content) {
+            Ok(content) => content,
+            // HEAD请求或网关返回的错误没有可解析的消息体, 保留原始内容
+            Err(_) => ErrorMessage {
+                message: content.to_string(),
+                request_id: headers
+                    .get("x-oss-request-id")
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+                ec: headers
+                    .get("x-oss-ec")
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_string()),
+                ..ErrorMessage::default()
+            },
         };
-        ApiData {
+        let error = Error::Service(Box::new(ApiData {
             url,
             status,
             headers,
             content,
-        }
+        }));
+        telemetry.record_error(&error);
+        error
     }
 
-    pub(crate) async fn bytes_data(resp: Response) -> ApiData<Bytes> {
-        assert!(resp.status().is_success());
+    pub(crate) async fn bytes_data(resp: Response) -> ApiResult<Bytes> {
         let url = resp.url().clone();
-        let status = resp.status().clone();
+        let status = resp.status();
         let headers = resp.headers().clone();
-        let content = resp.bytes().await.unwrap();
-        ApiData {
+        let content = resp.bytes().await?;
+        Ok(ApiData {
             url,
             status,
             headers,
             content,
-        }
+        })
     }
 
-    pub(crate) async fn to_type<T>(self) -> ApiResponse<T>
+    pub(crate) async fn into_type<T>(self) -> ApiResult<T>
     where
         T: for<'a> Deserialize<'a>,
     {
         let resp = self.0;
         if resp.status().is_success() {
+            let telemetry = resp.telemetry().clone();
             let url = resp.url().clone();
-            let status = resp.status().clone();
+            let status = resp.status();
             let headers = resp.headers().clone();
-            let content = resp.bytes().await.unwrap();
+            let content = resp.bytes().await?;
             let content = String::from_utf8_lossy(&content);
-            // dbg!(&content);
-            let content: T = quick_xml::de::from_str(&content).unwrap();
+            let content: T = quick_xml::de::from_str(&content).map_err(|e| {
+                let error = Error::from(e);
+                telemetry.record_error(&error);
+                error
+            })?;
 
             Ok(ApiData {
                 url,
@@ -148,29 +338,40 @@ impl ApiResponseFrom {
                 content,
             })
         } else {
-            let data_fail_message = Self::fail_message(resp).await;
-            Err(data_fail_message)
+            Err(Self::fail_message(resp).await)
+        }
+    }
+
+    pub(crate) async fn into_bytes(self) -> ApiResult<Bytes> {
+        let resp = self.0;
+        if resp.status().is_success() {
+            Self::bytes_data(resp).await
+        } else {
+            Err(Self::fail_message(resp).await)
         }
     }
 
-    pub(crate) async fn to_bytes(self) -> ApiResponse<Bytes> {
+    pub(crate) async fn into_stream(self) -> ApiResult<ByteStream> {
         let resp = self.0;
         if resp.status().is_success() {
-            let data = Self::bytes_data(resp).await;
-            Ok(data)
+            Ok(ApiData {
+                url: resp.url().clone(),
+                status: resp.status(),
+                headers: resp.headers().clone(),
+                content: ByteStream::new(resp),
+            })
         } else {
-            let data_fail_message = Self::fail_message(resp).await;
-            Err(data_fail_message)
+            Err(Self::fail_message(resp).await)
         }
     }
 
-    pub(crate) async fn to_text(self) -> ApiResponse<String> {
+    pub(crate) async fn into_text(self) -> ApiResult<String> {
         let resp = self.0;
         if resp.status().is_success() {
             let url = resp.url().clone();
-            let status = resp.status().clone();
+            let status = resp.status();
             let headers = resp.headers().clone();
-            let content = resp.text().await.unwrap();
+            let content = resp.text().await?;
             Ok(ApiData {
                 url,
                 status,
@@ -178,23 +379,21 @@ impl ApiResponseFrom {
                 content,
             })
         } else {
-            let data_fail_message = Self::fail_message(resp).await;
-            Err(data_fail_message)
+            Err(Self::fail_message(resp).await)
         }
     }
 
-    pub(crate) async fn to_empty(self) -> ApiResponse<()> {
+    pub(crate) async fn into_empty(self) -> ApiResult {
         let resp = self.0;
         if resp.status().is_success() {
             Ok(ApiData {
                 url: resp.url().clone(),
-                status: resp.status().clone(),
+                status: resp.status(),
                 headers: resp.headers().clone(),
                 content: (),
             })
         } else {
-            let data_fail_message = Self::fail_message(resp).await;
-            Err(data_fail_message)
+            Err(Self::fail_message(resp).await)
         }
     }
 }
@@ -229,7 +428,106 @@ fn insert_custom_header<T: ToString + std::fmt::Display>(
     );
 }
 
+/// 单链接限速`x-oss-traffic-limit`的取值范围, 单位bit/s(100 KB/s ~ 100 MB/s)
+pub const TRAFFIC_LIMIT_RANGE: std::ops::RangeInclusive<u64> = 819_200..=838_860_800;
+
+fn insert_traffic_limit(headers: &mut http::HeaderMap, value: Option<u64>) -> Result<(), Error> {
+    if let Some(value) = value {
+        if !TRAFFIC_LIMIT_RANGE.contains(&value) {
+            return Err(Error::Config(format!(
+                "x-oss-traffic-limit must be between {} and {} bit/s, got {}",
+                TRAFFIC_LIMIT_RANGE.start(),
+                TRAFFIC_LIMIT_RANGE.end(),
+                value
+            )));
+        }
+        insert_custom_header(headers, "x-oss-traffic-limit", value);
+    }
+    Ok(())
+}
+
 pub(crate) mod bucket;
 pub(crate) mod objects;
 pub(crate) mod region;
 pub(crate) mod service;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oss;
+    use axum::{http::HeaderMap as AxumHeaderMap, routing::get, Router};
+
+    async fn client(
+        status: u16,
+        headers: &'static [(&'static str, &'static str)],
+        body: &'static str,
+    ) -> oss::Client {
+        let app = Router::new().route(
+            "/",
+            get(move || async move {
+                let mut map = AxumHeaderMap::new();
+                for (key, value) in headers {
+                    map.insert(*key, value.parse().unwrap());
+                }
+                (axum::http::StatusCode::from_u16(status).unwrap(), map, body)
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_cname(true)
+            .with_endpoint(addr.to_string())
+            .client()
+    }
+
+    #[tokio::test]
+    async fn api_result_decode_error() {
+        let client = client(200, &[], "not xml").await;
+        let result = client.GetBucketAcl().execute().await;
+        assert!(matches!(result, Err(Error::Decode(_))));
+    }
+
+    #[tokio::test]
+    async fn api_result_service_error() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>AccessDenied</Code>
+  <Message>The bucket you access does not belong to you.</Message>
+  <RequestId>5C3D9175B6FC201293AD****</RequestId>
+  <HostId>test.oss-cn-zhangjiakou.aliyuncs.com</HostId>
+  <EC>0003-00000001</EC>
+</Error>"#;
+        let client = client(
+            403,
+            &[("x-oss-request-id", "5C3D9175B6FC201293AD****")],
+            body,
+        )
+        .await;
+        let error = client.GetBucketAcl().execute().await.unwrap_err();
+        assert_eq!(error.status(), Some(StatusCode::FORBIDDEN));
+        let message = error.service().unwrap();
+        assert_eq!(message.request_id(), Some("5C3D9175B6FC201293AD****"));
+        assert_eq!(message.content.code, "AccessDenied");
+        assert_eq!(message.content.ec.as_deref(), Some("0003-00000001"));
+        assert_eq!(error.code(), Some(ErrorCode::AccessDenied));
+        assert!(error.is_auth_error());
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn api_result_service_error_without_body() {
+        let client = client(404, &[("x-oss-ec", "0026-00000001")], "").await;
+        let error = client.GetBucketAcl().execute().await.unwrap_err();
+        let message = error.service().unwrap();
+        assert_eq!(message.status(), &StatusCode::NOT_FOUND);
+        assert_eq!(message.request_id(), None);
+        assert_eq!(message.content.ec.as_deref(), Some("0026-00000001"));
+        assert_eq!(error.code(), Some(ErrorCode::NoSuchKey));
+        assert!(error.is_not_found());
+        assert!(error.to_string().starts_with("oss error 404 Not Found"));
+    }
+}
@@ -11,22 +11,30 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketTransferAccelerationBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketTransferAccelerationBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         enabled: Option<bool>,
     }
 
-    impl<'a> PutBucketTransferAccelerationBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, value: bool) -> Self {
+    impl<'a, M> PutBucketTransferAccelerationBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, value: bool) -> Self {
             Self {
                 client,
                 enabled: Some(value),
+                retry_policy: None,
             }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let res = format!("/{}/?transferAcceleration", self.client.bucket());
-            let url = format!("{}/?transferAcceleration", self.client.base_url());
+            let url = format!("{}/?transferAcceleration", self.client.base_url()?);
 
             let config = TransferAccelerationConfiguration {
                 enabled: self.enabled.unwrap(),
@@ -36,55 +44,79 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketTransferAcceleration")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::PUT)
                 .with_body(data)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        PutBucketTransferAccelerationBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
+
     //----------------------------------------------
-    pub struct GetBucketTransferAccelerationBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct GetBucketTransferAccelerationBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> GetBucketTransferAccelerationBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    impl<'a, M> GetBucketTransferAccelerationBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<TransferAccelerationConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<TransferAccelerationConfiguration> {
             let res = format!("/{}/?transferAcceleration", self.client.bucket());
-            let url = format!("{}/?transferAcceleration", self.client.base_url());
+            let url = format!("{}/?transferAcceleration", self.client.base_url()?);
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketTransferAcceleration")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetBucketTransferAccelerationBuilder,
+        fn execute => inner_execute -> api::ApiResult<TransferAccelerationConfiguration>;
+    }
 }
 
 /// # 传输加速`TransferAcceleration``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 接口用于为存储空间（Bucket）配置传输加速。开启传输加速后，可提升全球各地用户对OSS的访问速度，
     /// 适用于远距离数据传输、GB或TB级大文件上传和下载的场景。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbuckettransferacceleration)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_transfer_acceleration_put.rs)
     pub fn PutBucketTransferAcceleration(
-        &self,
+        &'a self,
         value: bool,
-    ) -> PutBucketTransferAccelerationBuilder<'_> {
+    ) -> PutBucketTransferAccelerationBuilder<'a, M> {
         PutBucketTransferAccelerationBuilder::new(&self, value)
     }
 
@@ -92,7 +124,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbuckettransferacceleration)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_transfer_acceleration_get.rs)
-    pub fn GetBucketTransferAcceleration(&self) -> GetBucketTransferAccelerationBuilder {
+    pub fn GetBucketTransferAcceleration(&'a self) -> GetBucketTransferAccelerationBuilder<'a, M> {
         GetBucketTransferAccelerationBuilder::new(&self)
     }
 }
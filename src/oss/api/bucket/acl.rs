@@ -6,20 +6,31 @@ use crate::oss::{
 };
 
 #[derive(Debug)]
-pub struct PutBucketAclBuilder<'a> {
-    client: &'a oss::Client<'a>,
+pub struct PutBucketAclBuilder<'a, M = oss::Async> {
+    client: &'a oss::Client<M>,
+    retry_policy: Option<oss::RetryPolicy>,
     acl: OssAcl,
 }
 
 #[allow(unused)]
-impl<'a> PutBucketAclBuilder<'a> {
-    pub fn new(client: &'a oss::Client, acl: OssAcl) -> Self {
-        Self { client, acl }
+impl<'a, M> PutBucketAclBuilder<'a, M> {
+    pub fn new(client: &'a oss::Client<M>, acl: OssAcl) -> Self {
+        Self {
+            client,
+            acl,
+            retry_policy: None,
+        }
     }
 
-    pub async fn execute(&self) -> api::ApiResult<()> {
+    /// 覆盖客户端的重试策略
+    pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+        self.retry_policy = Some(value);
+        self
+    }
+
+    async fn inner_execute(&self) -> api::ApiResult<()> {
         let res = format!("/{}/?{}", self.client.bucket(), "acl");
-        let url = { format!("{}/?{}", self.client.base_url(), "acl") };
+        let url = { format!("{}/?{}", self.client.base_url()?, "acl") };
 
         let mut headers = http::HeaderMap::new();
         insert_custom_header(&mut headers, "x-oss-acl", self.acl.to_string());
@@ -28,6 +39,8 @@ impl<'a> PutBucketAclBuilder<'a> {
             .client
             .request
             .task()
+            .with_operation("PutBucketAcl")
+            .with_retry_policy(self.retry_policy)
             .with_url(&url)
             .with_method(http::Method::PUT)
             .with_headers(headers)
@@ -35,43 +48,65 @@ impl<'a> PutBucketAclBuilder<'a> {
             .execute_timeout(self.client.timeout())
             .await?;
 
-        Ok(ApiResponseFrom(resp).to_empty().await)
+        ApiResponseFrom(resp).into_empty().await
     }
 }
 
-pub struct GetBucketAclBuilder<'a> {
-    client: &'a oss::Client<'a>,
+oss::builder_execute! {
+    PutBucketAclBuilder,
+    fn execute => inner_execute -> api::ApiResult<()>;
+}
+
+pub struct GetBucketAclBuilder<'a, M = oss::Async> {
+    client: &'a oss::Client<M>,
+    retry_policy: Option<oss::RetryPolicy>,
 }
 
-impl<'a> GetBucketAclBuilder<'a> {
-    pub(crate) fn new(client: &'a oss::Client) -> Self {
-        Self { client }
+impl<'a, M> GetBucketAclBuilder<'a, M> {
+    pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+        Self {
+            client,
+            retry_policy: None,
+        }
     }
 
-    pub async fn execute(&self) -> api::ApiResult<AccessControlPolicy> {
+    /// 覆盖客户端的重试策略
+    pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+        self.retry_policy = Some(value);
+        self
+    }
+
+    async fn inner_execute(&self) -> api::ApiResult<AccessControlPolicy> {
         let res = format!("/{}/?{}", self.client.bucket(), "acl");
-        let url = format!("{}/?{}", self.client.base_url(), "acl");
+        let url = format!("{}/?{}", self.client.base_url()?, "acl");
 
         let resp = self
             .client
             .request
             .task()
+            .with_operation("GetBucketAcl")
+            .with_retry_policy(self.retry_policy)
             .with_url(&url)
             .with_resource(&res)
             .execute_timeout(self.client.timeout())
             .await?;
-        Ok(ApiResponseFrom(resp).to_type().await)
+        ApiResponseFrom(resp).into_type().await
     }
 }
 
+oss::builder_execute! {
+    GetBucketAclBuilder,
+    fn execute => inner_execute -> api::ApiResult<AccessControlPolicy>;
+}
+
 /// # 权限控制`ACL``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketAcl接口用于设置或修改存储空间`Bucket`的访问权限`ACL`。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketacl)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_acl_put.rs)
-    pub fn PutBucketAcl(&self, acl: OssAcl) -> PutBucketAclBuilder {
+    pub fn PutBucketAcl(&'a self, acl: OssAcl) -> PutBucketAclBuilder<'a, M> {
         PutBucketAclBuilder::new(self, acl)
     }
 
@@ -80,7 +115,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketacl)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_acl_get.rs)
-    pub fn GetBucketAcl(&self) -> GetBucketAclBuilder {
+    pub fn GetBucketAcl(&'a self) -> GetBucketAclBuilder<'a, M> {
         GetBucketAclBuilder::new(&self)
     }
 }
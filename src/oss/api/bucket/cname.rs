@@ -14,14 +14,25 @@ pub mod builders {
         http,
     };
 
-    pub struct CreateCnameTokenBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct CreateCnameTokenBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         cname: &'a str,
     }
 
-    impl<'a> CreateCnameTokenBuilder<'a> {
-        pub fn new(client: &'a oss::Client, cname: &'a str) -> Self {
-            Self { client, cname }
+    impl<'a, M> CreateCnameTokenBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, cname: &'a str) -> Self {
+            Self {
+                client,
+                cname,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
         fn config(&self) -> String {
@@ -34,9 +45,9 @@ pub mod builders {
             quick_xml::se::to_string(&config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<CnameToken> {
+        async fn inner_execute(&self) -> api::ApiResult<CnameToken> {
             let res = format!("/{}/?cname&comp=token", self.client.bucket());
-            let url = format!("{}/?cname&comp=token", &self.client.base_url());
+            let url = format!("{}/?cname&comp=token", &self.client.base_url()?);
 
             let data = oss::Bytes::from(self.config());
 
@@ -44,6 +55,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("CreateCnameToken")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_resource(&res)
@@ -51,25 +64,41 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct GetCnameTokenBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        CreateCnameTokenBuilder,
+        fn execute => inner_execute -> api::ApiResult<CnameToken>;
+    }
+
+    pub struct GetCnameTokenBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         cname: &'a str,
     }
 
-    impl<'a> GetCnameTokenBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, cname: &'a str) -> Self {
-            Self { client, cname }
+    impl<'a, M> GetCnameTokenBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, cname: &'a str) -> Self {
+            Self {
+                client,
+                cname,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<CnameToken> {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<CnameToken> {
             let res = format!("/{}/?cname={}&comp=token", self.client.bucket(), self.cname);
             let url = format!(
                 "{}/?cname={}&comp=token",
-                self.client.base_url(),
+                self.client.base_url()?,
                 self.cname
             );
 
@@ -77,28 +106,43 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetCnameToken")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct PutCnameBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetCnameTokenBuilder,
+        fn execute => inner_execute -> api::ApiResult<CnameToken>;
+    }
+
+    pub struct PutCnameBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         bucket_cname_configuration: BucketCnameConfiguration,
     }
 
-    impl<'a> PutCnameBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutCnameBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 bucket_cname_configuration: BucketCnameConfiguration::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_config(mut self, value: BucketCnameConfiguration) -> Self {
             self.bucket_cname_configuration = value;
             self
@@ -108,9 +152,9 @@ pub mod builders {
             quick_xml::se::to_string(&self.bucket_cname_configuration).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?cname&comp=add", self.client.bucket());
-            let url = format!("{}/?cname&comp=add", self.client.base_url());
+            let url = format!("{}/?cname&comp=add", self.client.base_url()?);
 
             let data = oss::Bytes::from(self.config());
 
@@ -118,6 +162,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutCname")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_body(data)
@@ -125,44 +171,77 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct ListCnameBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutCnameBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> ListCnameBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct ListCnameBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> ListCnameBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListCnameResult> {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<ListCnameResult> {
             let res = format!("/{}/?{}", self.client.bucket(), "cname");
-            let url = format!("{}/?{}", self.client.base_url(), "cname");
+            let url = format!("{}/?{}", self.client.base_url()?, "cname");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("ListCname")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteCnameBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        ListCnameBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListCnameResult>;
+    }
+
+    pub struct DeleteCnameBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         cname: &'a str,
     }
 
-    impl<'a> DeleteCnameBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, cname: &'a str) -> Self {
-            Self { client, cname }
+    impl<'a, M> DeleteCnameBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, cname: &'a str) -> Self {
+            Self {
+                client,
+                cname,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
         fn config(&self) -> String {
@@ -172,9 +251,9 @@ pub mod builders {
             quick_xml::se::to_string(&config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?cname&comp=delete", self.client.bucket());
-            let url = format!("{}/?cname&comp=delete", self.client.base_url());
+            let url = format!("{}/?cname&comp=delete", self.client.base_url()?);
 
             let data = oss::Bytes::from(self.config());
 
@@ -182,6 +261,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteCname")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::POST)
@@ -189,19 +270,24 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteCnameBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 自定义域名`CNAME``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用CreateCnameToken接口创建域名所有权验证所需的CnameToken
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/createcnametoken)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cname_create_token.rs)
-    pub fn CreateCnameToken(&self, cname: &'a str) -> CreateCnameTokenBuilder<'_> {
+    pub fn CreateCnameToken(&'a self, cname: &'a str) -> CreateCnameTokenBuilder<'a, M> {
         CreateCnameTokenBuilder::new(self, cname)
     }
 
@@ -209,7 +295,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getcnametoken)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cname_get_token.rs)
-    pub fn GetCnameToken(&self, cname: &'a str) -> GetCnameTokenBuilder<'_> {
+    pub fn GetCnameToken(&'a self, cname: &'a str) -> GetCnameTokenBuilder<'a, M> {
         GetCnameTokenBuilder::new(self, cname)
     }
 
@@ -217,7 +303,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putcname)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cname_put.rs)
-    pub fn PutCname(&self) -> PutCnameBuilder<'_> {
+    pub fn PutCname(&'a self) -> PutCnameBuilder<'a, M> {
         PutCnameBuilder::new(self)
     }
 
@@ -225,7 +311,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listcname)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cname_list.rs)
-    pub fn ListCname(&self) -> ListCnameBuilder<'_> {
+    pub fn ListCname(&'a self) -> ListCnameBuilder<'a, M> {
         ListCnameBuilder::new(self)
     }
 
@@ -233,7 +319,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletecname)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cname_del.rs)
-    pub fn DeleteCname(&self, cname: &'a str) -> DeleteCnameBuilder<'_> {
+    pub fn DeleteCname(&'a self, cname: &'a str) -> DeleteCnameBuilder<'a, M> {
         DeleteCnameBuilder::new(self, cname)
     }
 }
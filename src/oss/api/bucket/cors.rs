@@ -17,34 +17,44 @@ pub mod builders {
         util::AllowedHeaderItem,
     };
 
-    pub struct PutBucketCorsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketCorsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         config: CORSConfiguration,
     }
 
     #[allow(unused)]
-    impl<'a> PutBucketCorsBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketCorsBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 config: CORSConfiguration::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_config(mut self, value: CORSConfiguration) -> Self {
             self.config = value;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "cors");
-            let url = format!("{}/?{}", self.client.base_url(), "cors");
+            let url = format!("{}/?{}", self.client.base_url()?, "cors");
             let content = quick_xml::se::to_string(&self.config).unwrap();
             let data = oss::Bytes::from(content);
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketCors")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
@@ -52,82 +62,129 @@ pub mod builders {
                 .execute_timeout(self.client.options.timeout)
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketCorsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketCorsBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketCorsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketCorsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketCorsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<CORSConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<CORSConfiguration> {
             let res = format!("/{}/?{}", self.client.bucket(), "cors");
-            let url = format!("{}/?{}", self.client.base_url(), "cors");
+            let url = format!("{}/?{}", self.client.base_url()?, "cors");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketCors")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.options.timeout)
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteBucketCorsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketCorsBuilder,
+        fn execute => inner_execute -> api::ApiResult<CORSConfiguration>;
+    }
+
+    pub struct DeleteBucketCorsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> DeleteBucketCorsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    impl<'a, M> DeleteBucketCorsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
-            let url = format!("{}/?{}", self.client.base_url(), "cors");
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
+            let url = format!("{}/?{}", self.client.base_url()?, "cors");
             let res = format!("/{}/?{}", self.client.bucket(), "cors");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketCors")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute_timeout(self.client.options.timeout)
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct OptionsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        DeleteBucketCorsBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct OptionsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         origin: &'a str,
         request_method: http::Method,
         request_headers: AllowedHeaderItem,
     }
 
-    impl<'a> OptionsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> OptionsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 origin: "",
                 request_method: http::Method::GET,
                 request_headers: AllowedHeaderItem::Any,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_origin(mut self, value: &'a str) -> Self {
             self.origin = value;
             self
@@ -143,9 +200,9 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/{}", self.client.bucket(), self.object);
-            let url = self.client.object_url(self.object);
+            let url = self.client.object_url(self.object)?;
 
             let mut headers = http::header::HeaderMap::new();
             insert_header(&mut headers, ORIGIN, self.origin);
@@ -166,6 +223,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("Options")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::OPTIONS)
                 .with_headers(headers)
@@ -173,20 +232,25 @@ pub mod builders {
                 .execute_timeout(self.client.options.timeout)
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        OptionsBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 跨域资源共享（CORS）
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutBucketCors接口为指定的存储空间`Bucket`设置跨域资源共享CORS
     ///`Cross-Origin Resource Sharing`规则
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketcors)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cors_put.rs)
-    pub fn PutBucketCors(&self) -> PutBucketCorsBuilder {
+    pub fn PutBucketCors(&'a self) -> PutBucketCorsBuilder<'a, M> {
         PutBucketCorsBuilder::new(&self)
     }
 
@@ -195,7 +259,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketcors)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cors_get.rs)
-    pub fn GetBucketCors(&self) -> GetBucketCorsBuilder {
+    pub fn GetBucketCors(&'a self) -> GetBucketCorsBuilder<'a, M> {
         GetBucketCorsBuilder::new(&self)
     }
 
@@ -204,7 +268,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketcors)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cors_del.rs)
-    pub fn DeleteBucketCors(&self) -> DeleteBucketCorsBuilder {
+    pub fn DeleteBucketCors(&'a self) -> DeleteBucketCorsBuilder<'a, M> {
         DeleteBucketCorsBuilder::new(&self)
     }
 
@@ -214,7 +278,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/options)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_cors_options.rs)
-    pub fn Options(&self, object: &'a str) -> OptionsBuilder {
+    pub fn Options(&'a self, object: &'a str) -> OptionsBuilder<'a, M> {
         OptionsBuilder::new(&self, object)
     }
 }
@@ -15,24 +15,32 @@ pub mod builders {
     };
 
     #[allow(unused)]
-    pub struct PutBucketEncryptionBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketEncryptionBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         algorithm: SSEAlgorithm,
         data_encryption: Option<&'a str>,
         master_key_id: Option<&'a str>,
     }
 
     #[allow(unused)]
-    impl<'a> PutBucketEncryptionBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketEncryptionBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 algorithm: SSEAlgorithm::default(),
                 data_encryption: None,
                 master_key_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_algorithm(mut self, value: SSEAlgorithm) -> Self {
             self.algorithm = value;
             self
@@ -48,9 +56,9 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "encryption");
-            let url = format!("{}/?{}", self.client.base_url(), "encryption");
+            let url = format!("{}/?{}", self.client.base_url()?, "encryption");
 
             let mut content = ServerSideEncryptionRule {
                 apply_server_side_encryption_by_default: ApplyServerSideEncryptionByDefault {
@@ -66,6 +74,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketEncryption")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
@@ -73,70 +83,109 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketEncryptionBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketEncryptionBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketEncryptionBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketEncryptionBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketEncryptionBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ServerSideEncryptionRule> {
+        async fn inner_execute(&self) -> api::ApiResult<ServerSideEncryptionRule> {
             let res = format!("/{}/?{}", self.client.bucket(), "encryption");
-            let url = format!("{}/?{}", self.client.base_url(), "encryption");
+            let url = format!("{}/?{}", self.client.base_url()?, "encryption");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketEncryption")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteBucketEncryptionBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketEncryptionBuilder,
+        fn execute => inner_execute -> api::ApiResult<ServerSideEncryptionRule>;
     }
 
-    impl<'a> DeleteBucketEncryptionBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct DeleteBucketEncryptionBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> DeleteBucketEncryptionBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "encryption");
-            let url = format!("{}/?{}", self.client.base_url(), "encryption");
+            let url = format!("{}/?{}", self.client.base_url()?, "encryption");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketEncryption")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketEncryptionBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 加密（Encryption）
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketEncryption接口用于配置存储空间`Bucket`的加密规则。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketencryption)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_encryption_put.rs)
-    pub fn PutBucketEncryption(&self) -> PutBucketEncryptionBuilder {
+    pub fn PutBucketEncryption(&'a self) -> PutBucketEncryptionBuilder<'a, M> {
         PutBucketEncryptionBuilder::new(self)
     }
 
@@ -144,7 +193,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketencryption)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_encryption_get.rs)
-    pub fn GetBucketEncryption(&self) -> GetBucketEncryptionBuilder {
+    pub fn GetBucketEncryption(&'a self) -> GetBucketEncryptionBuilder<'a, M> {
         GetBucketEncryptionBuilder::new(&self)
     }
 
@@ -152,7 +201,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketencryption)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_encryption_del.rs)
-    pub fn DeleteBucketEncryption(&self) -> DeleteBucketEncryptionBuilder {
+    pub fn DeleteBucketEncryption(&'a self) -> DeleteBucketEncryptionBuilder<'a, M> {
         DeleteBucketEncryptionBuilder::new(&self)
     }
 }
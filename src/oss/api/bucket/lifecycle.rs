@@ -13,27 +13,35 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketLifecycleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketLifecycleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         config: LifecycleConfiguration,
     }
 
-    impl<'a> PutBucketLifecycleBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketLifecycleBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 config: LifecycleConfiguration::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_config(mut self, value: LifecycleConfiguration) -> Self {
             self.config = value;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "lifecycle");
-            let url = format!("{}/?{}", self.client.base_url(), "lifecycle");
+            let url = format!("{}/?{}", self.client.base_url()?, "lifecycle");
 
             let config = quick_xml::se::to_string(&self.config).unwrap();
             let data = oss::Bytes::from(config);
@@ -42,72 +50,113 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketLifecycle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
                 .with_body(data)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketLifecycleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketLifecycleBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketLifecycleBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketLifecycleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketLifecycleBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<LifecycleConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<LifecycleConfiguration> {
             let res = format!("/{}/?{}", self.client.bucket(), "lifecycle");
-            let url = format!("{}/?{}", self.client.base_url(), "lifecycle");
+            let url = format!("{}/?{}", self.client.base_url()?, "lifecycle");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketLifecycle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteBucketLifecycleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketLifecycleBuilder,
+        fn execute => inner_execute -> api::ApiResult<LifecycleConfiguration>;
     }
 
-    impl<'a> DeleteBucketLifecycleBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct DeleteBucketLifecycleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> DeleteBucketLifecycleBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "lifecycle");
-            let url = format!("{}/?{}", self.client.base_url(), "lifecycle");
+            let url = format!("{}/?{}", self.client.base_url()?, "lifecycle");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketLifecycle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketLifecycleBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 生命周期`Lifecycle``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 您可以基于最后一次修改时间以及最后一次访问时间的策略创建生命周期规则，定期将存储空间
     /// `Bucket`内的多个文件`Object`转储为指定存储类型,或者将过期的Object和碎片删除,
     /// 从而节省存储费用。本文为您介绍如何调用PutBucketLifecycle接口为存储空间`Bucket``
@@ -116,7 +165,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketlifecycle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_lifecycle_put.rs)
-    pub fn PutBucketLifecycle(&self) -> PutBucketLifecycleBuilder {
+    pub fn PutBucketLifecycle(&'a self) -> PutBucketLifecycleBuilder<'a, M> {
         PutBucketLifecycleBuilder::new(&self)
     }
 
@@ -125,7 +174,7 @@ impl<'a> oss::Client<'a> {
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketlifecycle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_lifecycle_get.rs)
     #[allow(non_snake_case)]
-    pub fn GetBucketLifecycle(&self) -> GetBucketLifecycleBuilder {
+    pub fn GetBucketLifecycle(&'a self) -> GetBucketLifecycleBuilder<'a, M> {
         GetBucketLifecycleBuilder::new(&self)
     }
 
@@ -136,7 +185,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketlifecycle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_lifecycle_del.rs)
-    pub fn DeleteBucketLifecycle(&self) -> DeleteBucketLifecycleBuilder {
+    pub fn DeleteBucketLifecycle(&'a self) -> DeleteBucketLifecycleBuilder<'a, M> {
         DeleteBucketLifecycleBuilder::new(&self)
     }
 }
@@ -12,23 +12,31 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketLoggingBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketLoggingBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         enabled: Option<bool>,
         bucket: Option<&'a str>,
         target_prefix: Option<&'a str>,
     }
 
-    impl<'a> PutBucketLoggingBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketLoggingBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 enabled: None,
                 bucket: None,
                 target_prefix: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_enabled(mut self, value: bool) -> Self {
             self.enabled = Some(value);
             self
@@ -65,9 +73,9 @@ pub mod builders {
             quick_xml::se::to_string(&config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.options.bucket, "logging");
-            let url = format!("{}/?{}", self.client.options.base_url(), "logging");
+            let url = format!("{}/?{}", self.client.options.base_url()?, "logging");
             let config = self.config();
             let data = oss::Bytes::from(config);
 
@@ -75,78 +83,119 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketLogging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_body(data)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketLoggingBuilder<'a> {
-        pub client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketLoggingBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketLoggingBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketLoggingBuilder<'a, M = oss::Async> {
+        pub client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketLoggingBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<BucketLoggingStatus> {
+        async fn inner_execute(&self) -> api::ApiResult<BucketLoggingStatus> {
             let res = format!("/{}/?{}", self.client.bucket(), "logging");
-            let url = format!("{}/?{}", self.client.base_url(), "logging");
+            let url = format!("{}/?{}", self.client.base_url()?, "logging");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketLogging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteBucketLoggingBuilder<'a> {
-        pub client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketLoggingBuilder,
+        fn execute => inner_execute -> api::ApiResult<BucketLoggingStatus>;
     }
 
-    impl<'a> DeleteBucketLoggingBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct DeleteBucketLoggingBuilder<'a, M = oss::Async> {
+        pub client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> DeleteBucketLoggingBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "logging");
-            let url = format!("{}/?{}", self.client.base_url(), "logging");
+            let url = format!("{}/?{}", self.client.base_url()?, "logging");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketLogging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketLoggingBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 日志管理`Logging``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketLogging接口用于为存储空间`Bucket`开启日志转存功能，
     /// 可将OSS的访问日志按照固定命名规则,以小时为单位生成日志文件写入您
     /// 指定的Bucket。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketlogging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_logging_put.rs)
-    pub fn PutBucketLogging(&self) -> PutBucketLoggingBuilder {
+    pub fn PutBucketLogging(&'a self) -> PutBucketLoggingBuilder<'a, M> {
         PutBucketLoggingBuilder::new(self)
     }
 
@@ -155,7 +204,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketlogging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_logging_get.rs)
-    pub fn GetBucketLogging(&self) -> GetBucketLoggingBuilder {
+    pub fn GetBucketLogging(&'a self) -> GetBucketLoggingBuilder<'a, M> {
         GetBucketLoggingBuilder::new(&self)
     }
 
@@ -164,7 +213,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketlogging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_logging_del.rs)
-    pub fn DeleteBucketLogging(&self) -> DeleteBucketLoggingBuilder {
+    pub fn DeleteBucketLogging(&'a self) -> DeleteBucketLoggingBuilder<'a, M> {
         DeleteBucketLoggingBuilder::new(&self)
     }
 }
@@ -6,31 +6,41 @@ pub mod builders {
         api::{self, ApiResponseFrom},
         entities::payment::{Payer, RequestPaymentConfiguration},
     };
-    pub struct PutBucketRequestPaymentBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketRequestPaymentBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         config: RequestPaymentConfiguration,
     }
 
-    impl<'a> PutBucketRequestPaymentBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, payer: Payer) -> Self {
+    impl<'a, M> PutBucketRequestPaymentBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, payer: Payer) -> Self {
             Self {
                 client,
                 config: RequestPaymentConfiguration { payer },
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         fn config(&self) -> String {
             quick_xml::se::to_string(&self.config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?requestPayment", self.client.bucket());
-            let url = format!("{}?requestPayment", self.client.base_url());
+            let url = format!("{}?requestPayment", self.client.base_url()?);
             let content = oss::Bytes::from(self.config());
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketRequestPayment")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_body(content)
@@ -38,35 +48,57 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketRequestPaymentBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketRequestPaymentBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetBucketRequestPaymentBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> GetBucketRequestPaymentBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    impl<'a, M> GetBucketRequestPaymentBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<RequestPaymentConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<RequestPaymentConfiguration> {
             let res = format!("/{}/?requestPayment", self.client.bucket());
-            let url = format!("{}/?requestPayment", self.client.base_url());
+            let url = format!("{}/?requestPayment", self.client.base_url()?);
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketRequestPayment")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetBucketRequestPaymentBuilder,
+        fn execute => inner_execute -> api::ApiResult<RequestPaymentConfiguration>;
+    }
 }
 
 use crate::oss::{self, entities::payment::Payer};
@@ -74,12 +106,15 @@ use crate::oss::{self, entities::payment::Payer};
 use self::builders::{GetBucketRequestPaymentBuilder, PutBucketRequestPaymentBuilder};
 /// # 请求者付费`RequestPayment`
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketRequestPayment接口用于设置请求者付费模式。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketrequestpayment)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_payment_put.rs)
-    pub fn PutBucketRequestPayment(&self, payer: Payer) -> PutBucketRequestPaymentBuilder {
+    pub fn PutBucketRequestPayment(
+        &'a self,
+        payer: Payer,
+    ) -> PutBucketRequestPaymentBuilder<'a, M> {
         PutBucketRequestPaymentBuilder::new(self, payer)
     }
 
@@ -87,7 +122,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketrequestpayment)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_payment_get.rs)
-    pub fn GetBucketRequestPayment(&self) -> GetBucketRequestPaymentBuilder {
+    pub fn GetBucketRequestPayment(&'a self) -> GetBucketRequestPaymentBuilder<'a, M> {
         GetBucketRequestPaymentBuilder::new(&self)
     }
 }
@@ -9,27 +9,35 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketPolicyBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketPolicyBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         policy: &'a str,
     }
 
-    impl<'a> PutBucketPolicyBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketPolicyBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 policy: Default::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_policy(mut self, value: &'a str) -> Self {
             self.policy = value;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let res = format!("/{}/?{}", self.client.bucket(), "policy");
-            let url = format!("{}/?{}", self.client.base_url(), "policy");
+            let url = format!("{}/?{}", self.client.base_url()?, "policy");
 
             let data = oss::Bytes::from(self.policy.to_string());
 
@@ -37,6 +45,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketPolicy")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::PUT)
@@ -44,72 +54,111 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketPolicyBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketPolicyBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
     }
 
-    impl<'a> GetBucketPolicyBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketPolicyBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketPolicyBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<String> {
+        async fn inner_execute(&self) -> api::ApiResult<String> {
             let res = format!("/{}/?{}", self.client.options.bucket, "policy");
-            let url = format!("{}/?{}", self.client.options.base_url(), "policy");
+            let url = format!("{}/?{}", self.client.options.base_url()?, "policy");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketPolicy")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_text().await)
+            ApiResponseFrom(resp).into_text().await
         }
     }
 
-    pub struct DeleteBucketPolicyBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketPolicyBuilder,
+        fn execute => inner_execute -> api::ApiResult<String>;
     }
 
-    impl<'a> DeleteBucketPolicyBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct DeleteBucketPolicyBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> DeleteBucketPolicyBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.options.bucket, "policy");
-            let url = format!("{}/?{}", self.client.options.base_url(), "policy");
+            let url = format!("{}/?{}", self.client.options.base_url()?, "policy");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketPolicy")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketPolicyBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 授权策略`Policy`
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketPolicy接口用于为指定的存储空间`Bucket`设置授权策略`Policy`。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketpolicy)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_policy_put.rs)
-    pub fn PutBucketPolicy(&self) -> PutBucketPolicyBuilder {
+    pub fn PutBucketPolicy(&'a self) -> PutBucketPolicyBuilder<'a, M> {
         PutBucketPolicyBuilder::new(self)
     }
 
@@ -117,7 +166,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketpolicy)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_policy_get.rs)
-    pub fn GetBucketPolicy(&self) -> GetBucketPolicyBuilder {
+    pub fn GetBucketPolicy(&'a self) -> GetBucketPolicyBuilder<'a, M> {
         GetBucketPolicyBuilder::new(self)
     }
 
@@ -125,7 +174,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketpolicy)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_policy_del.rs)
-    pub fn DeleteBucketPolicy(&self) -> DeleteBucketPolicyBuilder {
+    pub fn DeleteBucketPolicy(&'a self) -> DeleteBucketPolicyBuilder<'a, M> {
         DeleteBucketPolicyBuilder::new(self)
     }
 }
@@ -11,19 +11,27 @@ pub mod builders {
     };
 
     #[derive(Debug)]
-    pub struct PutBucketRefererBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketRefererBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         config: RefererConfiguration,
     }
 
-    impl<'a> PutBucketRefererBuilder<'a> {
-        pub fn new(cilent: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketRefererBuilder<'a, M> {
+        pub fn new(cilent: &'a oss::Client<M>) -> Self {
             Self {
                 client: cilent,
                 config: RefererConfiguration::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_config(mut self, value: RefererConfiguration) -> Self {
             self.config = value;
             self
@@ -33,9 +41,9 @@ pub mod builders {
             quick_xml::se::to_string(&self.config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "referer");
-            let url = format!("{}/?{}", self.client.base_url(), "referer");
+            let url = format!("{}/?{}", self.client.base_url()?, "referer");
             let config = self.config();
             let data = oss::Bytes::from(config);
 
@@ -43,6 +51,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketReferer")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::PUT)
@@ -50,44 +60,66 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketRefererBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketRefererBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketRefererBuilder<'a> {
-        pub fn new(cilent: &'a oss::Client) -> Self {
-            Self { client: cilent }
+    pub struct GetBucketRefererBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketRefererBuilder<'a, M> {
+        pub fn new(cilent: &'a oss::Client<M>) -> Self {
+            Self {
+                client: cilent,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<RefererConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<RefererConfiguration> {
             let res = format!("/{}/?{}", self.client.options.bucket, "referer");
-            let url = format!("{}?{}", self.client.options.base_url(), "referer");
+            let url = format!("{}?{}", self.client.options.base_url()?, "referer");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketReferer")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetBucketRefererBuilder,
+        fn execute => inner_execute -> api::ApiResult<RefererConfiguration>;
+    }
 }
 
 /// # 防盗链`Referer``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutBucketReferer接口设置存储空间`Bucket`级别的Referer访问白名单以及黑名单
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketreferer)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_referer_put.rs)
-    pub fn PutBucketReferer(&self) -> PutBucketRefererBuilder {
+    pub fn PutBucketReferer(&'a self) -> PutBucketRefererBuilder<'a, M> {
         PutBucketRefererBuilder::new(self)
     }
 
@@ -95,7 +127,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketreferer)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_referer_get.rs)
-    pub fn GetBucketReferer(&self) -> GetBucketRefererBuilder {
+    pub fn GetBucketReferer(&'a self) -> GetBucketRefererBuilder<'a, M> {
         GetBucketRefererBuilder::new(self)
     }
 }
@@ -23,8 +23,9 @@ pub mod builders {
     use std::fmt;
 
     #[derive(Debug)]
-    pub struct PutBucketBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         region: Option<&'a str>,
         bucket: Option<&'a str>,
         acl: Option<OssAcl>,
@@ -33,8 +34,8 @@ pub mod builders {
         data_redundancy_type: Option<DataRedundancyType>,
     }
 
-    impl<'a> PutBucketBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 region: None,
@@ -44,9 +45,16 @@ pub mod builders {
                 // config: None,
                 storage_class: None,
                 data_redundancy_type: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_region(mut self, value: &'a str) -> Self {
             self.region = Some(value);
             self
@@ -97,24 +105,11 @@ pub mod builders {
         }
 
         /// 调用PutBucket接口创建存储空间（Bucket）。
-        pub async fn execute(&self) -> api::ApiResult {
-            let region = self.region.unwrap_or(self.client.options.region);
+        async fn inner_execute(&self) -> api::ApiResult {
+            let region = self.region.unwrap_or(self.client.region());
             let bucket = self.bucket.unwrap_or(self.client.bucket());
             let res = format!("/{}/", bucket);
-            let url = format!(
-                "{}://{}.{}",
-                self.client.options.schema(),
-                bucket,
-                format!(
-                    "{}{}.{}",
-                    region,
-                    match self.client.options.internal {
-                        true => "-internal",
-                        false => "",
-                    },
-                    oss::BASE_URL
-                )
-            );
+            let url = self.client.options.bucket_url(bucket, region)?;
 
             let headers = self.headers();
             let config = oss::Bytes::from(self.config());
@@ -123,6 +118,9 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucket")
+                .with_retry_policy(self.retry_policy)
+                .with_region(region)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
@@ -131,25 +129,38 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct DeleteBucketBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct DeleteBucketBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         region: Option<&'a str>,
         bucket: Option<&'a str>,
     }
 
-    impl<'a> DeleteBucketBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> DeleteBucketBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 region: None,
                 bucket: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_region(mut self, region: &'a str) -> Self {
             self.region = Some(region);
             self
@@ -160,24 +171,11 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
-            let region = self.region.unwrap_or(self.client.options.region);
+        async fn inner_execute(&self) -> api::ApiResult {
+            let region = self.region.unwrap_or(self.client.region());
             let bucket = self.bucket.unwrap_or(self.client.bucket());
             let res = format!("/{}/", bucket);
-            let url = format!(
-                "{}://{}.{}",
-                self.client.options.schema(),
-                bucket,
-                format!(
-                    "{}{}.{}",
-                    region,
-                    match self.client.options.internal {
-                        true => "-internal",
-                        false => "",
-                    },
-                    oss::BASE_URL
-                )
-            );
+            let url = self.client.options.bucket_url(bucket, region)?;
             // dbg!(&res);
             // dbg!(&url);
 
@@ -185,16 +183,24 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucket")
+                .with_retry_policy(self.retry_policy)
+                .with_region(region)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        DeleteBucketBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
     #[derive(Debug, Serialize, Deserialize)]
     pub(crate) struct ListObjectQuery<'a> {
         delimiter: Option<&'a str>,
@@ -224,19 +230,27 @@ pub mod builders {
         }
     }
 
-    pub struct ListObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         query: ListObjectQuery<'a>,
     }
 
-    impl<'a> ListObjectBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> ListObjectBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 query: ListObjectQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_delimiter(mut self, value: &'a str) -> Self {
             self.query.delimiter = Some(value);
             self
@@ -262,9 +276,9 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListBucketResult> {
+        async fn inner_execute(&self) -> api::ApiResult<ListBucketResult> {
             let res = format!("/{}/", self.client.bucket());
-            let mut url = self.client.base_url();
+            let mut url = self.client.base_url()?;
             let query = self.query.to_string();
             if !query.is_empty() {
                 url = format!("{}?{}", url, query);
@@ -274,15 +288,22 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ListObjects")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
+    oss::builder_execute! {
+        ListObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListBucketResult>;
+    }
+
     #[derive(Debug, Serialize, Deserialize)]
     pub(crate) struct ListObjectsV2Query<'a> {
         #[serde(rename = "list-type")]
@@ -322,19 +343,27 @@ pub mod builders {
         }
     }
 
-    pub struct ListObjectsV2Builder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListObjectsV2Builder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         query: ListObjectsV2Query<'a>,
     }
 
-    impl<'a> ListObjectsV2Builder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> ListObjectsV2Builder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 query: ListObjectsV2Query::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_delimiter(mut self, value: &'a str) -> Self {
             self.query.delimiter = Some(value);
             self
@@ -370,9 +399,9 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListBucketResult2> {
+        async fn inner_execute(&self) -> api::ApiResult<ListBucketResult2> {
             let mut res = format!("/{}/", self.client.bucket());
-            let mut url = self.client.base_url();
+            let mut url = self.client.base_url()?;
             let query = self.query.to_string();
             if !query.is_empty() {
                 if let Some(token) = self.query.continuation_token {
@@ -385,131 +414,156 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ListObjectsV2")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::GET)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct GetBucketInfoBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        ListObjectsV2Builder,
+        fn execute => inner_execute -> api::ApiResult<ListBucketResult2>;
+    }
+
+    pub struct GetBucketInfoBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         bucket: Option<&'a str>,
     }
 
-    impl<'a> GetBucketInfoBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> GetBucketInfoBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 bucket: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_bucket(mut self, value: &'a str) -> Self {
             self.bucket = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<BucketInfo> {
+        async fn inner_execute(&self) -> api::ApiResult<BucketInfo> {
             let region = self.client.region();
             let bucket = self.bucket.unwrap_or(self.client.bucket());
             let res = format!("/{}/?bucketInfo", bucket);
             let url = format!(
-                "{}://{}.{}?bucketInfo",
-                self.client.options.schema(),
-                bucket,
-                format!(
-                    "{}{}.{}",
-                    region,
-                    match self.client.options.internal {
-                        true => "-internal",
-                        false => "",
-                    },
-                    oss::BASE_URL
-                )
+                "{}?bucketInfo",
+                self.client.options.bucket_url(bucket, region)?
             );
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketInfo")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct GetBucketLocationBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketInfoBuilder,
+        fn execute => inner_execute -> api::ApiResult<BucketInfo>;
+    }
+
+    pub struct GetBucketLocationBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         bucket: Option<&'a str>,
     }
 
-    impl<'a> GetBucketLocationBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> GetBucketLocationBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 bucket: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_bucket(mut self, value: &'a str) -> Self {
             self.bucket = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<LocationConstraint> {
-            let region = self.client.options.region;
+        async fn inner_execute(&self) -> api::ApiResult<LocationConstraint> {
+            let region = self.client.region();
             let bucket = self.bucket.unwrap_or(self.client.bucket());
             let res = format!("/{}/?location", bucket);
             let url = format!(
-                "{}://{}.{}/?location",
-                self.client.options.schema(),
-                bucket,
-                format!(
-                    "{}{}.{}",
-                    region,
-                    match self.client.options.internal {
-                        true => "-internal",
-                        false => "",
-                    },
-                    oss::BASE_URL
-                )
+                "{}/?location",
+                self.client.options.bucket_url(bucket, region)?
             );
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketLocation")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::GET)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct GetBucketStatBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketLocationBuilder,
+        fn execute => inner_execute -> api::ApiResult<LocationConstraint>;
+    }
+
+    pub struct GetBucketStatBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         region: Option<&'a str>,
         bucket: Option<&'a str>,
     }
 
-    impl<'a> GetBucketStatBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> GetBucketStatBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 region: None,
                 bucket: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_region(mut self, region: &'a str) -> Self {
             self.region = Some(region);
             self
@@ -520,48 +574,43 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<BucketStat> {
-            let region = self.region.unwrap_or(self.client.options.region);
+        async fn inner_execute(&self) -> api::ApiResult<BucketStat> {
+            let region = self.region.unwrap_or(self.client.region());
             let bucket = self.bucket.unwrap_or(self.client.bucket());
             let res = format!("/{}/?stat", bucket);
-            let url = format!(
-                "{}://{}.{}/?stat",
-                self.client.options.schema(),
-                bucket,
-                format!(
-                    "{}{}.{}",
-                    region,
-                    match self.client.options.internal {
-                        true => "-internal",
-                        false => "",
-                    },
-                    oss::BASE_URL
-                )
-            );
+            let url = format!("{}/?stat", self.client.options.bucket_url(bucket, region)?);
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketStat")
+                .with_retry_policy(self.retry_policy)
+                .with_region(region)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::GET)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetBucketStatBuilder,
+        fn execute => inner_execute -> api::ApiResult<BucketStat>;
+    }
 }
 
 /// # 基础操作
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutBucket接口创建存储空间`Bucket`。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucket)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_put.rs)
-    pub fn PutBucket(&self) -> PutBucketBuilder<'_> {
+    pub fn PutBucket(&'a self) -> PutBucketBuilder<'a, M> {
         PutBucketBuilder::new(self)
     }
 
@@ -569,7 +618,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucket)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_del.rs)
-    pub fn DeleteBucket(&self) -> DeleteBucketBuilder<'_> {
+    pub fn DeleteBucket(&'a self) -> DeleteBucketBuilder<'a, M> {
         DeleteBucketBuilder::new(self)
     }
 
@@ -578,7 +627,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listobjects)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_list_object.rs)
-    pub fn ListObjects(&self) -> ListObjectBuilder<'_> {
+    pub fn ListObjects(&'a self) -> ListObjectBuilder<'a, M> {
         ListObjectBuilder::new(self)
     }
 
@@ -587,7 +636,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listobjectsv2)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_list_object_v2.rs)
-    pub fn ListObjectsV2(&self) -> ListObjectsV2Builder<'_> {
+    pub fn ListObjectsV2(&'a self) -> ListObjectsV2Builder<'a, M> {
         ListObjectsV2Builder::new(self)
     }
 
@@ -595,7 +644,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketinfo)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_get_info.rs)
-    pub fn GetBucketInfo(&self) -> GetBucketInfoBuilder<'_> {
+    pub fn GetBucketInfo(&'a self) -> GetBucketInfoBuilder<'a, M> {
         GetBucketInfoBuilder::new(self)
     }
 
@@ -604,7 +653,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketlocation)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_get_location.rs)
-    pub fn GetBucketLocation(&self) -> GetBucketLocationBuilder<'_> {
+    pub fn GetBucketLocation(&'a self) -> GetBucketLocationBuilder<'a, M> {
         GetBucketLocationBuilder::new(self)
     }
 
@@ -613,7 +662,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketstat)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_stand_get_stat.rs)
-    pub fn GetBucketStat(&self) -> GetBucketStatBuilder<'_> {
+    pub fn GetBucketStat(&'a self) -> GetBucketStatBuilder<'a, M> {
         GetBucketStatBuilder::new(self)
     }
 }
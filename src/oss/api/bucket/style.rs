@@ -10,19 +10,27 @@ pub mod builders {
         http,
     };
 
-    pub struct PutStyleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutStyleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         style: Style,
     }
 
-    impl<'a> PutStyleBuilder<'a> {
-        pub fn new(client: &'a oss::Client<'a>) -> Self {
+    impl<'a, M> PutStyleBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 style: Style::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_name(mut self, value: &'a str) -> Self {
             self.style.name = value.to_string();
             self
@@ -42,7 +50,7 @@ pub mod builders {
             quick_xml::se::to_string(&self.style).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/?{}&styleName={}",
                 self.client.bucket(),
@@ -51,7 +59,7 @@ pub mod builders {
             );
             let url = format!(
                 "{}?{}&styleName={}",
-                self.client.base_url(),
+                self.client.base_url()?,
                 "style",
                 self.style.name
             );
@@ -63,6 +71,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutStyle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
@@ -70,45 +80,78 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct ListStyleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutStyleBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct ListStyleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> ListStyleBuilder<'a> {
-        pub fn new(client: &'a oss::Client<'a>) -> Self {
-            Self { client }
+    impl<'a, M> ListStyleBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<StyleList> {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<StyleList> {
             let res = format!("/{}/?{}", self.client.bucket(), "style");
-            let url = format!("{}/?{}", self.client.base_url(), "style");
+            let url = format!("{}/?{}", self.client.base_url()?, "style");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("ListStyle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct GetStyleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        ListStyleBuilder,
+        fn execute => inner_execute -> api::ApiResult<StyleList>;
+    }
+
+    pub struct GetStyleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         name: &'a str,
     }
 
-    impl<'a> GetStyleBuilder<'a> {
-        pub fn new(client: &'a oss::Client<'a>, name: &'a str) -> Self {
-            Self { client, name }
+    impl<'a, M> GetStyleBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, name: &'a str) -> Self {
+            Self {
+                client,
+                name,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<Style> {
+        async fn inner_execute(&self) -> api::ApiResult<Style> {
             let res = format!(
                 "/{}/?{}&styleName={}",
                 self.client.bucket(),
@@ -117,7 +160,7 @@ pub mod builders {
             );
             let url = format!(
                 "{}/?{}&styleName={}",
-                self.client.options.base_url(),
+                self.client.options.base_url()?,
                 "style",
                 self.name
             );
@@ -126,26 +169,44 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetStyle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::GET)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteStyleBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetStyleBuilder,
+        fn execute => inner_execute -> api::ApiResult<Style>;
+    }
+
+    pub struct DeleteStyleBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         name: &'a str,
     }
 
-    impl<'a> DeleteStyleBuilder<'a> {
-        pub fn new(client: &'a oss::Client<'a>, name: &'a str) -> Self {
-            Self { client, name }
+    impl<'a, M> DeleteStyleBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, name: &'a str) -> Self {
+            Self {
+                client,
+                name,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/?{}&styleName={}",
                 self.client.bucket(),
@@ -154,7 +215,7 @@ pub mod builders {
             );
             let url = format!(
                 "{}/?{}&styleName={}",
-                self.client.base_url(),
+                self.client.base_url()?,
                 "style",
                 self.name
             );
@@ -163,24 +224,31 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteStyle")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteStyleBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 图片样式`Style`
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutStyle接口新增图片样式。一个图片样式中可以包含单个或多个图片处理参数
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putstyle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_style_put.rs)
-    pub fn PutStyle(&self) -> PutStyleBuilder {
+    pub fn PutStyle(&'a self) -> PutStyleBuilder<'a, M> {
         PutStyleBuilder::new(self)
     }
 
@@ -188,7 +256,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletestyle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_style_get.rs)
-    pub fn GetStyle(&self, name: &'a str) -> GetStyleBuilder {
+    pub fn GetStyle(&'a self, name: &'a str) -> GetStyleBuilder<'a, M> {
         GetStyleBuilder::new(self, name)
     }
 
@@ -196,7 +264,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getstyle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_style_list.rs)
-    pub fn ListStyle(&self) -> ListStyleBuilder {
+    pub fn ListStyle(&'a self) -> ListStyleBuilder<'a, M> {
         ListStyleBuilder::new(self)
     }
 
@@ -204,7 +272,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletestyle)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_style_del.rs)
-    pub fn DeleteStyle(&self, name: &'a str) -> DeleteStyleBuilder {
+    pub fn DeleteStyle(&'a self, name: &'a str) -> DeleteStyleBuilder<'a, M> {
         DeleteStyleBuilder::new(self, name)
     }
 }
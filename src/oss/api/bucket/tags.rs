@@ -12,19 +12,27 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketTagsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketTagsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         tags: HashMap<&'a str, &'a str>,
     }
 
-    impl<'a> PutBucketTagsBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketTagsBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 tags: HashMap::new(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_tags(mut self, tags: HashMap<&'a str, &'a str>) -> Self {
             self.tags = tags;
             self
@@ -50,68 +58,100 @@ pub mod builders {
             quick_xml::se::to_string(&self.tagging()).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.options.bucket, "tagging");
-            let url = format!("{}?{}", self.client.options.base_url(), "tagging");
+            let url = format!("{}?{}", self.client.options.base_url()?, "tagging");
             let data = oss::Bytes::from(self.tagging_xml());
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketTags")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
                 .with_body(data)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketTagsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketTagsBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetBucketTagsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> GetBucketTagsBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    impl<'a, M> GetBucketTagsBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<Tagging> {
+        async fn inner_execute(&self) -> api::ApiResult<Tagging> {
             let res = format!("/{}/?{}", self.client.options.bucket, "tagging");
-            let url = format!("{}?{}", self.client.options.base_url(), "tagging");
+            let url = format!("{}?{}", self.client.options.base_url()?, "tagging");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketTags")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
-    pub struct DeleteBucketTagsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+
+    oss::builder_execute! {
+        GetBucketTagsBuilder,
+        fn execute => inner_execute -> api::ApiResult<Tagging>;
+    }
+    pub struct DeleteBucketTagsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         keys: Vec<&'a str>,
     }
 
-    impl<'a> DeleteBucketTagsBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> DeleteBucketTagsBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 keys: Vec::new(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_keys(mut self, keys: Vec<&'a str>) -> Self {
             self.keys = keys;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let res = format!("/{}/?{}", self.client.options.bucket, "tagging");
-            let url = format!("{}?{}", self.client.options.base_url(), "tagging");
+            let url = format!("{}?{}", self.client.options.base_url()?, "tagging");
             let url = if !self.keys.is_empty() {
                 let keys = self.keys.join(",");
                 format!("{}&{}", url, keys)
@@ -123,25 +163,32 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketTags")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketTagsBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
 }
 
 /// # 标签`Tags``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// PutBucketTags接口用来给某个存储空间`Bucket`添加或修改标签。
     ///
     /// - [official docs]()
     /// - [xtoss example]()
-    pub fn PutBucketTags(&self) -> PutBucketTagsBuilder {
+    pub fn PutBucketTags(&'a self) -> PutBucketTagsBuilder<'a, M> {
         PutBucketTagsBuilder::new(self)
     }
 
@@ -149,7 +196,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs]()
     /// - [xtoss example]()
-    pub fn GetBucketTags(&self) -> GetBucketTagsBuilder {
+    pub fn GetBucketTags(&'a self) -> GetBucketTagsBuilder<'a, M> {
         GetBucketTagsBuilder::new(self)
     }
 
@@ -157,7 +204,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs]()
     /// - [xtoss example]()
-    pub fn DeleteBucketTags(&self) -> DeleteBucketTagsBuilder {
+    pub fn DeleteBucketTags(&'a self) -> DeleteBucketTagsBuilder<'a, M> {
         DeleteBucketTagsBuilder::new(self)
     }
 }
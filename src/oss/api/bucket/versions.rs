@@ -16,19 +16,30 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketVersioningBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketVersioningBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         status: VersioningStatus,
     }
 
-    impl<'a> PutBucketVersioningBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, status: VersioningStatus) -> Self {
-            Self { client, status }
+    impl<'a, M> PutBucketVersioningBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, status: VersioningStatus) -> Self {
+            Self {
+                client,
+                status,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "versioning");
-            let url = format!("{}/?{}", self.client.base_url(), "versioning");
+            let url = format!("{}/?{}", self.client.base_url()?, "versioning");
 
             let config = VersioningConfiguration {
                 status: Some(self.status.to_owned()),
@@ -40,39 +51,63 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketVersioning")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
                 .with_body(data)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketVersioningBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketVersioningBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketVersioningBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketVersioningBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketVersioningBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
-        pub async fn execute(&self) -> api::ApiResult<VersioningConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<VersioningConfiguration> {
             let res = format!("/{}/?{}", self.client.bucket(), "versioning");
-            let url = format!("{}/?{}", self.client.base_url(), "versioning");
+            let url = format!("{}/?{}", self.client.base_url()?, "versioning");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketVersioning")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
+    oss::builder_execute! {
+        GetBucketVersioningBuilder,
+        fn execute => inner_execute -> api::ApiResult<VersioningConfiguration>;
+    }
+
     #[derive(Debug, Serialize, Deserialize, Default)]
     pub(crate) struct ListObjectVersionsQuery<'a> {
         pub delimiter: Option<&'a str>,
@@ -93,19 +128,27 @@ pub mod builders {
         }
     }
 
-    pub struct ListObjectVersionsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListObjectVersionsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         query: ListObjectVersionsQuery<'a>,
     }
 
-    impl<'a> ListObjectVersionsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> ListObjectVersionsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 query: ListObjectVersionsQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_delimiter(mut self, value: &'a str) -> Self {
             self.query.delimiter = Some(value);
             self
@@ -136,39 +179,49 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListVersionsResult> {
+        async fn inner_execute(&self) -> api::ApiResult<ListVersionsResult> {
             let query = self.query.to_string();
             let res = format!("/{}/?versions", self.client.bucket());
-            let url = format!("{}/?versions&{}", self.client.base_url(), query);
+            let url = format!("{}/?versions&{}", self.client.base_url()?, query);
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("ListObjectVersions")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        ListObjectVersionsBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListVersionsResult>;
+    }
 }
 
 /// # 版本控制`Versioning``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutBucketVersioning设置指定存储空间`Bucket`的版本控制状态。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketversioning)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_version_put.rs)
-    pub fn PutBucketVersioning(&self, status: VersioningStatus) -> PutBucketVersioningBuilder {
+    pub fn PutBucketVersioning(
+        &'a self,
+        status: VersioningStatus,
+    ) -> PutBucketVersioningBuilder<'a, M> {
         PutBucketVersioningBuilder::new(self, status)
     }
     /// 接口用于获取指定Bucket的版本控制状态。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketversioning)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_version_get.rs)
-    pub fn GetBucketVersioning(&self) -> GetBucketVersioningBuilder {
+    pub fn GetBucketVersioning(&'a self) -> GetBucketVersioningBuilder<'a, M> {
         GetBucketVersioningBuilder::new(self)
     }
 
@@ -177,7 +230,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listobjectversions)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_version_list.rs)
-    pub fn ListObjectVersions(&self) -> ListObjectVersionsBuilder {
+    pub fn ListObjectVersions(&'a self) -> ListObjectVersionsBuilder<'a, M> {
         ListObjectVersionsBuilder::new(self)
     }
 }
@@ -12,19 +12,27 @@ pub mod builders {
         http,
     };
 
-    pub struct PutBucketWebsiteBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutBucketWebsiteBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         config: WebsiteConfiguration,
     }
 
-    impl<'a> PutBucketWebsiteBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> PutBucketWebsiteBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 config: WebsiteConfiguration::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_config(mut self, config: WebsiteConfiguration) -> Self {
             self.config = config;
             self
@@ -34,9 +42,9 @@ pub mod builders {
             quick_xml::se::to_string(&self.config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let res = format!("/{}/?{}", self.client.bucket(), "website");
-            let url = format!("{}/?{}", self.client.base_url(), "website");
+            let url = format!("{}/?{}", self.client.base_url()?, "website");
 
             let config = self.config();
             let data = oss::Bytes::from(config);
@@ -45,6 +53,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutBucketWebsite")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
@@ -52,69 +62,108 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketWebsiteBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutBucketWebsiteBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
     }
 
-    impl<'a> GetBucketWebsiteBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketWebsiteBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketWebsiteBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<WebsiteConfiguration> {
+        async fn inner_execute(&self) -> api::ApiResult<WebsiteConfiguration> {
             let res = format!("/{}/?{}", self.client.bucket(), "website");
-            let url = format!("{}/?{}", self.client.base_url(), "website");
+            let url = format!("{}/?{}", self.client.base_url()?, "website");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketWebsite")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteBucketWebsiteBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetBucketWebsiteBuilder,
+        fn execute => inner_execute -> api::ApiResult<WebsiteConfiguration>;
     }
 
-    impl<'a> DeleteBucketWebsiteBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct DeleteBucketWebsiteBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> DeleteBucketWebsiteBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "website");
-            let url = format!("{}/?{}", self.client.base_url(), "website");
+            let url = format!("{}/?{}", self.client.base_url()?, "website");
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("DeleteBucketWebsite")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteBucketWebsiteBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 静态网站`Website``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutBucketWebsite接口将存储空间`Bucket`设置为静态网站托管模式并设置跳
     /// 转规则`RoutingRule`。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putbucketwebsite)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_website_put.rs)
-    pub fn PutBucketWebsite(&self) -> PutBucketWebsiteBuilder {
+    pub fn PutBucketWebsite(&'a self) -> PutBucketWebsiteBuilder<'a, M> {
         PutBucketWebsiteBuilder::new(self)
     }
 
@@ -122,7 +171,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketwebsite)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_website_get.rs)
-    pub fn GetBucketWebsite(&self) -> GetBucketWebsiteBuilder {
+    pub fn GetBucketWebsite(&'a self) -> GetBucketWebsiteBuilder<'a, M> {
         GetBucketWebsiteBuilder::new(self)
     }
 
@@ -131,7 +180,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletebucketwebsite)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_website_del.rs)
-    pub fn DeleteBucketWebsite(&self) -> DeleteBucketWebsiteBuilder {
+    pub fn DeleteBucketWebsite(&'a self) -> DeleteBucketWebsiteBuilder<'a, M> {
         DeleteBucketWebsiteBuilder::new(self)
     }
 }
@@ -14,14 +14,25 @@ pub mod builders {
         http,
     };
 
-    pub struct InitiateBucketWormBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct InitiateBucketWormBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         days: i32,
     }
 
-    impl<'a> InitiateBucketWormBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client, days: 1 }
+    impl<'a, M> InitiateBucketWormBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                days: 1,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
         pub fn with_days(mut self, value: i32) -> Self {
@@ -36,15 +47,17 @@ pub mod builders {
             quick_xml::se::to_string(&config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "worm");
-            let url = format!("{}/?{}", self.client.base_url(), "worm");
+            let url = format!("{}/?{}", self.client.base_url()?, "worm");
 
             let data = oss::Bytes::from(self.config());
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("InitiateBucketWorm")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_resource(&res)
@@ -52,51 +65,81 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct AbortBucketWormBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        InitiateBucketWormBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> AbortBucketWormBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct AbortBucketWormBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> AbortBucketWormBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?{}", self.client.bucket(), "worm");
-            let url = format!("{}/?{}", self.client.base_url(), "worm");
+            let url = format!("{}/?{}", self.client.base_url()?, "worm");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("AbortBucketWorm")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct ExtendBucketWormBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        AbortBucketWormBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct ExtendBucketWormBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         worm_id: &'a str,
         days: u32,
     }
 
-    impl<'a> ExtendBucketWormBuilder<'a> {
-        pub fn new(client: &'a oss::Client, worm_id: &'a str) -> Self {
+    impl<'a, M> ExtendBucketWormBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, worm_id: &'a str) -> Self {
             Self {
                 client,
                 days: 1,
                 worm_id,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_days(mut self, value: u32) -> Self {
             self.days = value;
             self
@@ -109,7 +152,7 @@ pub mod builders {
             quick_xml::se::to_string(&config).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/?{}&wormId={}",
                 self.client.bucket(),
@@ -118,7 +161,7 @@ pub mod builders {
             );
             let url = format!(
                 "{}/?{}&wormId={}",
-                self.client.base_url(),
+                self.client.base_url()?,
                 "wormExtend",
                 self.worm_id
             );
@@ -129,6 +172,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ExtendBucketWorm")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_body(data)
@@ -136,72 +181,112 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct CompleteBucketWormBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        ExtendBucketWormBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct CompleteBucketWormBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         worm_id: &'a str,
     }
 
-    impl<'a> CompleteBucketWormBuilder<'a> {
-        pub fn new(client: &'a oss::Client, worm_id: &'a str) -> Self {
-            Self { client, worm_id }
+    impl<'a, M> CompleteBucketWormBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, worm_id: &'a str) -> Self {
+            Self {
+                client,
+                worm_id,
+                retry_policy: None,
+            }
+        }
+
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!("/{}/?wormId={}", self.client.bucket(), self.worm_id);
-            let url = format!("{}/?wormId={}", self.client.base_url(), self.worm_id);
+            let url = format!("{}/?wormId={}", self.client.base_url()?, self.worm_id);
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("CompleteBucketWorm")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetBucketWormBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        CompleteBucketWormBuilder,
+        fn execute => inner_execute -> api::ApiResult;
     }
 
-    impl<'a> GetBucketWormBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    pub struct GetBucketWormBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+    }
+
+    impl<'a, M> GetBucketWormBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<WormConfiguration> {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<WormConfiguration> {
             let res = format!("/{}/?{}", self.client.bucket(), "worm");
-            let url = format!("{}/?{}", self.client.base_url(), "worm");
+            let url = format!("{}/?{}", self.client.base_url()?, "worm");
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("GetBucketWorm")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetBucketWormBuilder,
+        fn execute => inner_execute -> api::ApiResult<WormConfiguration>;
+    }
 }
 
 /// # 合规保留策略`WORM``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用InitiateBucketWorm接口新建一条合规保留策略。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/initiatebucketworm)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_worm_init.rs)
     #[allow(non_snake_case)]
-    pub fn InitiateBucketWorm(&self) -> InitiateBucketWormBuilder {
+    pub fn InitiateBucketWorm(&'a self) -> InitiateBucketWormBuilder<'a, M> {
         InitiateBucketWormBuilder::new(self)
     }
 
@@ -210,7 +295,7 @@ impl<'a> oss::Client<'a> {
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/abortbucketworm)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_worm_abort.rs)
     #[allow(non_snake_case)]
-    pub fn AbortBucketWorm(&self) -> AbortBucketWormBuilder {
+    pub fn AbortBucketWorm(&'a self) -> AbortBucketWormBuilder<'a, M> {
         AbortBucketWormBuilder::new(self)
     }
 
@@ -219,7 +304,7 @@ impl<'a> oss::Client<'a> {
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/completebucketworm)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_worm_complete.rs)
     #[allow(non_snake_case)]
-    pub fn CompleteBucketWorm(&self, worm_id: &'a str) -> CompleteBucketWormBuilder {
+    pub fn CompleteBucketWorm(&'a self, worm_id: &'a str) -> CompleteBucketWormBuilder<'a, M> {
         CompleteBucketWormBuilder::new(self, worm_id)
     }
 
@@ -227,7 +312,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/extendbucketworm)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_worm_extend.rs)
-    pub fn ExtendBucketWorm(&self, worm_id: &'a str) -> ExtendBucketWormBuilder {
+    pub fn ExtendBucketWorm(&'a self, worm_id: &'a str) -> ExtendBucketWormBuilder<'a, M> {
         ExtendBucketWormBuilder::new(self, worm_id)
     }
 
@@ -235,7 +320,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getbucketworm)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_bucket_worm_get.rs)
-    pub fn GetBucketWorm(&self) -> GetBucketWormBuilder {
+    pub fn GetBucketWorm(&'a self) -> GetBucketWormBuilder<'a, M> {
         GetBucketWormBuilder::new(self)
     }
 }
@@ -9,24 +9,32 @@ pub mod builders {
         http,
     };
 
-    pub struct PutObjectACLBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutObjectACLBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
         acl: ObjectACL,
     }
 
     #[allow(unused)]
-    impl<'a> PutObjectACLBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> PutObjectACLBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
                 acl: ObjectACL::Default,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_acl(mut self, acl: ObjectACL) -> Self {
             self.acl = acl;
             self
@@ -37,9 +45,9 @@ pub mod builders {
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "acl");
-            let mut url = { format!("{}?{}", self.client.object_url(self.object), "acl") };
+            let mut url = { format!("{}?{}", self.client.object_url(self.object)?, "acl") };
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, version_id);
                 url = format!("{}&versionId={}", url, version_id);
@@ -52,39 +60,54 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutObjectACL")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_headers(headers)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetObjectAclBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutObjectACLBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetObjectAclBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> GetObjectAclBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> GetObjectAclBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<AccessControlPolicy> {
+        async fn inner_execute(&self) -> api::ApiResult<AccessControlPolicy> {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "acl");
-            let mut url = { format!("{}?{}", self.client.object_url(self.object), "acl") };
+            let mut url = { format!("{}?{}", self.client.object_url(self.object)?, "acl") };
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, version_id);
                 url = format!("{}&versionId={}", url, version_id);
@@ -94,24 +117,31 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetObjectACL")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        GetObjectAclBuilder,
+        fn execute => inner_execute -> api::ApiResult<AccessControlPolicy>;
+    }
 }
 
 /// # 基础操作
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutObjectACL接口修改文件`Object`的访问权限`ACL`。
     /// 此操作只有Bucket Owner有权限执行,且需对Object有读写权限。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putobjectacl)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_acl_put.rs)
-    pub fn PutObjectACL(&self, object: &'a str) -> PutObjectACLBuilder {
+    pub fn PutObjectACL(&'a self, object: &'a str) -> PutObjectACLBuilder<'a, M> {
         PutObjectACLBuilder::new(self, object)
     }
 
@@ -119,7 +149,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobjectacl)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_acl_get.rs)
-    pub fn GetObjectACL(&self, object: &'a str) -> GetObjectAclBuilder {
+    pub fn GetObjectACL(&'a self, object: &'a str) -> GetObjectAclBuilder<'a, M> {
         GetObjectAclBuilder::new(self, object)
     }
 }
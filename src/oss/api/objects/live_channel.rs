@@ -2,40 +2,40 @@ use crate::oss::Client;
 
 /// 基础操作
 #[allow(non_snake_case)]
-impl<'a> Client<'a> {
+impl<'a> Client {
     /// 使用Multipart Upload模式传输数据前,您必须先调用InitiateMultipartUpload接口来通知OSS
     /// 初始化一个Multipart Upload事件
-    pub async fn PutLiveChannel(&self) {
+    pub async fn PutLiveChannel(&'a self) {
         todo!()
     }
 
     /// 初始化一个MultipartUpload后,调用UploadPart接口根据指定的Object名和uploadId来分块`Part``
     /// 上传数据
-    pub async fn ListLiveChannel(&self) {
+    pub async fn ListLiveChannel(&'a self) {
         todo!()
     }
 
-    pub async fn DeleteLiveChannel(&self) {
+    pub async fn DeleteLiveChannel(&'a self) {
         todo!()
     }
 
-    pub async fn PutLiveChannelStatus(&self) {
+    pub async fn PutLiveChannelStatus(&'a self) {
         todo!()
     }
 
-    pub async fn GetLiveChannelInfo(&self) {
+    pub async fn GetLiveChannelInfo(&'a self) {
         todo!()
     }
 
-    pub async fn GetLiveChannelStat(&self) {
+    pub async fn GetLiveChannelStat(&'a self) {
         todo!()
     }
 
-    pub async fn GetLiveChannelHistory(&self) {
+    pub async fn GetLiveChannelHistory(&'a self) {
         todo!()
     }
 
-    pub async fn PostVodPlaylist(&self) {
+    pub async fn PostVodPlaylist(&'a self) {
         todo!()
     }
 }
@@ -2,5 +2,7 @@ pub(crate) mod acl;
 pub(crate) mod stand;
 // pub(crate) mod live_channel;
 pub(crate) mod multi_upload;
+pub(crate) mod post;
+pub(crate) mod presign;
 pub(crate) mod symlink;
 pub(crate) mod taging;
@@ -21,6 +21,7 @@ pub mod builders {
             self, bucket::stand::builders::ListObjectQuery, insert_custom_header, insert_header,
             ApiResponseFrom,
         },
+        crc64,
         entities::{
             multi_upload::{
                 CompleteMultipartUploadResult, InitiateMultipartUploadResult,
@@ -32,6 +33,7 @@ pub mod builders {
             self,
             header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_ENCODING, CONTENT_TYPE, EXPECT},
         },
+        progress::ProgressSink,
     };
 
     #[derive(Debug, Default)]
@@ -49,23 +51,31 @@ pub mod builders {
         oss_tagging: HashMap<String, String>,
     }
 
-    pub struct InitiateMultipartUploadBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct InitiateMultipartUploadBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         encoding_type: Option<&'a str>,
         headers: InitiateMultipartUploadBuilderHeaders<'a>,
     }
 
-    impl<'a> InitiateMultipartUploadBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> InitiateMultipartUploadBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 encoding_type: None,
                 headers: InitiateMultipartUploadBuilderHeaders::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_content_type(mut self, value: &'a str) -> Self {
             self.headers.content_type = Some(value);
             self
@@ -187,9 +197,9 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult<InitiateMultipartUploadResult> {
+        async fn inner_execute(&self) -> api::ApiResult<InitiateMultipartUploadResult> {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "uploads");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "uploads");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "uploads");
             if let Some(encoding_type) = self.encoding_type {
                 res = format!("{}&encoding_type={}", res, encoding_type);
                 url = format!("{}&encoding_type={}", res, encoding_type);
@@ -199,6 +209,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("InitiateMultipartUpload")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_headers(self.headers())
@@ -206,29 +218,56 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct UploadPartBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        InitiateMultipartUploadBuilder,
+        fn execute => inner_execute -> api::ApiResult<InitiateMultipartUploadResult>;
+    }
+
+    pub struct UploadPartBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+        traffic_limit: Option<u64>,
         object: &'a str,
         part_number: u32,
         upload_id: &'a str,
-        content: oss::Bytes,
+        content: oss::UploadBody,
+        content_md5: Option<&'a str>,
+        compute_content_md5: Option<bool>,
+        progress: Option<ProgressSink>,
     }
 
-    impl<'a> UploadPartBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> UploadPartBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 part_number: Default::default(),
                 upload_id: Default::default(),
-                content: oss::Bytes::new(),
+                content: oss::UploadBody::default(),
+                content_md5: None,
+                compute_content_md5: None,
+                progress: None,
+                traffic_limit: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        /// 单链接限速, 单位bit/s, 取值范围为`api::TRAFFIC_LIMIT_RANGE`(100 KB/s ~ 100 MB/s)
+        pub fn with_traffic_limit(mut self, value: u64) -> Self {
+            self.traffic_limit = Some(value);
+            self
+        }
+
         pub fn with_part_number(mut self, value: u32) -> Self {
             self.part_number = value;
             self
@@ -240,11 +279,35 @@ pub mod builders {
         }
 
         pub fn with_content(mut self, value: oss::Bytes) -> Self {
+            self.content = value.into();
+            self
+        }
+
+        /// 以数据流方式上传分片, 例如`oss::UploadBody::from_path_range`读取本地文件的一部分;
+        /// 不限制上传的总时长, 超时时间为连续未发送数据的时长
+        pub fn with_body(mut self, value: oss::UploadBody) -> Self {
             self.content = value;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        pub fn with_content_md5(mut self, value: &'a str) -> Self {
+            self.content_md5 = Some(value);
+            self
+        }
+
+        /// 是否自动计算`Content-MD5`, 覆盖客户端的设置, 已通过`with_content_md5`指定时不计算
+        pub fn with_compute_content_md5(mut self, value: bool) -> Self {
+            self.compute_content_md5 = Some(value);
+            self
+        }
+
+        /// 报告该分片的上传进度, 进度中带有分片号; 使用[`oss::MultipartProgress`]汇总所有分片
+        pub fn with_progress(mut self, value: impl oss::ProgressListener + 'static) -> Self {
+            self.progress = Some(ProgressSink::new(value));
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/{}?partNumber={}&uploadId={}",
                 self.client.bucket(),
@@ -254,40 +317,79 @@ pub mod builders {
             );
             let url = format!(
                 "{}?partNumber={}&uploadId={}",
-                self.client.object_url(self.object),
+                self.client.object_url(self.object)?,
                 self.part_number,
                 self.upload_id
             );
 
+            let mut headers = http::HeaderMap::new();
+            if let Some(content_md5) = self.content_md5 {
+                insert_custom_header(&mut headers, "Content-MD5", content_md5);
+            }
+            api::insert_traffic_limit(&mut headers, self.traffic_limit)?;
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("UploadPart")
+                .with_crc64_check(self.client.crc64_check().then_some(0))
+                .with_compute_content_md5(
+                    self.compute_content_md5
+                        .unwrap_or(self.client.compute_content_md5()),
+                )
+                .with_progress(
+                    self.progress
+                        .clone()
+                        .map(|progress| progress.with_part_number(self.part_number)),
+                )
+                .with_plane(oss::Plane::Data)
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
+                .with_headers(headers)
                 .with_resource(&res)
-                .with_body(self.content.to_owned())
-                .execute_timeout(self.client.timeout())
+                .with_upload_body(self.content.clone())
+                .execute_upload_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct UploadPartCopyBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        UploadPartBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct UploadPartCopyBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
     }
 
-    impl<'a> UploadPartCopyBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
-            Self { client }
+    impl<'a, M> UploadPartCopyBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                retry_policy: None,
+            }
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult {
             todo!()
         }
     }
 
+    oss::builder_execute! {
+        UploadPartCopyBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
     #[derive(Debug, Default, Serialize)]
     struct CompleteMultipartUploadBuilderQuery<'a> {
         #[serde(rename = "uploadId")]
@@ -296,23 +398,33 @@ pub mod builders {
         encoding_type: Option<&'a str>,
     }
 
-    pub struct CompleteMultipartUploadBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct CompleteMultipartUploadBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         forbid_overwrite: Option<bool>,
+        crc64: Option<u64>,
         query: CompleteMultipartUploadBuilderQuery<'a>,
     }
 
-    impl<'a> CompleteMultipartUploadBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> CompleteMultipartUploadBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 forbid_overwrite: None,
+                crc64: None,
                 query: CompleteMultipartUploadBuilderQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_upload_id(mut self, value: &'a str) -> Self {
             self.query.upload_id = value;
             self
@@ -328,18 +440,24 @@ pub mod builders {
             self
         }
 
+        /// 各分片按分片号顺序合并的CRC64(见`oss::Crc64::combine`), 开启CRC64校验时用于校验合并后的对象
+        pub fn with_crc64(mut self, value: u64) -> Self {
+            self.crc64 = Some(value);
+            self
+        }
+
         fn query(&self) -> String {
             serde_qs::to_string(&self.query).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<CompleteMultipartUploadResult> {
+        async fn inner_execute(&self) -> api::ApiResult<CompleteMultipartUploadResult> {
             let res = format!(
                 "/{}/{}?uploadId={}",
                 self.client.bucket(),
                 &self.object,
                 self.query.upload_id
             );
-            let url = format!("{}?{}", self.client.object_url(self.object), self.query());
+            let url = format!("{}?{}", self.client.object_url(self.object)?, self.query());
 
             let mut headers = http::HeaderMap::new();
             insert_header(&mut headers, CONTENT_LENGTH, 0);
@@ -352,37 +470,57 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("CompleteMultipartUpload")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_headers(headers)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            if let Some(crc) = self.crc64.filter(|_| self.client.crc64_check()) {
+                if resp.status().is_success() {
+                    crc64::verify(crc, resp.headers())?;
+                }
+            }
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct AbortMultipartUploadBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        CompleteMultipartUploadBuilder,
+        fn execute => inner_execute -> api::ApiResult<CompleteMultipartUploadResult>;
+    }
+
+    pub struct AbortMultipartUploadBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         upload_id: &'a str,
     }
 
-    impl<'a> AbortMultipartUploadBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> AbortMultipartUploadBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 upload_id: Default::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_upload_id(mut self, upload_id: &'a str) -> Self {
             self.upload_id = upload_id;
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/{}?uploadId={}",
                 self.client.bucket(),
@@ -391,22 +529,29 @@ pub mod builders {
             );
             let url = format!(
                 "{}?uploadId={}",
-                self.client.object_url(self.object),
+                self.client.object_url(self.object)?,
                 self.upload_id
             );
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("AbortMultipartUpload")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        AbortMultipartUploadBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
     #[derive(Debug, Default, Serialize, Deserialize)]
     pub(crate) struct ListMultipartUploadsBuilderQuery<'a> {
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -423,19 +568,27 @@ pub mod builders {
         pub(crate) upload_id_marker: Option<&'a str>,
     }
 
-    pub struct ListMultipartUploadsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListMultipartUploadsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         query: ListMultipartUploadsBuilderQuery<'a>,
     }
 
-    impl<'a> ListMultipartUploadsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> ListMultipartUploadsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 query: ListMultipartUploadsBuilderQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_delimiter(mut self, value: &'a str) -> Self {
             self.query.delimiter = Some(value);
             self
@@ -470,9 +623,9 @@ pub mod builders {
             serde_qs::to_string(&self.query).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListMultipartUploadsResult> {
+        async fn inner_execute(&self) -> api::ApiResult<ListMultipartUploadsResult> {
             let mut res = format!("/{}/?{}", self.client.bucket(), "uploads");
-            let mut url = format!("{}?{}", self.client.base_url(), "uploads");
+            let mut url = format!("{}?{}", self.client.base_url()?, "uploads");
             let query = self.query();
             if !query.is_empty() {
                 res = format!("{}&{}", &res, &query);
@@ -482,15 +635,22 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ListMultipartUploads")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::GET)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
+    oss::builder_execute! {
+        ListMultipartUploadsBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListMultipartUploadsResult>;
+    }
+
     #[derive(Debug, Default, Serialize, Deserialize)]
     struct ListPartsBuilderQuery<'a> {
         #[serde(rename = "uploadId")]
@@ -503,21 +663,29 @@ pub mod builders {
         encoding_type: Option<&'a str>,
     }
 
-    pub struct ListPartsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListPartsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         query: ListPartsBuilderQuery<'a>,
     }
 
-    impl<'a> ListPartsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> ListPartsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 query: ListPartsBuilderQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_upload_id(mut self, value: &'a str) -> Self {
             self.query.upload_id = value;
             self
@@ -542,9 +710,9 @@ pub mod builders {
             serde_qs::to_string(&self.query).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListPartsResult> {
+        async fn inner_execute(&self) -> api::ApiResult<ListPartsResult> {
             let mut res = format!("/{}/{}", self.client.bucket(), self.object);
-            let mut url = self.client.object_url(self.object);
+            let mut url = self.client.object_url(self.object)?;
             let query = self.query();
             if !query.is_empty() {
                 res = format!("{}?{}", res, query);
@@ -556,24 +724,34 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ListParts")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        ListPartsBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListPartsResult>;
+    }
 }
 
 /// 基础操作
 #[allow(non_snake_case)]
-impl<'a> Client<'a> {
+impl<'a, M> Client<M> {
     /// 使用Multipart Upload模式传输数据前,您必须先调用InitiateMultipartUpload接口来通知OSS初始化一
     /// 个Multipart Upload事件。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/initiatemultipartupload)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_init.rs)
-    pub fn InitiateMultipartUpload(&self, object: &'a str) -> InitiateMultipartUploadBuilder {
+    pub fn InitiateMultipartUpload(
+        &'a self,
+        object: &'a str,
+    ) -> InitiateMultipartUploadBuilder<'a, M> {
         InitiateMultipartUploadBuilder::new(self, object)
     }
 
@@ -581,7 +759,7 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/uploadpart)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_upload_part.rs)
-    pub fn UploadPart(&self, object: &'a str) -> UploadPartBuilder {
+    pub fn UploadPart(&'a self, object: &'a str) -> UploadPartBuilder<'a, M> {
         UploadPartBuilder::new(self, object)
     }
 
@@ -590,7 +768,7 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/uploadpartcopy)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_upload_part.rs)
-    pub fn UploadPartCopy(&self) -> UploadPartCopyBuilder {
+    pub fn UploadPartCopy(&'a self) -> UploadPartCopyBuilder<'a, M> {
         UploadPartCopyBuilder::new(self)
     }
 
@@ -598,7 +776,10 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/completemultipartupload)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_comp.rs)
-    pub fn CompleteMultipartUpload(&self, object: &'a str) -> CompleteMultipartUploadBuilder {
+    pub fn CompleteMultipartUpload(
+        &'a self,
+        object: &'a str,
+    ) -> CompleteMultipartUploadBuilder<'a, M> {
         CompleteMultipartUploadBuilder::new(self, object)
     }
 
@@ -606,7 +787,7 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_abort.rs)
-    pub fn AbortMultipartUpload(&self, object: &'a str) -> AbortMultipartUploadBuilder {
+    pub fn AbortMultipartUpload(&'a self, object: &'a str) -> AbortMultipartUploadBuilder<'a, M> {
         AbortMultipartUploadBuilder::new(self, object)
     }
 
@@ -615,7 +796,7 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listmultipartuploads)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_list.rs)
-    pub fn ListMultipartUploads(&self) -> ListMultipartUploadsBuilder {
+    pub fn ListMultipartUploads(&'a self) -> ListMultipartUploadsBuilder<'a, M> {
         ListMultipartUploadsBuilder::new(self)
     }
 
@@ -623,7 +804,7 @@ impl<'a> Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listparts)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_mutil_list_part.rs)
-    pub fn ListParts(&self, object: &'a str) -> ListPartsBuilder {
+    pub fn ListParts(&'a self, object: &'a str) -> ListPartsBuilder<'a, M> {
         ListPartsBuilder::new(self, object)
     }
 }
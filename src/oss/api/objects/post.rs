@@ -0,0 +1,253 @@
+use crate::oss;
+
+use self::builders::PostObjectPolicyBuilder;
+
+pub mod builders {
+    use base64::{engine::general_purpose, Engine as _};
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::oss::{
+        self,
+        api::Error,
+        entities::{
+            callback::Callback,
+            post::{PostObjectForm, PostPolicy, PostPolicyCondition},
+        },
+    };
+
+    /// Policy默认有效时长(秒)
+    pub const DEFAULT_POST_POLICY_EXPIRES: u64 = 3600;
+
+    #[derive(Debug)]
+    pub struct PostObjectPolicyBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        expires: u64,
+        expiration: Option<DateTime<Utc>>,
+        key: Option<&'a str>,
+        key_prefix: Option<&'a str>,
+        content_length_range: Option<(u64, u64)>,
+        success_action_status: Option<u16>,
+        conditions: Vec<PostPolicyCondition>,
+        fields: Vec<(&'a str, &'a str)>,
+        callback: Option<Callback>,
+    }
+
+    impl<'a, M> PostObjectPolicyBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
+            Self {
+                client,
+                expires: DEFAULT_POST_POLICY_EXPIRES,
+                expiration: None,
+                key: None,
+                key_prefix: None,
+                content_length_range: None,
+                success_action_status: None,
+                conditions: Vec::new(),
+                fields: Vec::new(),
+                callback: None,
+            }
+        }
+
+        /// Policy有效时长(秒)
+        pub fn with_expires(mut self, value: u64) -> Self {
+            self.expires = value;
+            self
+        }
+
+        /// Policy过期时间, 优先于`with_expires`
+        pub fn with_expiration(mut self, value: DateTime<Utc>) -> Self {
+            self.expiration = Some(value);
+            self
+        }
+
+        /// 限定上传的文件名, 同时写入`key`表单域
+        pub fn with_key(mut self, value: &'a str) -> Self {
+            self.key = Some(value);
+            self
+        }
+
+        /// 限定上传文件名的前缀, 表单中的`key`须由调用方填写
+        pub fn with_key_prefix(mut self, value: &'a str) -> Self {
+            self.key_prefix = Some(value);
+            self
+        }
+
+        /// 上传文件大小范围(字节)
+        pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+            self.content_length_range = Some((min, max));
+            self
+        }
+
+        /// 上传成功后返回的状态码, 可选200、201、204
+        pub fn with_success_action_status(mut self, value: u16) -> Self {
+            self.success_action_status = Some(value);
+            self
+        }
+
+        /// 表单域的值必须与指定值完全匹配
+        pub fn with_eq(mut self, field: &'a str, value: &'a str) -> Self {
+            self.conditions
+                .push(PostPolicyCondition::Eq(field.into(), value.into()));
+            self
+        }
+
+        /// 表单域的值必须以指定前缀开始
+        pub fn with_starts_with(mut self, field: &'a str, value: &'a str) -> Self {
+            self.conditions
+                .push(PostPolicyCondition::StartsWith(field.into(), value.into()));
+            self
+        }
+
+        /// 其他Policy条件
+        pub fn with_condition(mut self, value: PostPolicyCondition) -> Self {
+            self.conditions.push(value);
+            self
+        }
+
+        /// 额外的表单域, 例如`Content-Type`、`x-oss-meta-*`
+        pub fn with_field(mut self, key: &'a str, value: &'a str) -> Self {
+            self.fields.push((key, value));
+            self
+        }
+
+        /// 上传回调
+        pub fn with_callback(mut self, value: Callback) -> Self {
+            self.callback = Some(value);
+            self
+        }
+
+        fn policy(&self, now: &DateTime<Utc>, signed_fields: &[(String, String)]) -> PostPolicy {
+            let expiration = self
+                .expiration
+                .unwrap_or(*now + Duration::seconds(self.expires as i64));
+            let mut conditions = vec![PostPolicyCondition::Bucket(
+                self.client.bucket().to_string(),
+            )];
+            if let Some(key) = self.key {
+                conditions.push(PostPolicyCondition::Eq("key".into(), key.into()));
+            }
+            if let Some(key_prefix) = self.key_prefix {
+                conditions.push(PostPolicyCondition::StartsWith(
+                    "key".into(),
+                    key_prefix.into(),
+                ));
+            }
+            if let Some((min, max)) = self.content_length_range {
+                conditions.push(PostPolicyCondition::ContentLengthRange(min, max));
+            }
+            if let Some(status) = self.success_action_status {
+                conditions.push(PostPolicyCondition::Eq(
+                    "success_action_status".into(),
+                    status.to_string(),
+                ));
+            }
+            for (key, value) in signed_fields {
+                if key.starts_with("x-oss-") {
+                    conditions.push(PostPolicyCondition::Eq(key.into(), value.into()));
+                }
+            }
+            conditions.extend(self.conditions.iter().cloned());
+            PostPolicy {
+                expiration,
+                conditions,
+            }
+        }
+
+        /// 生成Policy、签名与表单域
+        async fn inner_execute(&self) -> Result<PostObjectForm, Error> {
+            let request = &self.client.request;
+            let now = request.now();
+            let credentials = request.credentials().await?;
+            let signed_fields = request.post_fields(&now, &credentials);
+            let policy = self.policy(&now, &signed_fields);
+            let policy = serde_json::to_string(&policy)
+                .map_err(|e| Error::Config(format!("invalid post policy: {}", e)))?;
+            let policy = general_purpose::STANDARD.encode(policy);
+            let (signature_field, signature) = request.post_signature(&policy, &now, &credentials);
+
+            let mut fields: Vec<(String, String)> = Vec::new();
+            if let Some(key) = self.key {
+                fields.push(("key".into(), key.into()));
+            }
+            fields.push(("policy".into(), policy.to_owned()));
+            fields.extend(signed_fields);
+            fields.push((signature_field.into(), signature.to_owned()));
+            if let Some(status) = self.success_action_status {
+                fields.push(("success_action_status".into(), status.to_string()));
+            }
+            if let Some(callback) = &self.callback {
+                fields.push(("callback".into(), callback.to_base64()?));
+            }
+            for (key, value) in &self.fields {
+                fields.push((key.to_string(), value.to_string()));
+            }
+
+            Ok(PostObjectForm {
+                url: self.client.base_url()?,
+                policy,
+                signature,
+                fields,
+            })
+        }
+    }
+
+    oss::builder_execute! {
+        PostObjectPolicyBuilder,
+        fn execute => inner_execute -> Result<PostObjectForm, Error>;
+    }
+}
+
+/// # 表单上传
+#[allow(non_snake_case)]
+impl<'a, M> oss::Client<M> {
+    /// 生成PostObject表单上传所需的Policy、签名与表单域,
+    /// 浏览器通过HTML表单直接上传文件`Object`到Bucket
+    ///
+    /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/postobject)
+    pub fn PostObjectPolicy(&'a self) -> PostObjectPolicyBuilder<'a, M> {
+        PostObjectPolicyBuilder::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oss;
+    use base64::{engine::general_purpose, Engine as _};
+
+    #[tokio::test]
+    async fn post_object_policy() {
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_sts_token("sts")
+            .with_signature_version(oss::SignatureVersion::V4)
+            .client();
+        let form = client
+            .PostObjectPolicy()
+            .with_key_prefix("user/")
+            .with_content_length_range(1, 1024)
+            .with_success_action_status(201)
+            .execute()
+            .await
+            .unwrap();
+        let policy = general_purpose::STANDARD.decode(&form.policy).unwrap();
+        let policy = String::from_utf8(policy).unwrap();
+        assert!(policy.contains(r#"{"bucket":"xtoss-ex"},["starts-with","$key","user/"],["content-length-range",1,1024],["eq","$success_action_status","201"],["eq","$x-oss-signature-version","OSS4-HMAC-SHA256"]"#));
+        assert!(policy.contains(r#"["eq","$x-oss-security-token","sts"]"#));
+        let keys: Vec<&str> = form.fields.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "policy",
+                "x-oss-signature-version",
+                "x-oss-credential",
+                "x-oss-date",
+                "x-oss-security-token",
+                "x-oss-signature",
+                "success_action_status"
+            ]
+        );
+        assert_eq!(form.url, "http://xtoss-ex.oss-cn-hangzhou.aliyuncs.com");
+    }
+}
@@ -0,0 +1,206 @@
+use crate::oss;
+
+use self::builders::PresignObjectBuilder;
+
+pub mod builders {
+    use std::collections::BTreeMap;
+
+    use crate::oss::{
+        self,
+        api::{insert_custom_header, insert_header, Error},
+        http::{self, header::CONTENT_TYPE},
+    };
+
+    /// 签名url默认有效时长(秒)
+    pub const DEFAULT_PRESIGN_EXPIRES: u64 = 3600;
+
+    #[derive(Debug)]
+    pub struct PresignObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        object: &'a str,
+        method: http::Method,
+        expires: u64,
+        headers: http::HeaderMap,
+        query: BTreeMap<&'a str, &'a str>,
+    }
+
+    impl<'a, M> PresignObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
+            Self {
+                client,
+                object,
+                method: http::Method::GET,
+                expires: DEFAULT_PRESIGN_EXPIRES,
+                headers: http::HeaderMap::new(),
+                query: BTreeMap::new(),
+            }
+        }
+
+        /// 请求方法, 默认`GET`
+        pub fn with_method(mut self, value: http::Method) -> Self {
+            self.method = value;
+            self
+        }
+
+        /// 有效时长(秒), V4签名最大为604800(7天)
+        pub fn with_expires(mut self, value: u64) -> Self {
+            self.expires = value;
+            self
+        }
+
+        pub fn with_version_id(mut self, value: &'a str) -> Self {
+            self.query.insert("versionId", value);
+            self
+        }
+
+        pub fn with_response_content_type(mut self, value: &'a str) -> Self {
+            self.query.insert("response-content-type", value);
+            self
+        }
+
+        pub fn with_response_content_language(mut self, value: &'a str) -> Self {
+            self.query.insert("response-content-language", value);
+            self
+        }
+
+        pub fn with_response_expires(mut self, value: &'a str) -> Self {
+            self.query.insert("response-expires", value);
+            self
+        }
+
+        pub fn with_response_cache_control(mut self, value: &'a str) -> Self {
+            self.query.insert("response-cache-control", value);
+            self
+        }
+
+        pub fn with_response_content_disposition(mut self, value: &'a str) -> Self {
+            self.query.insert("response-content-disposition", value);
+            self
+        }
+
+        pub fn with_response_content_encoding(mut self, value: &'a str) -> Self {
+            self.query.insert("response-content-encoding", value);
+            self
+        }
+
+        /// 其他子资源参数, 例如`uploadId`、`partNumber`、`x-oss-process`
+        ///
+        /// 使用V1签名时仅OSS规定的子资源参与签名, 请勿传入普通查询参数
+        pub fn with_parameter(mut self, key: &'a str, value: &'a str) -> Self {
+            self.query.insert(key, value);
+            self
+        }
+
+        /// 上传时浏览器须携带相同的`Content-Type`
+        pub fn with_content_type(mut self, value: &'a str) -> Self {
+            insert_header(&mut self.headers, CONTENT_TYPE, value);
+            self
+        }
+
+        /// 上传时浏览器须携带相同的`Content-MD5`
+        pub fn with_content_md5(mut self, value: &'a str) -> Self {
+            insert_custom_header(&mut self.headers, "Content-MD5", value);
+            self
+        }
+
+        /// 参与签名的`x-oss-*`头部, 请求时须携带
+        pub fn with_oss_header(mut self, key: &'a str, value: &'a str) -> Self {
+            insert_custom_header(&mut self.headers, key, value);
+            self
+        }
+
+        pub(crate) fn query(&self) -> String {
+            self.query
+                .iter()
+                .map(|(key, value)| match value.is_empty() {
+                    true => key.to_string(),
+                    false => format!("{}={}", key, urlencoding::encode(value)),
+                })
+                .collect::<Vec<String>>()
+                .join("&")
+        }
+
+        /// 返回签名后的url
+        async fn inner_execute(&self) -> Result<String, Error> {
+            let mut res = format!("/{}/{}", self.client.bucket(), self.object);
+            let mut url = self.client.object_url(self.object)?;
+            let query = self.query();
+            if !query.is_empty() {
+                res = format!("{}?{}", res, query);
+                url = format!("{}?{}", url, query);
+            }
+
+            self.client
+                .request
+                .task()
+                .with_url(&url)
+                .with_method(self.method.to_owned())
+                .with_headers(self.headers.to_owned())
+                .with_resource(&res)
+                .presign(self.expires)
+                .await
+        }
+    }
+
+    oss::builder_execute! {
+        PresignObjectBuilder,
+        fn execute => inner_execute -> Result<String, Error>;
+    }
+}
+
+/// # 签名url
+#[allow(non_snake_case)]
+impl<'a, M> oss::Client<M> {
+    /// 生成携带签名的url, 可直接交给浏览器或第三方访问文件`Object`,
+    /// 支持任意文件操作(通过`with_method`与`with_parameter`指定)
+    ///
+    /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/ddd-signatures-to-urls)
+    pub fn PresignObject(&'a self, object: &'a str) -> PresignObjectBuilder<'a, M> {
+        PresignObjectBuilder::new(self, object)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::oss;
+
+    #[tokio::test]
+    async fn presign_object_v1() {
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_sts_token("sts")
+            .client();
+        let url = client
+            .PresignObject("example/ex1.txt")
+            .with_version_id("version123")
+            .with_response_content_type("text/plain")
+            .execute()
+            .await
+            .unwrap();
+        assert!(url.starts_with("http://xtoss-ex.oss-cn-hangzhou.aliyuncs.com/example/ex1.txt?response-content-type=text%2Fplain&versionId=version123&OSSAccessKeyId=access_key_id&Expires="));
+        assert!(url.contains("&Signature="));
+        assert!(url.ends_with("&security-token=sts"));
+    }
+
+    #[tokio::test]
+    async fn presign_object_v4() {
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_signature_version(oss::SignatureVersion::V4)
+            .client();
+        let url = client
+            .PresignObject("example/ex1.txt")
+            .with_method(oss::http::Method::PUT)
+            .with_expires(600)
+            .execute()
+            .await
+            .unwrap();
+        assert!(url.starts_with("http://xtoss-ex.oss-cn-hangzhou.aliyuncs.com/example/ex1.txt?x-oss-signature-version=OSS4-HMAC-SHA256&x-oss-credential=access_key_id%2F"));
+        assert!(url.contains("%2Fcn-hangzhou%2Foss%2Faliyun_v4_request&x-oss-date="));
+        assert!(url.contains("&x-oss-expires=600&x-oss-signature="));
+    }
+}
@@ -9,8 +9,9 @@ use self::builders::{
 
 pub mod builders {
 
-    use std::collections::HashMap;
+    use std::{collections::HashMap, path::Path};
 
+    use crate::oss::{crc64, progress::ProgressSink, transport::ByteStream, Response};
     use chrono::{DateTime, Utc};
     use oss::http::{
         header::{
@@ -20,26 +21,22 @@ pub mod builders {
         },
         CacheControl, ContentDisposition, ContentEncoding,
     };
-    use reqwest::Response;
     use serde::{Deserialize, Serialize};
 
-    use crate::{oss::entities::object::delete_multiple::DeleteResult, util::ByteRange};
-    use crate::{
-        oss::{
-            self,
-            api::{self, insert_custom_header, insert_header, ApiResponseFrom},
-            entities::{
-                object::{
-                    delete_multiple::{Delete, Object},
-                    CopyObjectResult, JobParameters, MetadataDirective, RestoreRequest,
-                    TaggingDirective, Tier,
-                },
-                ObjectACL, ServerSideEncryption, StorageClass,
+    use crate::oss::{
+        self,
+        api::{self, insert_custom_header, insert_header, ApiResponseFrom},
+        entities::{
+            object::{
+                delete_multiple::{Delete, Object},
+                CopyObjectResult, JobParameters, MetadataDirective, RestoreRequest,
+                TaggingDirective, Tier,
             },
-            http, Bytes,
+            ObjectACL, ServerSideEncryption, StorageClass,
         },
-        util::oss_md5,
+        http, Bytes,
     };
+    use crate::{oss::entities::object::delete_multiple::DeleteResult, util::ByteRange};
 
     #[derive(Debug, Default)]
     struct PutObjectBuilderHeaders<'a> {
@@ -63,25 +60,45 @@ pub mod builders {
         oss_meta: Option<Vec<(&'a str, &'a str)>>,
     }
 
-    pub struct PutObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+        traffic_limit: Option<u64>,
         object: &'a str,
-        content: oss::Bytes,
+        content: oss::UploadBody,
+        compute_content_md5: Option<bool>,
+        progress: Option<ProgressSink>,
         headers: PutObjectBuilderHeaders<'a>,
         timeout: Option<u64>,
     }
 
-    impl<'a> PutObjectBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> PutObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
-                content: oss::Bytes::new(),
+                content: oss::UploadBody::default(),
+                compute_content_md5: None,
+                progress: None,
                 headers: PutObjectBuilderHeaders::default(),
                 timeout: None,
+                traffic_limit: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        /// 单链接限速, 单位bit/s, 取值范围为`api::TRAFFIC_LIMIT_RANGE`(100 KB/s ~ 100 MB/s)
+        pub fn with_traffic_limit(mut self, value: u64) -> Self {
+            self.traffic_limit = Some(value);
+            self
+        }
+
         pub fn with_content_type(mut self, value: &'a str) -> Self {
             self.headers.content_type = Some(value.to_string());
             self
@@ -112,6 +129,12 @@ pub mod builders {
             self
         }
 
+        /// 是否自动计算`Content-MD5`, 覆盖客户端的设置, 已通过`with_content_md5`指定时不计算
+        pub fn with_compute_content_md5(mut self, value: bool) -> Self {
+            self.compute_content_md5 = Some(value);
+            self
+        }
+
         pub fn with_content_length(mut self, value: u64) -> Self {
             self.headers.content_length = Some(value);
             self
@@ -168,7 +191,20 @@ pub mod builders {
         }
 
         pub fn with_content(mut self, content: oss::Bytes) -> Self {
-            self.content = content;
+            self.content = content.into();
+            self
+        }
+
+        /// 以数据流方式上传文件、`AsyncRead`或`Stream`, 见[`oss::UploadBody`];
+        /// 不限制上传的总时长, 超时时间为连续未发送数据的时长
+        pub fn with_body(mut self, value: oss::UploadBody) -> Self {
+            self.content = value;
+            self
+        }
+
+        /// 报告上传进度, 见[`oss::ProgressListener`]
+        pub fn with_progress(mut self, value: impl oss::ProgressListener + 'static) -> Self {
+            self.progress = Some(ProgressSink::new(value));
             self
         }
 
@@ -264,26 +300,41 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let res = format!("/{}/{}", self.client.bucket(), self.object);
-            let url = self.client.object_url(self.object);
-            let headers = self.headers();
+            let url = self.client.object_url(self.object)?;
+            let mut headers = self.headers();
+            api::insert_traffic_limit(&mut headers, self.traffic_limit)?;
 
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("PutObject")
+                .with_plane(oss::Plane::Data)
+                .with_retry_policy(self.retry_policy)
+                .with_crc64_check(self.client.crc64_check().then_some(0))
+                .with_compute_content_md5(
+                    self.compute_content_md5
+                        .unwrap_or(self.client.compute_content_md5()),
+                )
+                .with_progress(self.progress.clone())
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_headers(headers)
-                .with_body(self.content.to_owned())
+                .with_upload_body(self.content.clone())
                 .with_resource(&res)
-                .execute_timeout(self.timeout.unwrap_or(self.client.timeout()))
+                .execute_upload_timeout(self.timeout.unwrap_or(self.client.timeout()))
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        PutObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
+
     #[derive(Debug, Default, Clone)]
     struct CopyObjectBuilderHeaders<'a> {
         copy_source: Option<&'a str>,
@@ -304,21 +355,37 @@ pub mod builders {
     }
 
     #[derive(Debug, Clone)]
-    pub struct CopyObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct CopyObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+        traffic_limit: Option<u64>,
         object: &'a str,
         headers: CopyObjectBuilderHeaders<'a>,
     }
 
-    impl<'a> CopyObjectBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> CopyObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 headers: CopyObjectBuilderHeaders::default(),
+                traffic_limit: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        /// 单链接限速, 单位bit/s, 取值范围为`api::TRAFFIC_LIMIT_RANGE`(100 KB/s ~ 100 MB/s)
+        pub fn with_traffic_limit(mut self, value: u64) -> Self {
+            self.traffic_limit = Some(value);
+            self
+        }
+
         /// 指定CopyObject操作时是否覆盖同名目标Object。当目标Bucket处于已开
         /// 启或已暂停版本控制状态时,x-oss-forbid-overwrite请求Header设置
         /// 无效,即允许覆盖同名Object。
@@ -482,24 +549,33 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult<CopyObjectResult> {
+        async fn inner_execute(&self) -> api::ApiResult<CopyObjectResult> {
             let res = format!("/{}/{}", self.client.bucket(), self.object);
-            let url = self.client.object_url(self.object);
-            let headers = self.headers();
+            let url = self.client.object_url(self.object)?;
+            let mut headers = self.headers();
+            api::insert_traffic_limit(&mut headers, self.traffic_limit)?;
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("CopyObject")
+                .with_plane(oss::Plane::Data)
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_headers(headers)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
+    oss::builder_execute! {
+        CopyObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<CopyObjectResult>;
+    }
+
     #[derive(Debug, Default)]
     struct AppendObjectBuilderHeaders<'a> {
         cache_control: Option<http::CacheControl>,
@@ -514,35 +590,76 @@ pub mod builders {
         oss_tagging: Option<Vec<(&'a str, &'a str)>>,
     }
 
-    pub struct AppendObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct AppendObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+        traffic_limit: Option<u64>,
         object: String,
         position: usize,
-        content: oss::Bytes,
+        init_crc64: Option<u64>,
+        content: oss::UploadBody,
+        compute_content_md5: Option<bool>,
+        progress: Option<ProgressSink>,
         headers: AppendObjectBuilderHeaders<'a>,
     }
 
-    impl<'a> AppendObjectBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> AppendObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object: object.to_string(),
                 position: 0,
-                content: oss::Bytes::new(),
+                init_crc64: None,
+                content: oss::UploadBody::default(),
+                compute_content_md5: None,
+                progress: None,
                 headers: AppendObjectBuilderHeaders::default(),
+                traffic_limit: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        /// 单链接限速, 单位bit/s, 取值范围为`api::TRAFFIC_LIMIT_RANGE`(100 KB/s ~ 100 MB/s)
+        pub fn with_traffic_limit(mut self, value: u64) -> Self {
+            self.traffic_limit = Some(value);
+            self
+        }
+
         pub fn with_position(mut self, value: usize) -> Self {
             self.position = value;
             self
         }
 
+        /// 追加前对象的CRC64, 即上次追加响应的`crc64()`, 开启CRC64校验且`position`不为0时用于校验追加后的对象
+        pub fn with_init_crc64(mut self, value: u64) -> Self {
+            self.init_crc64 = Some(value);
+            self
+        }
+
         pub fn with_content(mut self, value: oss::Bytes) -> Self {
+            self.content = value.into();
+            self
+        }
+
+        /// 以数据流方式追加文件、`AsyncRead`或`Stream`, 见[`oss::UploadBody`];
+        /// 不限制上传的总时长, 超时时间为连续未发送数据的时长
+        pub fn with_body(mut self, value: oss::UploadBody) -> Self {
             self.content = value;
             self
         }
 
+        /// 报告上传进度, 见[`oss::ProgressListener`]
+        pub fn with_progress(mut self, value: impl oss::ProgressListener + 'static) -> Self {
+            self.progress = Some(ProgressSink::new(value));
+            self
+        }
+
         pub fn with_cache_control(mut self, value: CacheControl) -> Self {
             self.headers.cache_control = Some(value);
             self
@@ -581,6 +698,12 @@ pub mod builders {
             self
         }
 
+        /// 是否自动计算`Content-MD5`, 覆盖客户端的设置, 已通过`with_content_md5`指定时不计算
+        pub fn with_compute_content_md5(mut self, value: bool) -> Self {
+            self.compute_content_md5 = Some(value);
+            self
+        }
+
         pub fn with_oss_tagging(mut self, value: Vec<(&'a str, &'a str)>) -> Self {
             self.headers.oss_tagging = Some(value);
             self
@@ -641,7 +764,7 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let res = format!(
                 "/{}/{}?append&position={}",
                 self.client.bucket(),
@@ -650,29 +773,49 @@ pub mod builders {
             );
             let url = format!(
                 "{}?append&position={}",
-                self.client.object_url(&self.object),
+                self.client.object_url(&self.object)?,
                 self.position
             );
 
             let mut headers = self.headers();
             headers.insert(CONTENT_LENGTH, self.content.len().into());
+            // 未给出追加前对象的CRC64时无法校验
+            let init_crc64 = match self.position {
+                0 => Some(0),
+                _ => self.init_crc64,
+            };
+            api::insert_traffic_limit(&mut headers, self.traffic_limit)?;
             // dbg!(&url);
             // dbg!(&headers);
             let resp = self
                 .client
                 .request
                 .task()
+                .with_operation("AppendObject")
+                .with_plane(oss::Plane::Data)
+                .with_retry_policy(self.retry_policy)
+                .with_crc64_check(init_crc64.filter(|_| self.client.crc64_check()))
+                .with_compute_content_md5(
+                    self.compute_content_md5
+                        .unwrap_or(self.client.compute_content_md5()),
+                )
+                .with_progress(self.progress.clone())
                 .with_url(&url)
                 .with_headers(headers)
                 .with_resource(&res)
-                .with_body(self.content.to_owned())
+                .with_upload_body(self.content.clone())
                 .with_method(http::Method::POST)
-                .execute()
+                .execute_upload_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        AppendObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
     #[derive(Debug, Default, Serialize, Deserialize)]
     pub(crate) struct GetObjectBuilderQuery<'a> {
         #[serde(
@@ -707,8 +850,10 @@ pub mod builders {
     }
 
     #[derive(Debug)]
-    pub struct GetObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct GetObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
+        traffic_limit: Option<u64>,
         object: &'a str,
         range: Option<ByteRange>,
         modified_since: Option<DateTime<Utc>>,
@@ -718,10 +863,11 @@ pub mod builders {
         accept_encoding: Option<&'a str>,
         query: GetObjectBuilderQuery<'a>,
         timeout: Option<u64>,
+        progress: Option<ProgressSink>,
     }
 
-    impl<'a> GetObjectBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> GetObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
@@ -733,9 +879,24 @@ pub mod builders {
                 accept_encoding: None,
                 query: GetObjectBuilderQuery::default(),
                 timeout: None,
+                progress: None,
+                traffic_limit: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
+        /// 单链接限速, 单位bit/s, 取值范围为`api::TRAFFIC_LIMIT_RANGE`(100 KB/s ~ 100 MB/s)
+        pub fn with_traffic_limit(mut self, value: u64) -> Self {
+            self.traffic_limit = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.query.version_id = Some(value);
             self
@@ -801,6 +962,13 @@ pub mod builders {
             self
         }
 
+        /// 报告下载进度, 总长度取自响应的`Content-Length`, 见[`oss::ProgressListener`]
+        pub fn with_progress(mut self, value: impl oss::ProgressListener + 'static) -> Self {
+            self.progress = Some(ProgressSink::new(value));
+            self
+        }
+
+        /// 超时时间(秒), `execute_stream`、`write_to`、`download_to_file`中为连续未收到数据的时长
         pub fn with_timeout(mut self, value: u64) -> Self {
             self.timeout = Some(value);
             self
@@ -834,9 +1002,10 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult<Bytes> {
+        /// `stream`为`true`时以数据流读取响应体, 超时时间为空闲超时, 不限制总时长
+        async fn send(&self, stream: bool) -> oss::Result<Response> {
             let mut res = format!("/{}/{}", self.client.bucket(), self.object);
-            let mut url = self.client.object_url(self.object);
+            let mut url = self.client.object_url(self.object)?;
             let query = self.query();
             // dbg!(&query);
             if !query.is_empty() {
@@ -844,45 +1013,158 @@ pub mod builders {
                 url = format!("{}?{}", url, query)
             }
 
-            let headers = self.headers();
-            let resp = self
+            let mut headers = self.headers();
+            api::insert_traffic_limit(&mut headers, self.traffic_limit)?;
+            let task = self
                 .client
                 .request
                 .task()
+                .with_operation("GetObject")
+                .with_plane(oss::Plane::Data)
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_headers(headers)
-                .with_resource(&res)
-                .execute_timeout(self.timeout.unwrap_or(self.client.timeout()))
+                .with_resource(&res);
+            let timeout = self.timeout.unwrap_or(self.client.timeout());
+            let resp = match stream {
+                true => task.execute_idle_timeout(timeout).await?,
+                false => task.execute_timeout(timeout).await?,
+            };
+            Ok(match &self.progress {
+                Some(progress) if resp.status().is_success() => {
+                    resp.with_progress(progress.clone())
+                }
+                _ => resp,
+            })
+        }
+
+        /// 只校验完整对象, 指定范围或压缩传输时响应内容与对象不同
+        fn crc64_check(&self, status: http::StatusCode) -> bool {
+            self.client.crc64_check()
+                && status == http::StatusCode::OK
+                && self.range.is_none()
+                && self.accept_encoding.is_none()
+        }
+
+        async fn inner_execute(&self) -> api::ApiResult<Bytes> {
+            let data = ApiResponseFrom(self.send(false).await?)
+                .into_bytes()
+                .await?;
+            if self.crc64_check(data.status) {
+                crc64::verify(crc64::checksum(&data.content), &data.headers)?;
+            }
+            Ok(data)
+        }
+
+        async fn inner_execute_stream(&self) -> api::ApiResult<ByteStream> {
+            let mut data = ApiResponseFrom(self.send(true).await?)
+                .into_stream()
                 .await?;
+            if let Some(server_crc) = data.crc64().filter(|_| self.crc64_check(data.status)) {
+                data.content = data.content.with_crc64_check(server_crc);
+            }
+            Ok(data)
+        }
+
+        async fn inner_download_to_file(&self, path: &Path) -> api::ApiResult<u64> {
+            let data = self.inner_execute_stream().await?;
+            let write = async {
+                let mut file = tokio::fs::File::create(path).await?;
+                let written = data.content.write_to(&mut file).await?;
+                file.sync_all().await?;
+                Ok(written)
+            };
+            match write.await {
+                Ok(written) => Ok(api::ApiData {
+                    url: data.url,
+                    status: data.status,
+                    headers: data.headers,
+                    content: written,
+                }),
+                Err(error) => {
+                    // 不保留下载不完整的文件
+                    let _ = tokio::fs::remove_file(path).await;
+                    Err(error)
+                }
+            }
+        }
+    }
 
-            Ok(ApiResponseFrom(resp).to_bytes().await)
+    oss::builder_execute! {
+        GetObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<Bytes>;
+    }
+
+    impl<'a> GetObjectBuilder<'a, oss::Async> {
+        /// 收到响应头后立即返回, 对象数据以数据流形式读取, 不在内存中缓存整个对象
+        ///
+        /// 不限制下载的总时长, 超时时间(`with_timeout`)为连续未收到数据的时长;
+        /// 设置`Options::with_read_timeout`时读取数据流使用读超时
+        pub async fn execute_stream(&self) -> api::ApiResult<ByteStream> {
+            self.inner_execute_stream().await
+        }
+
+        /// 将对象数据写入`writer`, 返回写入的字节数
+        pub async fn write_to<W>(&self, writer: &mut W) -> api::ApiResult<u64>
+        where
+            W: tokio::io::AsyncWrite + Unpin + ?Sized,
+        {
+            let data = self.inner_execute_stream().await?;
+            let written = data.content.write_to(writer).await?;
+            Ok(api::ApiData {
+                url: data.url,
+                status: data.status,
+                headers: data.headers,
+                content: written,
+            })
+        }
+
+        /// 下载到本地文件, 返回写入的字节数; 失败时删除不完整的文件
+        pub async fn download_to_file(&self, path: impl AsRef<Path>) -> api::ApiResult<u64> {
+            self.inner_download_to_file(path.as_ref()).await
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    impl<'a> GetObjectBuilder<'a, oss::blocking::Blocking> {
+        /// 下载到本地文件, 返回写入的字节数; 失败时删除不完整的文件
+        pub fn download_to_file(&self, path: impl AsRef<Path>) -> api::ApiResult<u64> {
+            oss::blocking::block_on(self.inner_download_to_file(path.as_ref()))
         }
     }
 
     #[derive(Debug)]
-    pub struct DeleteObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct DeleteObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> DeleteObjectBuilder<'a> {
-        pub fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> DeleteObjectBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let mut res = format!("/{}/{}", self.client.bucket(), self.object);
-            let mut url = self.client.object_url(self.object);
+            let mut url = self.client.object_url(self.object)?;
             if let Some(version_id) = self.version_id {
                 res = format!("{}?versionId={}", res, version_id);
                 url = format!("{}?versionId={}", url, version_id);
@@ -892,19 +1174,27 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteObject")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .with_method(http::Method::DELETE)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
+    oss::builder_execute! {
+        DeleteObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
+
     #[allow(unused)]
-    pub struct DeleteMultipleObjectsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct DeleteMultipleObjectsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         quiet: Option<bool>,
         encoding_type: Option<&'a str>,
         deletes: Vec<(&'a str, &'a str)>,
@@ -912,8 +1202,8 @@ pub mod builders {
         content_md5: Option<&'a str>,
     }
 
-    impl<'a> DeleteMultipleObjectsBuilder<'a> {
-        pub fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> DeleteMultipleObjectsBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 quiet: Some(false),
@@ -921,9 +1211,16 @@ pub mod builders {
                 encoding_type: None,
                 content_length: None,
                 content_md5: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         /// 添加删除目标 Vec<(object, version_id)>
         ///
         pub fn with_deletes(mut self, value: Vec<(&'a str, &'a str)>) -> Self {
@@ -959,19 +1256,19 @@ pub mod builders {
             }
         }
 
-        pub async fn execute(&self) -> api::ApiResult<DeleteResult> {
-            let resp = self.inner_execute(false).await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+        async fn inner_execute(&self) -> api::ApiResult<DeleteResult> {
+            let resp = self.send(false).await?;
+            ApiResponseFrom(resp).into_type().await
         }
 
-        pub async fn execute_quiet(&self) -> api::ApiResult {
-            let resp = self.inner_execute(true).await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+        async fn inner_execute_quiet(&self) -> api::ApiResult {
+            let resp = self.send(true).await?;
+            ApiResponseFrom(resp).into_empty().await
         }
 
-        async fn inner_execute(&self, quiet: bool) -> oss::Result<Response> {
+        async fn send(&self, quiet: bool) -> oss::Result<Response> {
             let res = format!("/{}/?{}", self.client.bucket(), "delete");
-            let url = format!("{}?{}", self.client.base_url(), "delete");
+            let url = format!("{}?{}", self.client.base_url()?, "delete");
             let mut content = self.content();
             content.quiet = Some(quiet);
             let content = quick_xml::se::to_string(&content).unwrap();
@@ -979,8 +1276,6 @@ pub mod builders {
 
             let mut headers = http::header::HeaderMap::new();
             headers.insert(CONTENT_LENGTH, content.len().into());
-            let content_md5 = oss_md5(&content).unwrap();
-            headers.insert("Content-MD5", content_md5.parse().unwrap());
             if let Some(encoding_type) = self.encoding_type {
                 headers.insert("Encoding-type", encoding_type.parse().unwrap());
             }
@@ -988,6 +1283,10 @@ pub mod builders {
             self.client
                 .request
                 .task()
+                .with_operation("DeleteMultipleObjects")
+                .with_retry_policy(self.retry_policy)
+                // 该接口要求携带`Content-MD5`
+                .with_compute_content_md5(true)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_headers(headers)
@@ -998,8 +1297,15 @@ pub mod builders {
         }
     }
 
-    pub struct HeadObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        DeleteMultipleObjectsBuilder,
+        fn execute => inner_execute -> api::ApiResult<DeleteResult>;
+        fn execute_quiet => inner_execute_quiet -> api::ApiResult;
+    }
+
+    pub struct HeadObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
         modified_since: Option<DateTime<Utc>>,
@@ -1008,8 +1314,8 @@ pub mod builders {
         none_match: Option<&'a str>,
     }
 
-    impl<'a> HeadObjectBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> HeadObjectBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
@@ -1018,9 +1324,16 @@ pub mod builders {
                 unmodified_since: None,
                 r#match: None,
                 none_match: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, version_id: &'a str) -> Self {
             self.version_id = Some(version_id);
             self
@@ -1072,9 +1385,9 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}", self.client.bucket(), self.object);
-            let mut url = self.client.object_url(self.object);
+            let mut url = self.client.object_url(self.object)?;
             if let Some(version_id) = self.version_id {
                 res = format!("{}?versionId={}", res, version_id);
                 url = format!("{}?versionId={}", url, version_id);
@@ -1084,6 +1397,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("HeadObject")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::HEAD)
                 .with_headers(self.headers())
@@ -1091,37 +1406,50 @@ pub mod builders {
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetObjectMetaBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        HeadObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetObjectMetaBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> GetObjectMetaBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> GetObjectMetaBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, version_id: &'a str) -> Self {
             self.version_id = Some(version_id);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let mut res = format!(
                 "/{}/{}?{}",
                 self.client.options.bucket, self.object, "objectMeta"
             );
 
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "objectMeta");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "objectMeta");
             // dbg!(&url);
 
             if let Some(version_id) = self.version_id {
@@ -1133,34 +1461,49 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetObjectMeta")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct RestoreObjectBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetObjectMetaBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
+
+    pub struct RestoreObjectBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
         days: Option<u8>,
         tier: Option<Tier>,
     }
 
-    impl<'a> RestoreObjectBuilder<'a> {
-        pub fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> RestoreObjectBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
                 days: None,
                 tier: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_days(mut self, days: u8) -> Self {
             self.days = Some(days);
             self
@@ -1183,9 +1526,9 @@ pub mod builders {
             quick_xml::se::to_string(&request).ok()
         }
 
-        pub async fn execute(&self) -> api::ApiResult<()> {
+        async fn inner_execute(&self) -> api::ApiResult<()> {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "restore");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "restore");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "restore");
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, version_id);
                 url = format!("{}&versionId={}", url, version_id);
@@ -1197,6 +1540,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("RestoreObject")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::POST)
                 .with_body(config)
@@ -1204,19 +1549,24 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        RestoreObjectBuilder,
+        fn execute => inner_execute -> api::ApiResult<()>;
+    }
 }
 
 /// # 基础操作
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutObject接口上传文件`Object`
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_put.rs)
-    pub fn PutObject(&self, object: &'a str) -> PutObjectBuilder {
+    pub fn PutObject(&'a self, object: &'a str) -> PutObjectBuilder<'a, M> {
         PutObjectBuilder::new(self, object)
     }
 
@@ -1224,7 +1574,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_get.rs)
-    pub fn GetObject(&self, object: &'a str) -> GetObjectBuilder {
+    pub fn GetObject(&'a self, object: &'a str) -> GetObjectBuilder<'a, M> {
         GetObjectBuilder::new(self, object)
     }
 
@@ -1232,7 +1582,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/copyobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_copy.rs)
-    pub fn CopyObject(&self, object: &'a str) -> CopyObjectBuilder {
+    pub fn CopyObject(&'a self, object: &'a str) -> CopyObjectBuilder<'a, M> {
         CopyObjectBuilder::new(self, object)
     }
 
@@ -1240,7 +1590,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/appendobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_append.rs)
-    pub fn AppendObject(&self, object: &'a str) -> AppendObjectBuilder {
+    pub fn AppendObject(&'a self, object: &'a str) -> AppendObjectBuilder<'a, M> {
         AppendObjectBuilder::new(self, object)
     }
 
@@ -1248,7 +1598,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deleteobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_del.rs)
-    pub fn DeleteObject(&self, object: &'a str) -> DeleteObjectBuilder {
+    pub fn DeleteObject(&'a self, object: &'a str) -> DeleteObjectBuilder<'a, M> {
         DeleteObjectBuilder::new(self, object)
     }
 
@@ -1256,7 +1606,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_mutil_del.rs)
-    pub fn DeleteMultipleObjects(&self) -> DeleteMultipleObjectsBuilder {
+    pub fn DeleteMultipleObjects(&'a self) -> DeleteMultipleObjectsBuilder<'a, M> {
         DeleteMultipleObjectsBuilder::new(self)
     }
 
@@ -1264,7 +1614,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/headobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_head.rs)
-    pub fn HeadObject(&self, object: &'a str) -> HeadObjectBuilder {
+    pub fn HeadObject(&'a self, object: &'a str) -> HeadObjectBuilder<'a, M> {
         HeadObjectBuilder::new(self, object)
     }
 
@@ -1273,7 +1623,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobjectmeta)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_meta.rs)
-    pub fn GetObjectMeta(&self, object: &'a str) -> GetObjectMetaBuilder {
+    pub fn GetObjectMeta(&'a self, object: &'a str) -> GetObjectMetaBuilder<'a, M> {
         GetObjectMetaBuilder::new(self, object)
     }
 
@@ -1281,7 +1631,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/restoreobject)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_stand_restore.rs)
-    pub fn RestoreObject(&self, object: &'a str) -> RestoreObjectBuilder {
+    pub fn RestoreObject(&'a self, object: &'a str) -> RestoreObjectBuilder<'a, M> {
         RestoreObjectBuilder::new(self, object)
     }
 }
@@ -1318,4 +1668,299 @@ mod tests {
 
         assert_eq!(left, builder.query());
     }
+
+    #[tokio::test]
+    async fn object_traffic_limit() {
+        use crate::oss::transport::tests::MemoryTransport;
+        use std::sync::Arc;
+        let transport = Arc::new(MemoryTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .client();
+        client
+            .GetObject("ex1.txt")
+            .with_traffic_limit(819200)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(
+            transport.requests.lock().unwrap()[0].headers["x-oss-traffic-limit"],
+            "819200"
+        );
+
+        let error = client
+            .PutObject("ex1.txt")
+            .with_traffic_limit(1024)
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, oss::Error::Config(_)));
+        assert_eq!(transport.requests.lock().unwrap().len(), 1);
+    }
+
+    use crate::oss::{
+        http::{self, header::ETAG, HeaderMap},
+        Bytes, Response,
+    };
+
+    /// 按块返回对象数据; `missing.txt`不存在, `broken.txt`读取到一半连接中断
+    #[derive(Debug)]
+    struct ChunkedTransport;
+
+    impl oss::Transport for ChunkedTransport {
+        fn send(
+            &self,
+            request: oss::transport::HttpRequest,
+        ) -> futures::future::BoxFuture<'_, Result<Response, oss::transport::TransportError>>
+        {
+            use crate::oss::transport::{TransportError, TransportErrorKind};
+            use futures::StreamExt;
+            Box::pin(async move {
+                let chunks = match request.url.path() {
+                    "/missing.txt" => {
+                        return Ok(Response::from_bytes(
+                            request.url,
+                            http::StatusCode::NOT_FOUND,
+                            HeaderMap::new(),
+                            "<Error><Code>NoSuchKey</Code></Error>",
+                        ))
+                    }
+                    "/broken.txt" => vec![
+                        Ok(Bytes::from("hello ")),
+                        Err(TransportError::new(TransportErrorKind::Body, "reset")),
+                    ],
+                    _ => ["hello ", "streaming ", "world"]
+                        .into_iter()
+                        .map(|chunk| Ok(Bytes::from(chunk)))
+                        .collect(),
+                };
+                let mut headers = HeaderMap::new();
+                headers.insert(ETAG, http::HeaderValue::from_static("\"etag\""));
+                Ok(Response::new(
+                    request.url,
+                    http::StatusCode::OK,
+                    headers,
+                    futures::stream::iter(chunks).boxed(),
+                ))
+            })
+        }
+    }
+
+    /// `slow.bin`每300毫秒返回一块数据, 共1.5秒; `stall.bin`返回一块后停止发送
+    async fn slow_server() -> oss::Client {
+        use axum::{body::Body, extract::Path, routing::get, Router};
+        use futures::StreamExt;
+        let app = Router::new().route(
+            "/:object",
+            get(|Path(object): Path<String>| async move {
+                let chunks = match object.as_str() {
+                    "stall.bin" => 1,
+                    _ => 5,
+                };
+                let stream = futures::stream::iter(0..chunks).then(|i| async move {
+                    if i > 0 {
+                        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+                    }
+                    Ok::<_, std::io::Error>(Bytes::from(vec![0u8; 1024]))
+                });
+                let stall = futures::stream::once(async {
+                    tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+                    Ok(Bytes::new())
+                });
+                match object.as_str() {
+                    "stall.bin" => Body::from_stream(stream.chain(stall)),
+                    _ => Body::from_stream(stream),
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_cname(true)
+            .with_endpoint(addr.to_string())
+            .with_retry_policy(oss::RetryPolicy::none())
+            .with_timeout(1)
+            .client()
+    }
+
+    #[tokio::test]
+    async fn object_get_stream_timeout() {
+        let client = slow_server().await;
+        let is_timeout = |error: oss::Error| match error {
+            oss::Error::Transport(e) => e.is_timeout(),
+            _ => false,
+        };
+        // 读取到内存时为总超时
+        let error = client.GetObject("slow.bin").execute().await.unwrap_err();
+        assert!(is_timeout(error));
+
+        // 数据流下载的总时长超过超时时间, 只要持续收到数据就不会失败
+        let mut buf = Vec::new();
+        let data = client
+            .GetObject("slow.bin")
+            .write_to(&mut buf)
+            .await
+            .unwrap();
+        assert_eq!(data.content(), 5 * 1024);
+
+        let error = client
+            .GetObject("stall.bin")
+            .write_to(&mut buf)
+            .await
+            .unwrap_err();
+        assert!(is_timeout(error));
+    }
+
+    #[tokio::test]
+    async fn object_get_stream() {
+        use futures::TryStreamExt;
+        use tokio::io::AsyncReadExt;
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(ChunkedTransport)
+            .client();
+
+        let data = client.GetObject("ex1.txt").execute_stream().await.unwrap();
+        assert_eq!(data.headers()[ETAG], "\"etag\"");
+        let chunks: Vec<Bytes> = data.content().try_collect().await.unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), b"hello streaming world");
+
+        let mut text = String::new();
+        let data = client.GetObject("ex1.txt").execute_stream().await.unwrap();
+        data.content()
+            .into_async_read()
+            .read_to_string(&mut text)
+            .await
+            .unwrap();
+        assert_eq!(text, "hello streaming world");
+
+        let mut buf = Vec::new();
+        let data = client
+            .GetObject("ex1.txt")
+            .write_to(&mut buf)
+            .await
+            .unwrap();
+        assert_eq!(data.content(), 21);
+        assert_eq!(buf, b"hello streaming world");
+
+        let path = std::env::temp_dir().join(format!("xt-oss-stream-{}.txt", std::process::id()));
+        let data = client
+            .GetObject("ex1.txt")
+            .download_to_file(&path)
+            .await
+            .unwrap();
+        assert_eq!(data.content(), 21);
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello streaming world");
+
+        // 下载失败时不创建或删除文件
+        let error = client
+            .GetObject("broken.txt")
+            .download_to_file(&path)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, oss::Error::Transport(_)));
+        assert!(!path.exists());
+        let error = client
+            .GetObject("missing.txt")
+            .download_to_file(&path)
+            .await
+            .unwrap_err();
+        assert!(error.is_not_found());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn object_content_md5() {
+        use crate::{oss::transport::tests::MemoryTransport, util::oss_md5};
+        use std::sync::Arc;
+        let transport = Arc::new(MemoryTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .with_compute_content_md5(true)
+            .client();
+        let content_md5 = |index: usize| {
+            transport.requests.lock().unwrap()[index]
+                .headers
+                .get("content-md5")
+                .map(|value| value.to_str().unwrap().to_string())
+        };
+        let expected = oss_md5(b"hello world").unwrap();
+
+        let path = std::env::temp_dir().join(format!("xt-oss-md5-{}.txt", std::process::id()));
+        std::fs::write(&path, "hello world").unwrap();
+        let body = oss::UploadBody::from_path(&path).await.unwrap();
+        client
+            .PutObject("ex1.txt")
+            .with_body(body)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(content_md5(0).as_deref(), Some(expected.as_str()));
+        std::fs::remove_file(&path).unwrap();
+
+        client
+            .AppendObject("ex2.txt")
+            .with_content(Bytes::from("hello world"))
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(content_md5(1).as_deref(), Some(expected.as_str()));
+
+        // 指定的值与单独关闭优先于客户端设置
+        client
+            .PutObject("ex1.txt")
+            .with_content(Bytes::from("hello world"))
+            .with_content_md5("1B2M2Y8AsgTpgAmY7PhCfg==")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(content_md5(2).as_deref(), Some("1B2M2Y8AsgTpgAmY7PhCfg=="));
+        client
+            .PutObject("ex1.txt")
+            .with_content(Bytes::from("hello world"))
+            .with_compute_content_md5(false)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(content_md5(3), None);
+
+        // 只能读取一次的数据流无法计算
+        let reader = oss::UploadBody::from_reader(&b"hello world"[..], 11);
+        let error = client
+            .UploadPart("ex3.txt")
+            .with_upload_id("upload_id")
+            .with_part_number(1)
+            .with_body(reader)
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, oss::Error::Config(_)), "{:?}", error);
+        assert_eq!(transport.requests.lock().unwrap().len(), 4);
+
+        client
+            .DeleteMultipleObjects()
+            .with_deletes(vec![("ex1.txt", "")])
+            .execute_quiet()
+            .await
+            .unwrap();
+        let request = transport.requests.lock().unwrap().pop().unwrap();
+        let body = request.body.as_bytes().unwrap().clone();
+        assert_eq!(
+            request.headers["content-md5"],
+            oss_md5(&body).unwrap().as_str()
+        );
+    }
 }
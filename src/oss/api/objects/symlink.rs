@@ -29,8 +29,9 @@ pub mod builders {
     }
 
     #[derive(Debug)]
-    pub struct PutSymlinkBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutSymlinkBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
         symlink_target: &'a str,
@@ -41,8 +42,8 @@ pub mod builders {
         headers: PutSymlinkBuilderHeaders<'a>,
     }
 
-    impl<'a> PutSymlinkBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> PutSymlinkBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
@@ -53,9 +54,16 @@ pub mod builders {
                 storage_class: None,
                 oss_meta: HashMap::new(),
                 headers: PutSymlinkBuilderHeaders::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_symlink_target(mut self, value: &'a str) -> Self {
             self.symlink_target = value;
             self
@@ -158,9 +166,9 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "symlink");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "symlink");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "symlink");
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, version_id);
                 url = format!("{}&versionId={}", url, version_id);
@@ -172,6 +180,8 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutSymlink")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_headers(headers)
                 .with_method(http::Method::PUT)
@@ -179,33 +189,46 @@ pub mod builders {
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetSymlinkBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutSymlinkBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetSymlinkBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> GetSymlinkBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> GetSymlinkBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "symlink");
-            let mut url = { format!("{}?{}", self.client.object_url(self.object), "symlink") };
+            let mut url = { format!("{}?{}", self.client.object_url(self.object)?, "symlink") };
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, version_id);
                 url = format!("{}&versionId={}", url, version_id);
@@ -215,25 +238,32 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetSymlink")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute()
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        GetSymlinkBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 软链接`Symlink``
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutSymlink接口用于为OSS的目标文件`TargetObject`创建软链接
     /// `Symlink`,您可以通过该软链接访问TargetObject。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putsymlink)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_symlink_put.rs)
-    pub fn PutSymlink(&self, object: &'a str) -> PutSymlinkBuilder<'_> {
+    pub fn PutSymlink(&'a self, object: &'a str) -> PutSymlinkBuilder<'a, M> {
         PutSymlinkBuilder::new(self, object)
     }
 
@@ -241,7 +271,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getsymlink)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_symlink_get.rs)
-    pub fn GetSymlink(&self, object: &'a str) -> GetSymlinkBuilder<'_> {
+    pub fn GetSymlink(&'a self, object: &'a str) -> GetSymlinkBuilder<'a, M> {
         GetSymlinkBuilder::new(self, object)
     }
 }
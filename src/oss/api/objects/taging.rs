@@ -10,23 +10,31 @@ pub mod builders {
         http,
     };
 
-    pub struct PutObjectTaggingBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct PutObjectTaggingBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
         tags: Vec<(&'a str, &'a str)>,
     }
 
-    impl<'a> PutObjectTaggingBuilder<'a> {
-        pub fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> PutObjectTaggingBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
                 tags: Vec::new(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
@@ -57,9 +65,9 @@ pub mod builders {
             quick_xml::se::to_string(&self.tagging()).unwrap()
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "tagging");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "tagging");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "tagging");
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, &version_id);
                 url = format!("{}&versionId={}", url, &version_id);
@@ -71,39 +79,54 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("PutObjectTagging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::PUT)
                 .with_resource(&res)
                 .with_body(data)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
 
-    pub struct GetObjectTaggingbuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        PutObjectTaggingBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
+
+    pub struct GetObjectTaggingbuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> GetObjectTaggingbuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> GetObjectTaggingbuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<Tagging> {
+        async fn inner_execute(&self) -> api::ApiResult<Tagging> {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "tagging");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "tagging");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "tagging");
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, &version_id);
                 url = format!("{}&versionId={}", url, &version_id);
@@ -113,37 +136,52 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("GetObjectTagging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
 
-    pub struct DeleteObjectTaggingBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    oss::builder_execute! {
+        GetObjectTaggingbuilder,
+        fn execute => inner_execute -> api::ApiResult<Tagging>;
+    }
+
+    pub struct DeleteObjectTaggingBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         object: &'a str,
         version_id: Option<&'a str>,
     }
 
-    impl<'a> DeleteObjectTaggingBuilder<'a> {
-        pub fn new(client: &'a oss::Client, object: &'a str) -> Self {
+    impl<'a, M> DeleteObjectTaggingBuilder<'a, M> {
+        pub fn new(client: &'a oss::Client<M>, object: &'a str) -> Self {
             Self {
                 client,
                 object,
                 version_id: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_version_id(mut self, value: &'a str) -> Self {
             self.version_id = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult {
+        async fn inner_execute(&self) -> api::ApiResult {
             let mut res = format!("/{}/{}?{}", self.client.bucket(), self.object, "tagging");
-            let mut url = format!("{}?{}", self.client.object_url(self.object), "tagging");
+            let mut url = format!("{}?{}", self.client.object_url(self.object)?, "tagging");
             if let Some(version_id) = self.version_id {
                 res = format!("{}&versionId={}", res, &version_id);
                 url = format!("{}&versionId={}", url, &version_id);
@@ -153,26 +191,33 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("DeleteObjectTagging")
+                .with_retry_policy(self.retry_policy)
                 .with_url(&url)
                 .with_method(http::Method::DELETE)
                 .with_resource(&res)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_empty().await)
+            ApiResponseFrom(resp).into_empty().await
         }
     }
+
+    oss::builder_execute! {
+        DeleteObjectTaggingBuilder,
+        fn execute => inner_execute -> api::ApiResult;
+    }
 }
 
 /// # 标签`Tagging`
 #[allow(non_snake_case)]
-impl<'a> oss::Client<'a> {
+impl<'a, M> oss::Client<M> {
     /// 调用PutObjectTagging接口设置或更新对象`Object`的标签`Tagging`信息。
     /// 对象标签使用一组键值对`Key-Value`标记对象。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/putobjecttagging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_tag_put.rs)
-    pub fn PutObjectTagging(&self, object: &'a str) -> PutObjectTaggingBuilder {
+    pub fn PutObjectTagging(&'a self, object: &'a str) -> PutObjectTaggingBuilder<'a, M> {
         PutObjectTaggingBuilder::new(self, object)
     }
 
@@ -180,7 +225,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/getobjecttagging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_tag_get.rs)
-    pub fn GetObjectTagging(&self, object: &'a str) -> GetObjectTaggingbuilder {
+    pub fn GetObjectTagging(&'a self, object: &'a str) -> GetObjectTaggingbuilder<'a, M> {
         GetObjectTaggingbuilder::new(&self, object)
     }
 
@@ -188,7 +233,7 @@ impl<'a> oss::Client<'a> {
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/deleteobjecttagging)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_object_tag_del.rs)
-    pub fn DeleteObjectTagging(&self, object: &'a str) -> DeleteObjectTaggingBuilder {
+    pub fn DeleteObjectTagging(&'a self, object: &'a str) -> DeleteObjectTaggingBuilder<'a, M> {
         DeleteObjectTaggingBuilder::new(self, object)
     }
 }
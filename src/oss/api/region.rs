@@ -9,47 +9,69 @@ pub mod builders {
         entities::region::RegionInfoList,
     };
 
-    pub struct DescribeRegionsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct DescribeRegionsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         region: Option<&'a str>,
     }
 
-    impl<'a> DescribeRegionsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> DescribeRegionsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 region: None,
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_region(&mut self, value: &'a str) -> &Self {
             self.region = Some(value);
             self
         }
 
-        pub async fn execute(&self) -> api::ApiResult<RegionInfoList> {
-            let mut url = format!("{}/?regions", self.client.root_url());
+        async fn inner_execute(&self) -> api::ApiResult<RegionInfoList> {
+            let mut url = format!("{}/?regions", self.client.root_url()?);
 
             if let Some(region) = self.region {
                 url = format!("{}={}", url, region);
             }
 
-            let resp = self.client.request.task().with_url(&url).execute().await?;
+            let resp = self
+                .client
+                .request
+                .task()
+                .with_operation("DescribeRegions")
+                .with_region(oss::DEFAULT_REGION)
+                .with_retry_policy(self.retry_policy)
+                .with_url(&url)
+                .execute()
+                .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        DescribeRegionsBuilder,
+        fn execute => inner_execute -> api::ApiResult<RegionInfoList>;
+    }
 }
 
 #[allow(non_snake_case)]
 /// 关于Service操作
-impl<'a> Client<'a> {
+impl<'a, M> Client<M> {
     /// 调用DescribeRegions接口查询所有支持地域或者指定地域对应的Endpoint信息,
     /// 包括外网Endpoint、内网Endpoint和传输加速Endpoint。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/describeregions)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_describe_regions.rs)
-    pub fn DescribeRegions(&self) -> DescribeRegionsBuilder {
+    pub fn DescribeRegions(&'a self) -> DescribeRegionsBuilder<'a, M> {
         DescribeRegionsBuilder::new(self)
     }
 }
@@ -33,21 +33,29 @@ pub mod builders {
     }
 
     #[derive(Debug)]
-    pub struct ListBucketsBuilder<'a> {
-        client: &'a oss::Client<'a>,
+    pub struct ListBucketsBuilder<'a, M = oss::Async> {
+        client: &'a oss::Client<M>,
+        retry_policy: Option<oss::RetryPolicy>,
         resource_group_id: Option<&'a str>,
         query: ListBucketsQuery<'a>,
     }
 
-    impl<'a> ListBucketsBuilder<'a> {
-        pub(crate) fn new(client: &'a oss::Client) -> Self {
+    impl<'a, M> ListBucketsBuilder<'a, M> {
+        pub(crate) fn new(client: &'a oss::Client<M>) -> Self {
             Self {
                 client,
                 resource_group_id: None,
                 query: ListBucketsQuery::default(),
+                retry_policy: None,
             }
         }
 
+        /// 覆盖客户端的重试策略
+        pub fn with_retry_policy(mut self, value: oss::RetryPolicy) -> Self {
+            self.retry_policy = Some(value);
+            self
+        }
+
         pub fn with_prefix(mut self, value: &'a str) -> Self {
             self.query.prefix = Some(value);
             self
@@ -80,11 +88,11 @@ pub mod builders {
             headers
         }
 
-        pub async fn execute(&self) -> api::ApiResult<ListAllMyBucketsResult> {
+        async fn inner_execute(&self) -> api::ApiResult<ListAllMyBucketsResult> {
             let query = self.query();
             let headers = self.headers();
 
-            let mut url = self.client.root_url();
+            let mut url = self.client.root_url()?;
 
             if !query.is_empty() {
                 url = format!("{}/?{}", url, query)
@@ -94,26 +102,68 @@ pub mod builders {
                 .client
                 .request
                 .task()
+                .with_operation("ListBuckets")
+                .with_region(oss::DEFAULT_REGION)
+                .with_retry_policy(self.retry_policy)
                 .with_method(http::Method::GET)
                 .with_headers(headers)
                 .with_url(&url)
                 .execute_timeout(self.client.timeout())
                 .await?;
 
-            Ok(ApiResponseFrom(resp).to_type().await)
+            ApiResponseFrom(resp).into_type().await
         }
     }
+
+    oss::builder_execute! {
+        ListBucketsBuilder,
+        fn execute => inner_execute -> api::ApiResult<ListAllMyBucketsResult>;
+    }
 }
 
 #[allow(non_snake_case)]
 /// 关于Region操作
-impl<'a> Client<'a> {
+impl<'a, M> Client<M> {
     /// 调用ListBuckets（GetService）接口列举请求者拥有的所有存储空间（Bucket）。
     /// 您还可以通过设置prefix、marker或者max-keys参数列举满足指定条件的存储空间。
     ///
     /// - [official docs](https://help.aliyun.com/zh/oss/developer-reference/listbuckets)
     /// - [xtoss example](https://github.com/isme-sun/xt_oss/blob/main/examples/api_service_list_buckets.rs)
-    pub fn ListBuckets(&self) -> ListBucketsBuilder {
+    pub fn ListBuckets(&'a self) -> ListBucketsBuilder<'a, M> {
         ListBucketsBuilder::new(self)
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use crate::oss::{self, http::header::AUTHORIZATION, transport::tests::MemoryTransport};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn service_v4_region() {
+        let transport = Arc::new(MemoryTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_region("oss-cn-shanghai")
+            .with_signature_version(oss::SignatureVersion::V4)
+            .with_transport(transport.clone())
+            .client();
+        // 响应内容不是XML, 只检查请求
+        let _ = client.ListBuckets().execute().await;
+        let _ = client.DescribeRegions().execute().await;
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        for request in requests.iter() {
+            assert_eq!(request.url.host_str(), Some("oss-cn-hangzhou.aliyuncs.com"));
+            let authorization = request
+                .headers
+                .get(AUTHORIZATION)
+                .unwrap()
+                .to_str()
+                .unwrap();
+            assert!(authorization.contains("/cn-hangzhou/oss/aliyun_v4_request"));
+        }
+    }
+}
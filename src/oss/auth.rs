@@ -2,6 +2,17 @@ use super::http;
 use super::http::header::CONTENT_TYPE;
 use super::DEFAULT_CONTENT_TYPE;
 use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use crypto::{digest::Digest, hmac::Hmac, mac::Mac, sha2::Sha256};
+use std::fmt;
+
+pub(super) const V4_ALGORITHM: &str = "OSS4-HMAC-SHA256";
+pub(super) const V4_DATE: &str = "x-oss-date";
+pub(super) const V4_CONTENT_SHA256: &str = "x-oss-content-sha256";
+pub(super) const V4_UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+pub(super) const V4_DATE_FMT: &str = "%Y%m%d";
+pub(super) const V4_DATETIME_FMT: &str = "%Y%m%dT%H%M%SZ";
+
 #[derive(Debug, Clone)]
 pub(super) struct SingerV1<'a> {
     pub(super) access_key_id: &'a str,
@@ -20,7 +31,6 @@ impl<'a> SingerV1<'a> {
     }
 
     fn headers_str(&self) -> String {
-        // dbg!(self.headers);
         let mut oss_key_name: Vec<&str> = self
             .headers
             .keys()
@@ -34,7 +44,6 @@ impl<'a> SingerV1<'a> {
             })
             .collect();
         oss_key_name.sort();
-        // dbg!(&oss_key_name);
         let mut value: Vec<String> = Vec::new();
         for key_name in oss_key_name {
             if let Some(key_value) = self.headers.get(key_name).and_then(|v| v.to_str().ok()) {
@@ -46,10 +55,19 @@ impl<'a> SingerV1<'a> {
     }
 
     fn signature(&self) -> String {
+        self.sign(DEFAULT_CONTENT_TYPE)
+    }
+
+    /// url签名, `date`为过期时间的unix时间戳, 浏览器请求时不带默认的`Content-Type`
+    pub(super) fn presign_signature(&self) -> String {
+        self.sign("")
+    }
+
+    fn sign(&self, default_content_type: &str) -> String {
         let header_str = self.headers_str();
         let content_type = match self.headers.get(CONTENT_TYPE) {
             Some(content_type) => content_type.to_str().unwrap(),
-            None => DEFAULT_CONTENT_TYPE,
+            None => default_content_type,
         };
         let content_md5 = match self.headers.get("content-md5") {
             Some(content_type) => content_type.to_str().unwrap().to_string(),
@@ -70,7 +88,6 @@ impl<'a> SingerV1<'a> {
             Header = header_str,
             Resource = resource
         );
-        // dbg!(&value);
         let key = self.access_key_secret.as_bytes();
         let message = value.as_bytes();
         let value = hmac_sha1::hmac_sha1(key, message);
@@ -78,3 +95,281 @@ impl<'a> SingerV1<'a> {
         encoded
     }
 }
+
+/// 签名版本
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureVersion {
+    /// `OSS AccessKeyId:Signature`, HMAC-SHA1
+    #[default]
+    V1,
+    /// `OSS4-HMAC-SHA256`, 区域(region)范围的派生密钥
+    V4,
+}
+
+impl fmt::Display for SignatureVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::V1 => "v1",
+                Self::V4 => "v4",
+            }
+        )
+    }
+}
+
+/// V4签名算法
+///
+/// [doc](https://help.aliyun.com/zh/oss/developer-reference/recommend-to-use-signature-version-4)
+#[derive(Debug, Clone)]
+pub(super) struct SingerV4<'a> {
+    pub(super) access_key_id: &'a str,
+    pub(super) access_key_secret: &'a str,
+    pub(super) headers: &'a http::HeaderMap,
+    pub(super) method: &'a http::Method,
+    pub(super) datetime: &'a DateTime<Utc>,
+    /// 形如`cn-hangzhou`, 允许携带`oss-`前缀
+    pub(super) region: &'a str,
+    pub(super) resourse: Option<&'a str>,
+    /// 请求url中已编码的查询字符串
+    pub(super) query: Option<&'a str>,
+    /// 需要额外参与签名的头部名称(小写)
+    pub(super) additional_headers: &'a [String],
+}
+
+impl<'a> SingerV4<'a> {
+    pub(super) fn complute(&self) -> String {
+        let additional_headers = self.additional_headers();
+        format!(
+            "{} Credential={}/{},{}Signature={}",
+            V4_ALGORITHM,
+            self.access_key_id,
+            self.scope(),
+            if additional_headers.is_empty() {
+                String::new()
+            } else {
+                format!("AdditionalHeaders={},", additional_headers.join(";"))
+            },
+            self.signature()
+        )
+    }
+
+    pub(super) fn region(&self) -> &str {
+        self.region.strip_prefix("oss-").unwrap_or(self.region)
+    }
+
+    pub(super) fn scope(&self) -> String {
+        format!(
+            "{}/{}/oss/aliyun_v4_request",
+            self.datetime.format(V4_DATE_FMT),
+            self.region()
+        )
+    }
+
+    pub(super) fn additional_headers(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .additional_headers
+            .iter()
+            .map(|name| name.to_lowercase())
+            .filter(|name| !Self::is_default_signed(name) && self.headers.contains_key(name))
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    fn is_default_signed(name: &str) -> bool {
+        name == "content-type" || name == "content-md5" || name.starts_with("x-oss-")
+    }
+
+    fn canonical_uri(&self) -> String {
+        let path = self
+            .resourse
+            .unwrap_or("/")
+            .split('?')
+            .next()
+            .unwrap_or("/");
+        let path = urlencoding::decode(path)
+            .map(|path| path.into_owned())
+            .unwrap_or(path.to_string());
+        path.split('/')
+            .map(|segment| urlencoding::encode(segment).into_owned())
+            .collect::<Vec<String>>()
+            .join("/")
+    }
+
+    fn canonical_query(&self) -> String {
+        let mut pairs: Vec<(String, String)> =
+            url::form_urlencoded::parse(self.query.unwrap_or_default().as_bytes())
+                .map(|(key, value)| {
+                    (
+                        urlencoding::encode(&key).into_owned(),
+                        urlencoding::encode(&value).into_owned(),
+                    )
+                })
+                .collect();
+        pairs.sort();
+        pairs
+            .into_iter()
+            .map(|(key, value)| match value.is_empty() {
+                true => key,
+                false => format!("{}={}", key, value),
+            })
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+
+    fn canonical_headers(&self, default_content_type: Option<&str>) -> String {
+        let additional_headers = self.additional_headers();
+        let mut headers: Vec<(String, String)> = self
+            .headers
+            .iter()
+            .filter_map(|(key, value)| {
+                let key = key.as_str();
+                if Self::is_default_signed(key) || additional_headers.iter().any(|k| k == key) {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (key.to_string(), value.trim().to_string()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if let (false, Some(content_type)) = (
+            self.headers.contains_key(CONTENT_TYPE),
+            default_content_type,
+        ) {
+            headers.push((CONTENT_TYPE.as_str().to_string(), content_type.to_string()));
+        }
+        headers.sort();
+        headers
+            .into_iter()
+            .map(|(key, value)| format!("{}:{}\n", key, value))
+            .collect()
+    }
+
+    fn canonical_request(&self, default_content_type: Option<&str>) -> String {
+        let payload = self
+            .headers
+            .get(V4_CONTENT_SHA256)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(V4_UNSIGNED_PAYLOAD);
+        format!(
+            "{Method}\n{URI}\n{Query}\n{Headers}\n{AdditionalHeaders}\n{Payload}",
+            Method = self.method,
+            URI = self.canonical_uri(),
+            Query = self.canonical_query(),
+            Headers = self.canonical_headers(default_content_type),
+            AdditionalHeaders = self.additional_headers().join(";"),
+            Payload = payload
+        )
+    }
+
+    fn string_to_sign(&self, default_content_type: Option<&str>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.input_str(&self.canonical_request(default_content_type));
+        format!(
+            "{}\n{}\n{}\n{}",
+            V4_ALGORITHM,
+            self.datetime.format(V4_DATETIME_FMT),
+            self.scope(),
+            hasher.result_str()
+        )
+    }
+
+    fn signing_key(&self) -> Vec<u8> {
+        let key = format!("aliyun_v4{}", self.access_key_secret);
+        let date = self.datetime.format(V4_DATE_FMT).to_string();
+        [date.as_str(), self.region(), "oss", "aliyun_v4_request"]
+            .iter()
+            .fold(key.into_bytes(), |key, message| {
+                hmac_sha256(&key, message.as_bytes())
+            })
+    }
+
+    fn signature(&self) -> String {
+        self.sign(Some(DEFAULT_CONTENT_TYPE))
+    }
+
+    /// PostObject签名, `policy`为base64编码后的Policy
+    pub(super) fn post_signature(&self, policy: &str) -> String {
+        hex::encode(hmac_sha256(&self.signing_key(), policy.as_bytes()))
+    }
+
+    /// url签名, 签名参数须已包含在`query`中, 浏览器请求时不带默认的`Content-Type`
+    pub(super) fn presign_signature(&self) -> String {
+        self.sign(None)
+    }
+
+    fn sign(&self, default_content_type: Option<&str>) -> String {
+        hex::encode(hmac_sha256(
+            &self.signing_key(),
+            self.string_to_sign(default_content_type).as_bytes(),
+        ))
+    }
+}
+
+/// V1 PostObject签名, `policy`为base64编码后的Policy
+pub(super) fn post_signature_v1(access_key_secret: &str, policy: &str) -> String {
+    let value = hmac_sha1::hmac_sha1(access_key_secret.as_bytes(), policy.as_bytes());
+    general_purpose::STANDARD.encode(value.as_slice())
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::new(Sha256::new(), key);
+    mac.input(message);
+    mac.result().code().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn singer_v1_presign() {
+        let headers = http::HeaderMap::new();
+        let singer = SingerV1 {
+            access_key_id: "44CF9590006BF252F707",
+            access_key_secret: "OtxrzxIsfpFjA7SwPzILwy8Bw21TLhquhboDYROV",
+            sts_token: None,
+            headers: &headers,
+            method: &http::Method::GET,
+            date: &"1141889120".to_string(),
+            resourse: Some("/oss-example/oss-api.pdf"),
+        };
+        assert_eq!(singer.presign_signature(), "EwaNTn1erJGkimiJ9WmXgwnANLc=");
+    }
+
+    #[test]
+    fn singer_v4_authorization() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-oss-head1", "value".parse().unwrap());
+        headers.insert("abc", "value".parse().unwrap());
+        headers.insert("zabc", "value".parse().unwrap());
+        headers.insert("xyz", "value".parse().unwrap());
+        headers.insert(CONTENT_TYPE, "text/plain".parse().unwrap());
+        headers.insert(V4_CONTENT_SHA256, V4_UNSIGNED_PAYLOAD.parse().unwrap());
+        headers.insert(V4_DATE, "20231216T162057Z".parse().unwrap());
+        let datetime = Utc.timestamp_opt(1702743657, 0).unwrap();
+        let singer = SingerV4 {
+            access_key_id: "ak",
+            access_key_secret: "sk",
+            headers: &headers,
+            method: &http::Method::PUT,
+            datetime: &datetime,
+            region: "oss-cn-hangzhou",
+            resourse: Some("/bucket/1234+-/123/1.txt"),
+            query: Some(
+                "param1=value1&%2Bparam1=value3&%7Cparam1=value4&%2Bparam2&%7Cparam2&param2",
+            ),
+            additional_headers: &[],
+        };
+        assert_eq!(
+            singer.complute(),
+            "OSS4-HMAC-SHA256 Credential=ak/20231216/cn-hangzhou/oss/aliyun_v4_request,Signature=e21d18daa82167720f9b1047ae7e7f1ce7cb77a31e8203a7d5f4624fa0284afe"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::{future::Future, sync::OnceLock};
+
+use tokio::runtime::Runtime;
+
+use crate::oss;
+
+/// 同步执行模式, builder的`execute`阻塞当前线程直到请求完成
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Blocking;
+
+/// 同步客户端
+///
+/// 与异步客户端共用builder、签名与重试逻辑, 请求在内部的tokio运行时中执行。
+/// 不能在异步运行时中调用`execute`, 否则会panic
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// let client = oss::Options::new().with_bucket("xtoss-ex").blocking_client();
+/// match client.GetObject("example/ex1.txt").execute() {
+///     Ok(data) => println!("{:?}", data.content()),
+///     Err(error) => println!("{}", error),
+/// }
+/// ```
+pub type Client = oss::Client<Blocking>;
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("xt-oss-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to build blocking runtime")
+    })
+}
+
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::oss::{
+        self,
+        http::{HeaderMap, StatusCode},
+        transport::{HttpRequest, Response, Transport, TransportError},
+    };
+    use futures::future::BoxFuture;
+
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    impl Transport for EchoTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let body = request.url.path().to_string();
+                Ok(Response::from_bytes(
+                    request.url,
+                    StatusCode::OK,
+                    HeaderMap::new(),
+                    body,
+                ))
+            })
+        }
+    }
+
+    #[test]
+    fn blocking_execute() {
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(EchoTransport)
+            .blocking_client();
+        let data = client.GetObject("example/ex1.txt").execute().unwrap();
+        assert_eq!(data.content(), "/example/ex1.txt");
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let client = client.clone();
+                std::thread::spawn(move || {
+                    let object = format!("{}.txt", i);
+                    client.GetObject(&object).execute().unwrap().content()
+                })
+            })
+            .collect();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap(), format!("/{}.txt", i));
+        }
+    }
+}
@@ -0,0 +1,467 @@
+//! 上传数据来源
+//!
+//! 除内存中的`Bytes`外, 支持本地文件、已知长度的`AsyncRead`与`Stream<Bytes>`,
+//! 以数据流方式按块发送, 内存占用与数据大小无关。
+//! 内存数据与文件在每次重试时重新读取; `AsyncRead`与`Stream`只能读取一次, 请求失败时不重试,
+//! 需要重试时使用`from_stream_fn`在每次发送时重新生成数据流。
+//! 以数据流发送时不限制请求的总时长, 超时时间为连续未发送或收到数据的时长
+use std::{
+    error::Error as StdError,
+    fmt, io,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use base64::{engine::general_purpose, Engine as _};
+use crypto::{digest::Digest, md5::Md5};
+use futures::{Stream, StreamExt, TryStreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt};
+
+use super::{
+    transport::{Body, BodyStream, TransportError, TransportErrorKind},
+    Bytes, BytesMut, Error,
+};
+
+/// 读取文件或`AsyncRead`时每块的大小
+const CHUNK_SIZE: usize = 64 * 1024;
+
+type StreamFn = dyn Fn() -> BodyStream + Send + Sync;
+
+#[derive(Clone)]
+enum Source {
+    Bytes(Bytes),
+    /// 文件路径与起始位置, 每次发送时重新打开
+    Path(PathBuf, u64),
+    /// 已打开的文件与起始位置, 每次发送时复制句柄并重新定位
+    File(Arc<tokio::fs::File>, u64),
+    /// 每次发送时重新生成数据流
+    StreamFn(Arc<StreamFn>),
+    /// 只能读取一次的数据流
+    Once(Arc<Mutex<Option<BodyStream>>>),
+}
+
+/// 上传数据, 用于`PutObject`、`AppendObject`、`UploadPart`的`with_body`
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # async fn run(client: oss::Client) -> Result<(), Box<dyn std::error::Error>> {
+/// // 文件按块读取, 失败重试时重新读取
+/// let body = oss::UploadBody::from_path("large.bin").await?;
+/// client.PutObject("large.bin").with_body(body).execute().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UploadBody {
+    source: Source,
+    len: u64,
+}
+
+impl UploadBody {
+    /// 本地文件
+    pub async fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let len = tokio::fs::metadata(path).await?.len();
+        Ok(Self::from_path_range(path, 0, len))
+    }
+
+    /// 本地文件中从`offset`开始的`len`字节, 例如分片上传中的一个分片
+    pub fn from_path_range(path: impl AsRef<Path>, offset: u64, len: u64) -> Self {
+        Self {
+            source: Source::Path(path.as_ref().to_path_buf(), offset),
+            len,
+        }
+    }
+
+    /// 已打开的文件, 从当前位置读取到文件末尾
+    ///
+    /// 重试时复制文件句柄并重新定位, 多个句柄共享读取位置, 不要同时用于多个请求
+    pub async fn from_file(mut file: tokio::fs::File) -> io::Result<Self> {
+        let offset = file.stream_position().await?;
+        let len = file.metadata().await?.len().saturating_sub(offset);
+        Ok(Self {
+            source: Source::File(Arc::new(file), offset),
+            len,
+        })
+    }
+
+    /// 长度为`len`的`AsyncRead`, 只能读取一次
+    pub fn from_reader<R>(reader: R, len: u64) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        Self::once(reader_stream(Box::pin(reader)), len)
+    }
+
+    /// 总长度为`len`的数据流, 只能读取一次
+    pub fn from_stream<S, E>(stream: S, len: u64) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        let stream = stream.map_err(|e| TransportError::new(TransportErrorKind::Other, e));
+        Self::once(stream.boxed(), len)
+    }
+
+    /// 每次发送(包括重试)时调用`f`生成总长度为`len`的数据流
+    pub fn from_stream_fn<F, S, E>(len: u64, f: F) -> Self
+    where
+        F: Fn() -> S + Send + Sync + 'static,
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        let f = move || {
+            f().map_err(|e| TransportError::new(TransportErrorKind::Other, e))
+                .boxed()
+        };
+        Self {
+            source: Source::StreamFn(Arc::new(f)),
+            len,
+        }
+    }
+
+    fn once(stream: BodyStream, len: u64) -> Self {
+        Self {
+            source: Source::Once(Arc::new(Mutex::new(Some(stream)))),
+            len,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 内存中的数据, 其他来源返回`None`
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match &self.source {
+            Source::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// 是否可以重新读取, 不可重新读取时请求失败后不重试
+    pub fn is_rewindable(&self) -> bool {
+        !matches!(self.source, Source::Once(_))
+    }
+
+    /// 按块计算`Content-MD5`(Base64编码), 文件等数据流需在发送前额外读取一次,
+    /// 只能读取一次的数据流无法计算
+    pub(crate) async fn content_md5(&self) -> Result<String, Error> {
+        if !self.is_rewindable() {
+            return Err(Error::Config(
+                "Content-MD5 requires a rewindable upload body".into(),
+            ));
+        }
+        let mut hasher = Md5::new();
+        match self.open().await? {
+            Body::Bytes(bytes) => hasher.input(&bytes),
+            Body::Stream(mut stream) => {
+                while let Some(chunk) = stream.try_next().await? {
+                    hasher.input(&chunk);
+                }
+            }
+        }
+        let mut digest = [0; 16];
+        hasher.result(&mut digest);
+        Ok(general_purpose::STANDARD.encode(digest))
+    }
+
+    /// 生成一次请求的请求体
+    pub(crate) async fn open(&self) -> Result<Body, Error> {
+        let stream = match &self.source {
+            Source::Bytes(bytes) => return Ok(Body::Bytes(bytes.clone())),
+            Source::Path(path, offset) => {
+                let mut file = tokio::fs::File::open(path).await?;
+                file.seek(SeekFrom::Start(*offset)).await?;
+                reader_stream(file.take(self.len))
+            }
+            Source::File(file, offset) => {
+                let mut file = file.try_clone().await?;
+                file.seek(SeekFrom::Start(*offset)).await?;
+                reader_stream(file.take(self.len))
+            }
+            Source::StreamFn(f) => f(),
+            Source::Once(stream) => stream
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take()
+                .ok_or_else(|| Error::Config("upload body has already been consumed".into()))?,
+        };
+        Ok(Body::Stream(sized(stream, self.len)))
+    }
+}
+
+impl Default for UploadBody {
+    fn default() -> Self {
+        Bytes::new().into()
+    }
+}
+
+impl From<Bytes> for UploadBody {
+    fn from(value: Bytes) -> Self {
+        Self {
+            len: value.len() as u64,
+            source: Source::Bytes(value),
+        }
+    }
+}
+
+impl From<Vec<u8>> for UploadBody {
+    fn from(value: Vec<u8>) -> Self {
+        Bytes::from(value).into()
+    }
+}
+
+impl fmt::Debug for UploadBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match &self.source {
+            Source::Bytes(_) => "Bytes",
+            Source::Path(..) => "Path",
+            Source::File(..) => "File",
+            Source::StreamFn(_) => "StreamFn",
+            Source::Once(_) => "Once",
+        };
+        f.debug_struct("UploadBody")
+            .field("source", &source)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// 按块读取`reader`
+fn reader_stream<R>(reader: R) -> BodyStream
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    futures::stream::try_unfold(reader, |mut reader| async move {
+        let mut buf = BytesMut::with_capacity(CHUNK_SIZE);
+        match reader.read_buf(&mut buf).await? {
+            0 => Ok(None),
+            _ => Ok(Some((buf.freeze(), reader))),
+        }
+    })
+    .map_err(|e: io::Error| TransportError::new(TransportErrorKind::Other, e))
+    .boxed()
+}
+
+/// 检查数据流的总长度与`Content-Length`一致, 避免服务端等待不存在的数据或截断多余的数据
+fn sized(stream: BodyStream, len: u64) -> BodyStream {
+    let mismatch = move |sent: u64| {
+        let message = format!(
+            "upload body length mismatch: expected {} bytes, got {}",
+            len, sent
+        );
+        TransportError::new(TransportErrorKind::Other, message)
+    };
+    futures::stream::unfold(Some((stream, 0u64)), move |state| async move {
+        let (mut stream, sent) = state?;
+        match stream.next().await {
+            Some(Ok(chunk)) => {
+                let sent = sent + chunk.len() as u64;
+                match sent > len {
+                    true => Some((Err(mismatch(sent)), None)),
+                    false => Some((Ok(chunk), Some((stream, sent)))),
+                }
+            }
+            Some(Err(e)) => Some((Err(e), None)),
+            None if sent != len => Some((Err(mismatch(sent)), None)),
+            None => None,
+        }
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        http::{self, HeaderMap, StatusCode},
+        transport::{HttpRequest, Response, Transport},
+    };
+    use futures::future::BoxFuture;
+
+    async fn read(body: &UploadBody) -> Result<Bytes, TransportError> {
+        let chunks: Vec<Bytes> = body
+            .open()
+            .await
+            .unwrap()
+            .into_stream()
+            .try_collect()
+            .await?;
+        Ok(chunks.concat().into())
+    }
+
+    #[tokio::test]
+    async fn upload_body_sources() {
+        let data: Vec<u8> = (0..200 * 1024).map(|i| (i % 251) as u8).collect();
+        let path = std::env::temp_dir().join(format!("xt-oss-body-{}.bin", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+
+        let body = UploadBody::from_path(&path).await.unwrap();
+        assert_eq!(body.len(), data.len() as u64);
+        assert!(body.is_rewindable());
+        for _ in 0..2 {
+            assert_eq!(read(&body).await.unwrap(), data);
+        }
+
+        let body = UploadBody::from_path_range(&path, 1000, 70 * 1024);
+        assert_eq!(read(&body).await.unwrap(), data[1000..1000 + 70 * 1024]);
+
+        let mut file = tokio::fs::File::open(&path).await.unwrap();
+        file.seek(SeekFrom::Start(100)).await.unwrap();
+        let body = UploadBody::from_file(file).await.unwrap();
+        assert_eq!(body.len(), data.len() as u64 - 100);
+        for _ in 0..2 {
+            assert_eq!(read(&body).await.unwrap(), data[100..]);
+        }
+
+        let file = tokio::fs::File::open(&path).await.unwrap();
+        let body = UploadBody::from_reader(file, data.len() as u64);
+        assert!(!body.is_rewindable());
+        assert_eq!(read(&body).await.unwrap(), data);
+        assert!(matches!(body.open().await, Err(Error::Config(_))));
+        std::fs::remove_file(&path).unwrap();
+
+        let chunks = || {
+            futures::stream::iter([
+                Ok::<_, io::Error>(Bytes::from("hello ")),
+                Ok(Bytes::from("world")),
+            ])
+        };
+        let body = UploadBody::from_stream_fn(11, chunks);
+        for _ in 0..2 {
+            assert_eq!(read(&body).await.unwrap(), "hello world");
+        }
+
+        // 长度与实际数据不符
+        let body = UploadBody::from_stream(chunks(), 5);
+        assert!(read(&body).await.is_err());
+        let body = UploadBody::from_stream(chunks(), 20);
+        assert!(read(&body).await.is_err());
+    }
+
+    /// 读取请求体, 第一次请求返回503
+    #[derive(Debug, Default)]
+    struct FlakyTransport {
+        bodies: Mutex<Vec<Bytes>>,
+    }
+
+    impl Transport for FlakyTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let length = request.headers[http::header::CONTENT_LENGTH].clone();
+                let chunks: Vec<Bytes> = request.body.into_stream().try_collect().await?;
+                let body = Bytes::from(chunks.concat());
+                assert_eq!(length, body.len().to_string().as_str());
+                let mut bodies = self.bodies.lock().unwrap();
+                bodies.push(body);
+                let status = match bodies.len() {
+                    1 => StatusCode::SERVICE_UNAVAILABLE,
+                    _ => StatusCode::OK,
+                };
+                Ok(Response::from_bytes(
+                    request.url,
+                    status,
+                    HeaderMap::new(),
+                    "",
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn upload_body_retry() {
+        let data: Vec<u8> = (0..100 * 1024).map(|i| (i % 251) as u8).collect();
+        let path = std::env::temp_dir().join(format!("xt-oss-retry-{}.bin", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let retry_policy = oss::RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_base_delay(std::time::Duration::from_millis(1))
+            .with_retry_server_error(true);
+
+        // 文件在重试时重新读取
+        let transport = Arc::new(FlakyTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .with_retry_policy(retry_policy)
+            .client();
+        let body = UploadBody::from_path(&path).await.unwrap();
+        client
+            .PutObject("ex1.bin")
+            .with_body(body)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(*transport.bodies.lock().unwrap(), [&data[..], &data[..]]);
+
+        // 只能读取一次的数据流不重试
+        let file = tokio::fs::File::open(&path).await.unwrap();
+        let body = UploadBody::from_reader(file, data.len() as u64);
+        let client = client
+            .options()
+            .clone()
+            .with_transport(FlakyTransport::default())
+            .client();
+        let error = client
+            .PutObject("ex1.bin")
+            .with_body(body)
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Service(_)), "{:?}", error);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn upload_body_timeout() {
+        use axum::{routing::put, Router};
+        let app = Router::new().route("/:object", put(|_: axum::body::Bytes| async { "" }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_cname(true)
+            .with_endpoint(addr.to_string())
+            .with_retry_policy(oss::RetryPolicy::none())
+            .with_timeout(1)
+            .client();
+        // 每300毫秒发送一块, 共1.5秒
+        let slow = futures::stream::iter(0..5).then(|_| async {
+            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+            Ok::<_, io::Error>(Bytes::from(vec![0u8; 1024]))
+        });
+        client
+            .PutObject("slow.bin")
+            .with_body(UploadBody::from_stream(slow, 5 * 1024))
+            .execute()
+            .await
+            .unwrap();
+
+        let stall = futures::stream::once(async {
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+            Ok::<_, io::Error>(Bytes::from(vec![0u8; 1024]))
+        });
+        let error = client
+            .PutObject("stall.bin")
+            .with_body(UploadBody::from_stream(stall, 1024))
+            .execute()
+            .await
+            .unwrap_err();
+        match error {
+            oss::Error::Transport(e) => assert!(e.is_timeout()),
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! 本地时钟与OSS服务端时钟的偏差
+//!
+//! 每次收到响应时根据`Date`响应头计算偏差, 签名(包括签名url的过期时间)使用校正后的时间,
+//! 避免本地时钟漂移导致`RequestTimeTooSkewed`
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::{
+    http::{header::DATE, HeaderMap, StatusCode},
+    transport::TransportError,
+    Response,
+};
+
+/// 偏差小于该值(毫秒)时视为时钟准确, `Date`精确到秒, 忽略取整与网络延迟带来的误差
+const SKEW_THRESHOLD_MILLIS: i64 = 2000;
+
+/// `RequestTimeTooSkewed`对应的`EC`错误码
+const SKEW_EC: &str = "0002-00000504";
+
+#[derive(Debug, Default)]
+pub(crate) struct Clock {
+    /// 服务端时间减去本地时间, 单位毫秒
+    offset: AtomicI64,
+}
+
+impl Clock {
+    /// 校正后的当前时间
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset()
+    }
+
+    pub(crate) fn offset(&self) -> Duration {
+        Duration::milliseconds(self.offset.load(Ordering::Relaxed))
+    }
+
+    /// 根据响应头`Date`更新偏差, 没有`Date`时不更新
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let Some(server) = headers
+            .get(DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+        else {
+            return;
+        };
+        let skew = (server.with_timezone(&Utc) - Utc::now()).num_milliseconds();
+        let skew = match skew.abs() < SKEW_THRESHOLD_MILLIS {
+            true => 0,
+            false => skew,
+        };
+        self.offset.store(skew, Ordering::Relaxed);
+    }
+}
+
+/// 响应是否为`RequestTimeTooSkewed`错误
+///
+/// 仅检查`403`响应; 响应头中没有错误码时读取错误消息, 并以缓存的响应体重建响应
+pub(crate) async fn check_skewed(resp: Response) -> Result<(Response, bool), TransportError> {
+    if resp.status() != StatusCode::FORBIDDEN {
+        return Ok((resp, false));
+    }
+    if let Some(ec) = resp.headers().get("x-oss-ec") {
+        let skewed = ec == SKEW_EC;
+        return Ok((resp, skewed));
+    }
+    let url = resp.url().clone();
+    let headers = resp.headers().clone();
+    let body = resp.bytes().await?;
+    let skewed = String::from_utf8_lossy(&body).contains("<Code>RequestTimeTooSkewed</Code>");
+    Ok((
+        Response::from_bytes(url, StatusCode::FORBIDDEN, headers, body),
+        skewed,
+    ))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        http::HeaderValue,
+        interceptor::{Context, Interceptor},
+        transport::{HttpRequest, Transport},
+    };
+    use futures::future::BoxFuture;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn clock_update() {
+        let clock = Clock::default();
+        let date = |time: DateTime<Utc>| {
+            let mut headers = HeaderMap::new();
+            let value = time.format(oss::GMT_DATE_FMT).to_string();
+            headers.insert(DATE, HeaderValue::from_str(&value).unwrap());
+            headers
+        };
+        clock.update(&date(Utc::now() + Duration::hours(1)));
+        assert!((clock.offset() - Duration::hours(1)).num_seconds().abs() <= 2);
+        assert!(
+            (clock.now() - Utc::now() - Duration::hours(1))
+                .num_seconds()
+                .abs()
+                <= 2
+        );
+
+        clock.update(&date(Utc::now()));
+        assert_eq!(clock.offset(), Duration::zero());
+        clock.update(&HeaderMap::new());
+        assert_eq!(clock.offset(), Duration::zero());
+    }
+
+    /// 服务端时钟比本地快1小时, 请求时间相差超过15分钟时返回`RequestTimeTooSkewed`
+    #[derive(Debug, Default)]
+    struct SkewedServer {
+        dates: Mutex<Vec<DateTime<Utc>>>,
+    }
+
+    impl Transport for SkewedServer {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let server = Utc::now() + Duration::hours(1);
+                let date = request.headers[DATE].to_str().unwrap();
+                let date = DateTime::parse_from_rfc2822(date)
+                    .unwrap()
+                    .with_timezone(&Utc);
+                self.dates.lock().unwrap().push(date);
+                let mut headers = HeaderMap::new();
+                let value = server.format(oss::GMT_DATE_FMT).to_string();
+                headers.insert(DATE, HeaderValue::from_str(&value).unwrap());
+                let (status, body) = match (server - date).num_minutes().abs() > 15 {
+                    true => (
+                        StatusCode::FORBIDDEN,
+                        "<Error><Code>RequestTimeTooSkewed</Code></Error>",
+                    ),
+                    false => (StatusCode::OK, "hello world"),
+                };
+                Ok(Response::from_bytes(request.url, status, headers, body))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn clock_skew_retry() {
+        let server = Arc::new(SkewedServer::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(server.clone())
+            .with_retry_policy(oss::RetryPolicy::none())
+            .client();
+        let data = client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(data.content(), "hello world");
+        assert_eq!(server.dates.lock().unwrap().len(), 2);
+        assert!(
+            (client.clock_offset() - Duration::hours(1))
+                .num_seconds()
+                .abs()
+                <= 2
+        );
+
+        // 已校正的时钟直接签名成功
+        client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(server.dates.lock().unwrap().len(), 3);
+
+        let url = client
+            .PresignObject("ex1.txt")
+            .with_expires(60)
+            .execute()
+            .await
+            .unwrap();
+        let expires: i64 = url::Url::parse(&url)
+            .unwrap()
+            .query_pairs()
+            .find(|(key, _)| key == "Expires")
+            .unwrap()
+            .1
+            .parse()
+            .unwrap();
+        let expected = (Utc::now() + Duration::hours(1)).timestamp() + 60;
+        assert!((expires - expected).abs() <= 2);
+    }
+
+    /// `cached.txt`直接返回缓存的响应, `Date`为2小时前
+    #[derive(Debug)]
+    struct CachedResponse;
+
+    impl Interceptor for CachedResponse {
+        fn before_sign(
+            &self,
+            context: &Context<'_>,
+            _headers: &mut HeaderMap,
+        ) -> Result<Option<Response>, oss::Error> {
+            if !context.url.ends_with("/cached.txt") {
+                return Ok(None);
+            }
+            let mut headers = HeaderMap::new();
+            let value = (Utc::now() - Duration::hours(2))
+                .format(oss::GMT_DATE_FMT)
+                .to_string();
+            headers.insert(DATE, HeaderValue::from_str(&value).unwrap());
+            Ok(Some(Response::from_bytes(
+                url::Url::parse(context.url).unwrap(),
+                StatusCode::OK,
+                headers,
+                "cached",
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn clock_ignore_interceptor_response() {
+        let server = Arc::new(SkewedServer::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(server.clone())
+            .with_interceptor(CachedResponse)
+            .client();
+        client.GetObject("ex1.txt").execute().await.unwrap();
+        let offset = client.clock_offset();
+        assert!((offset - Duration::hours(1)).num_seconds().abs() <= 2);
+
+        let data = client.GetObject("cached.txt").execute().await.unwrap();
+        assert_eq!(data.content(), "cached");
+        assert_eq!(client.clock_offset(), offset);
+        assert_eq!(server.dates.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,192 @@
+//! 并发请求数限制
+//!
+//! 数据面(上传、下载对象数据)与管控面(元数据、列举、Bucket配置等)分别使用独立的信号量,
+//! 大量下载不会阻塞列举或查询请求。每次尝试(包括重试)发送前获取许可,
+//! 读取完响应体或丢弃响应后释放
+use std::{fmt, sync::Arc, time::Duration};
+
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
+
+/// 请求类别
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Plane {
+    /// 传输对象数据, 例如`GetObject`、`PutObject`、`UploadPart`
+    Data,
+    /// 其他请求
+    #[default]
+    Control,
+}
+
+impl Plane {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Data => "data",
+            Self::Control => "control",
+        }
+    }
+}
+
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 按请求类别限制同时进行的请求数, 未设置的类别不限制
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ConcurrencyLimit {
+    data: Option<Arc<Semaphore>>,
+    control: Option<Arc<Semaphore>>,
+}
+
+impl ConcurrencyLimit {
+    pub(crate) fn new(data: Option<usize>, control: Option<usize>) -> Self {
+        let semaphore = |permits: usize| Arc::new(Semaphore::new(permits.max(1)));
+        Self {
+            data: data.map(semaphore),
+            control: control.map(semaphore),
+        }
+    }
+
+    /// 等待许可, 返回许可与排队时间; 该类别不限制时返回`None`
+    pub(crate) async fn acquire(&self, plane: Plane) -> Option<(OwnedSemaphorePermit, Duration)> {
+        let semaphore = match plane {
+            Plane::Data => self.data.as_ref(),
+            Plane::Control => self.control.as_ref(),
+        }?;
+        let start = Instant::now();
+        // 信号量不会被关闭
+        let permit = semaphore.clone().acquire_owned().await.ok()?;
+        Some((permit, start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        metrics::{Attempt, Labels, Metrics},
+        transport::{HttpRequest, Response, Transport, TransportError},
+    };
+    use futures::future::BoxFuture;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    /// 记录同时进行的请求数峰值, 前`fails`次请求返回503
+    #[derive(Debug, Default)]
+    struct SlowTransport {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+        fails: AtomicUsize,
+    }
+
+    impl Transport for SlowTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                let mut headers = oss::http::HeaderMap::new();
+                let failed = self
+                    .fails
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                    .is_ok();
+                let status = match failed {
+                    // 带有错误码时不读取响应体, 响应保持未读状态
+                    true => {
+                        headers.insert("x-oss-ec", "0003-00000001".parse().unwrap());
+                        oss::http::StatusCode::SERVICE_UNAVAILABLE
+                    }
+                    false => oss::http::StatusCode::OK,
+                };
+                Ok(Response::from_bytes(
+                    request.url,
+                    status,
+                    headers,
+                    "hello world",
+                ))
+            })
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct QueueWait(Mutex<Vec<(String, Plane)>>);
+
+    impl Metrics for QueueWait {
+        fn record_attempt(&self, _attempt: &Attempt<'_>) {}
+
+        fn record_queue_wait(&self, labels: &Labels<'_>, plane: Plane, _wait: Duration) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((labels.operation.to_string(), plane));
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrency_limit() {
+        let transport = Arc::new(SlowTransport::default());
+        let metrics = Arc::new(QueueWait::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .with_metrics(metrics.clone())
+            .with_max_data_requests(2)
+            .client();
+        let tasks = (0..8).map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.GetObject("ex1.txt").execute().await })
+        });
+        for result in futures::future::join_all(tasks).await {
+            assert_eq!(result.unwrap().unwrap().content(), "hello world");
+        }
+        assert_eq!(transport.peak.load(Ordering::SeqCst), 2);
+
+        // 未限制管控面请求
+        client.HeadObject("ex1.txt").execute().await.unwrap();
+        let waits = metrics.0.lock().unwrap();
+        assert_eq!(waits.len(), 8);
+        assert!(waits
+            .iter()
+            .all(|(operation, plane)| operation == "GetObject" && *plane == Plane::Data));
+    }
+
+    #[tokio::test]
+    async fn concurrency_release_on_retry() {
+        let transport = Arc::new(SlowTransport {
+            fails: AtomicUsize::new(1),
+            ..Default::default()
+        });
+        let retry_policy = oss::RetryPolicy::new()
+            .with_base_delay(Duration::from_millis(500))
+            .with_jitter(false);
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .with_retry_policy(retry_policy)
+            .with_max_data_requests(1)
+            .client();
+        let first = {
+            let client = client.clone();
+            tokio::spawn(async move { client.GetObject("ex1.txt").execute().await })
+        };
+        // 第一个请求收到503后进入退避
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let start = std::time::Instant::now();
+        client.GetObject("ex2.txt").execute().await.unwrap();
+        let elapsed = start.elapsed();
+        assert!(elapsed < Duration::from_millis(300), "{:?}", elapsed);
+        assert_eq!(first.await.unwrap().unwrap().content(), "hello world");
+    }
+}
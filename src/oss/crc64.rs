@@ -0,0 +1,455 @@
+//! CRC64-ECMA数据校验
+//!
+//! OSS在上传与下载的响应头`x-oss-hash-crc64ecma`中返回对象的CRC64(ECMA-182多项式, 与XZ相同),
+//! 开启`Options::with_crc64_check`后与本地计算的值比较, 不一致时返回`Error::Crc64Mismatch`。
+//! 追加上传与分片上传的整体CRC64可由各段的CRC64与长度通过`combine`得到, 不需要重新读取数据
+use std::{error::Error as StdError, fmt};
+
+use super::http::HeaderMap;
+
+/// 响应头中对象的CRC64
+pub const HASH_CRC64ECMA: &str = "x-oss-hash-crc64ecma";
+
+/// ECMA-182多项式的反射形式
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const TABLE: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ POLY,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// 增量计算的CRC64-ECMA, 同时记录数据长度
+///
+/// ```
+/// use xt_oss::oss::Crc64;
+/// let mut crc = Crc64::new();
+/// crc.update(b"hello ");
+/// crc.update(b"world");
+/// assert_eq!(crc.value(), xt_oss::oss::crc64::checksum(b"hello world"));
+///
+/// // 分段计算后合并
+/// let mut total = Crc64::new();
+/// total.combine(&Crc64::from_checksum(xt_oss::oss::crc64::checksum(b"hello "), 6));
+/// total.combine(&Crc64::from_checksum(xt_oss::oss::crc64::checksum(b"world"), 5));
+/// assert_eq!(total, crc);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc64 {
+    value: u64,
+    len: u64,
+}
+
+impl Crc64 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 由已知的CRC64与数据长度创建, 例如`UploadPart`响应中返回的值
+    pub fn from_checksum(value: u64, len: u64) -> Self {
+        Self { value, len }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = !self.value;
+        for byte in data {
+            crc = TABLE[((crc ^ *byte as u64) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.value = !crc;
+        self.len += data.len() as u64;
+    }
+
+    /// 在当前数据之后追加`other`对应的数据
+    pub fn combine(&mut self, other: &Crc64) {
+        self.value = combine(self.value, other.value, other.len);
+        self.len += other.len;
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// 计算`data`的CRC64
+pub fn checksum(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.value()
+}
+
+/// 由数据A的CRC64`crc1`与长度为`len2`的数据B的CRC64`crc2`计算A+B的CRC64
+pub fn combine(mut crc1: u64, crc2: u64, mut len2: u64) -> u64 {
+    if len2 == 0 {
+        return crc1;
+    }
+    // 参照zlib的crc32_combine: 以GF(2)矩阵表示在CRC后追加零字节的运算, 按长度的二进制位平方
+    let mut even = [0u64; 64];
+    let mut odd = [0u64; 64];
+    odd[0] = POLY;
+    for (n, row) in odd.iter_mut().enumerate().skip(1) {
+        *row = 1 << (n - 1);
+    }
+    gf2_matrix_square(&mut even, &odd);
+    gf2_matrix_square(&mut odd, &even);
+    loop {
+        gf2_matrix_square(&mut even, &odd);
+        if len2 & 1 != 0 {
+            crc1 = gf2_matrix_times(&even, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+        gf2_matrix_square(&mut odd, &even);
+        if len2 & 1 != 0 {
+            crc1 = gf2_matrix_times(&odd, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+    }
+    crc1 ^ crc2
+}
+
+fn gf2_matrix_times(mat: &[u64; 64], mut vec: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while vec != 0 {
+        if vec & 1 != 0 {
+            sum ^= mat[i];
+        }
+        vec >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
+    for (n, row) in square.iter_mut().enumerate() {
+        *row = gf2_matrix_times(mat, mat[n]);
+    }
+}
+
+/// 响应头中的CRC64
+pub(crate) fn from_headers(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(HASH_CRC64ECMA)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// 与响应头中的CRC64比较, 响应中没有CRC64时不校验
+pub(crate) fn verify(client_crc: u64, headers: &HeaderMap) -> Result<(), Crc64Mismatch> {
+    match from_headers(headers) {
+        Some(server_crc) => check(client_crc, server_crc),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn check(client_crc: u64, server_crc: u64) -> Result<(), Crc64Mismatch> {
+    match client_crc == server_crc {
+        true => Ok(()),
+        false => Err(Crc64Mismatch {
+            client_crc,
+            server_crc,
+        }),
+    }
+}
+
+/// 本地计算的CRC64与OSS返回的不一致, 数据在传输中损坏
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc64Mismatch {
+    client_crc: u64,
+    server_crc: u64,
+}
+
+impl Crc64Mismatch {
+    /// 本地计算的CRC64
+    pub fn client_crc(&self) -> u64 {
+        self.client_crc
+    }
+
+    /// 响应头`x-oss-hash-crc64ecma`中的CRC64
+    pub fn server_crc(&self) -> u64 {
+        self.server_crc
+    }
+}
+
+impl fmt::Display for Crc64Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "crc64ecma mismatch: client {}, server {}",
+            self.client_crc, self.server_crc
+        )
+    }
+}
+
+impl StdError for Crc64Mismatch {}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        http::{Method, StatusCode},
+        transport::{HttpRequest, Response, Transport, TransportError},
+        Bytes,
+    };
+    use futures::{future::BoxFuture, TryStreamExt};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    #[test]
+    fn crc64_checksum() {
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"123456789"), 0x995D_C9BB_DF19_39FA);
+
+        let data: Vec<u8> = (0..10_000).map(|i| (i % 251) as u8).collect();
+        let mut crc = Crc64::new();
+        for chunk in data.chunks(777) {
+            crc.update(chunk);
+        }
+        assert_eq!(crc.value(), checksum(&data));
+        assert_eq!(crc.len(), data.len() as u64);
+
+        for split in [0, 1, 4096, 9999, 10_000] {
+            let (a, b) = data.split_at(split);
+            assert_eq!(
+                combine(checksum(a), checksum(b), b.len() as u64),
+                checksum(&data)
+            );
+        }
+
+        let mut headers = HeaderMap::new();
+        assert!(verify(1, &headers).is_ok());
+        headers.insert(HASH_CRC64ECMA, checksum(&data).to_string().parse().unwrap());
+        assert!(verify(checksum(&data), &headers).is_ok());
+        let error = verify(1, &headers).unwrap_err();
+        assert_eq!(error.client_crc(), 1);
+        assert_eq!(error.server_crc(), checksum(&data));
+    }
+
+    /// 在内存中保存对象并返回CRC64, `corrupt`时返回错误的CRC64与损坏的数据
+    #[derive(Debug, Default)]
+    struct CrcServer {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+        parts: Mutex<BTreeMap<u32, Vec<u8>>>,
+        corrupt: AtomicBool,
+    }
+
+    impl Transport for CrcServer {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let chunks: Vec<Bytes> = request.body.into_stream().try_collect().await?;
+                let body = chunks.concat();
+                let key = request.url.path().to_string();
+                let query = request.url.query().unwrap_or_default().to_string();
+                let part_number = query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix("partNumber="))
+                    .map(|value| value.parse::<u32>().unwrap());
+                let mut objects = self.objects.lock().unwrap();
+                let mut parts = self.parts.lock().unwrap();
+                let (mut data, content) = match (&request.method, part_number) {
+                    (&Method::PUT, Some(part_number)) => {
+                        parts.insert(part_number, body.clone());
+                        (body, String::new())
+                    }
+                    (&Method::PUT, None) => {
+                        objects.insert(key, body.clone());
+                        (body, String::new())
+                    }
+                    (&Method::POST, _) if query.starts_with("append") => {
+                        let object = objects.entry(key).or_default();
+                        object.extend(body);
+                        (object.clone(), String::new())
+                    }
+                    (&Method::POST, _) => {
+                        let object: Vec<u8> = std::mem::take(&mut *parts)
+                            .into_values()
+                            .flatten()
+                            .collect();
+                        objects.insert(key.clone(), object.clone());
+                        let content = format!(
+                            "<CompleteMultipartUploadResult><Location>{0}</Location>\
+                             <Bucket>xtoss-ex</Bucket><Key>{0}</Key><ETag>etag</ETag>\
+                             </CompleteMultipartUploadResult>",
+                            key
+                        );
+                        (object, content)
+                    }
+                    _ => {
+                        let object = objects[&key].clone();
+                        (object.clone(), String::from_utf8(object).unwrap())
+                    }
+                };
+                let mut crc = checksum(&data);
+                let mut content = Bytes::from(content);
+                if self.corrupt.load(Ordering::SeqCst) {
+                    match request.method {
+                        Method::GET => {
+                            data[0] ^= 1;
+                            content = data.into();
+                        }
+                        _ => crc ^= 1,
+                    }
+                }
+                let mut headers = HeaderMap::new();
+                headers.insert(HASH_CRC64ECMA, crc.to_string().parse().unwrap());
+                Ok(Response::from_bytes(
+                    request.url,
+                    StatusCode::OK,
+                    headers,
+                    content,
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn crc64_verify_requests() {
+        let server = Arc::new(CrcServer::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(server.clone())
+            .with_crc64_check(true)
+            .client();
+        let data = "hello world ".repeat(1000);
+
+        let chunks: Vec<Bytes> = data
+            .as_bytes()
+            .chunks(5000)
+            .map(Bytes::copy_from_slice)
+            .collect();
+        let body = oss::UploadBody::from_stream_fn(data.len() as u64, move || {
+            futures::stream::iter(chunks.clone().into_iter().map(Ok::<_, std::io::Error>))
+        });
+        client
+            .PutObject("ex1.txt")
+            .with_body(body)
+            .execute()
+            .await
+            .unwrap();
+        let get = client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(get.crc64(), Some(checksum(data.as_bytes())));
+        assert_eq!(get.content(), data);
+
+        let first = client
+            .AppendObject("ex2.txt")
+            .with_content(Bytes::from("hello "))
+            .execute()
+            .await
+            .unwrap();
+        let append = client
+            .AppendObject("ex2.txt")
+            .with_position(6)
+            .with_content(Bytes::from("world"));
+        append
+            .with_init_crc64(first.crc64().unwrap())
+            .execute()
+            .await
+            .unwrap();
+        let error = client
+            .AppendObject("ex2.txt")
+            .with_position(11)
+            .with_init_crc64(0)
+            .with_content(Bytes::from("!"))
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, oss::Error::Crc64Mismatch(_)), "{:?}", error);
+
+        let mut total = Crc64::new();
+        for (n, chunk) in data.as_bytes().chunks(5000).enumerate() {
+            let part = client
+                .UploadPart("ex3.txt")
+                .with_upload_id("upload_id")
+                .with_part_number(n as u32 + 1)
+                .with_content(Bytes::copy_from_slice(chunk))
+                .execute()
+                .await
+                .unwrap();
+            total.combine(&Crc64::from_checksum(
+                part.crc64().unwrap(),
+                chunk.len() as u64,
+            ));
+        }
+        assert_eq!(total.value(), checksum(data.as_bytes()));
+        client
+            .CompleteMultipartUpload("ex3.txt")
+            .with_upload_id("upload_id")
+            .with_crc64(total.value())
+            .execute()
+            .await
+            .unwrap();
+        let stream = client.GetObject("ex3.txt").execute_stream().await.unwrap();
+        assert_eq!(stream.content().collect().await.unwrap(), data);
+
+        // 传输中数据损坏
+        server.corrupt.store(true, Ordering::SeqCst);
+        let error = client
+            .PutObject("ex1.txt")
+            .with_content(Bytes::from(data.clone()))
+            .execute()
+            .await
+            .unwrap_err();
+        match error {
+            oss::Error::Crc64Mismatch(mismatch) => {
+                assert_eq!(mismatch.client_crc(), checksum(data.as_bytes()))
+            }
+            error => panic!("{:?}", error),
+        }
+        let error = client.GetObject("ex1.txt").execute().await.unwrap_err();
+        assert!(matches!(error, oss::Error::Crc64Mismatch(_)), "{:?}", error);
+        let stream = client.GetObject("ex1.txt").execute_stream().await.unwrap();
+        let error = oss::Error::from(stream.content().collect().await.unwrap_err());
+        assert!(matches!(error, oss::Error::Crc64Mismatch(_)), "{:?}", error);
+
+        let path = std::env::temp_dir().join(format!("xt-oss-crc64-{}.txt", std::process::id()));
+        let error = client
+            .GetObject("ex1.txt")
+            .download_to_file(&path)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, oss::Error::Crc64Mismatch(_)), "{:?}", error);
+        assert!(!path.exists());
+
+        // 指定范围时不校验
+        client
+            .GetObject("ex1.txt")
+            .with_range(crate::util::ByteRange::new())
+            .execute()
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,761 @@
+//! 访问凭证
+//!
+//! `Client`在每次请求签名前通过[`CredentialsProvider`]获取访问凭证,
+//! 临时凭证(STS、ECS实例RAM角色)在过期前自动刷新。
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+/// ECS实例元数据服务地址
+pub const ECS_METADATA_ENDPOINT: &str = "http://100.100.100.200";
+/// STS服务地址
+pub const STS_ENDPOINT: &str = "https://sts.aliyuncs.com";
+/// 临时凭证距过期不足该时长(秒)时刷新
+pub const REFRESH_BEFORE_EXPIRATION: i64 = 300;
+/// 获取临时凭证的连接超时(秒)
+pub const CREDENTIALS_CONNECT_TIMEOUT: u64 = 5;
+/// 获取临时凭证的请求超时(秒), 包括读取响应
+pub const CREDENTIALS_TIMEOUT: u64 = 10;
+
+/// 获取临时凭证默认使用的HTTP客户端
+fn default_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .user_agent(super::USER_AGENT)
+        .connect_timeout(std::time::Duration::from_secs(CREDENTIALS_CONNECT_TIMEOUT))
+        .build()
+        .unwrap_or_default()
+}
+
+/// 访问凭证
+#[derive(Clone, Default)]
+pub struct Credentials {
+    pub access_key_id: String,
+    pub access_key_secret: String,
+    /// STS临时授权令牌
+    pub security_token: Option<String>,
+    /// 过期时间, 长期凭证为`None`
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("access_key_secret", &"******")
+            .field(
+                "security_token",
+                &self.security_token.as_ref().map(|_| "******"),
+            )
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+impl Credentials {
+    pub fn new(access_key_id: impl Into<String>, access_key_secret: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            access_key_secret: access_key_secret.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_security_token(mut self, value: impl Into<String>) -> Self {
+        self.security_token = Some(value.into());
+        self
+    }
+
+    pub fn with_expiration(mut self, value: DateTime<Utc>) -> Self {
+        self.expiration = Some(value);
+        self
+    }
+
+    /// 在`seconds`秒内是否过期
+    pub fn expires_within(&self, seconds: i64) -> bool {
+        match self.expiration {
+            Some(expiration) => expiration - Utc::now() <= Duration::seconds(seconds),
+            None => false,
+        }
+    }
+}
+
+/// 获取访问凭证失败
+#[derive(Debug, Clone)]
+pub struct CredentialsError {
+    message: String,
+}
+
+impl CredentialsError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "credentials error: {}", self.message)
+    }
+}
+
+impl std::error::Error for CredentialsError {}
+
+impl From<reqwest::Error> for CredentialsError {
+    fn from(value: reqwest::Error) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+impl From<std::io::Error> for CredentialsError {
+    fn from(value: std::io::Error) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+pub type CredentialsResult = Result<Credentials, CredentialsError>;
+
+/// 访问凭证提供者
+pub trait CredentialsProvider: fmt::Debug + Send + Sync {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult>;
+}
+
+impl<P: CredentialsProvider + ?Sized> CredentialsProvider for Arc<P> {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        (**self).credentials()
+    }
+}
+
+/// 凭证缓存, 在凭证即将过期时才重新获取
+#[derive(Debug, Default)]
+pub struct CredentialsCache {
+    value: Mutex<Option<Credentials>>,
+}
+
+impl CredentialsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn get_or_refresh<F, Fut>(&self, fetch: F) -> CredentialsResult
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = CredentialsResult>,
+    {
+        let mut value = self.value.lock().await;
+        if let Some(credentials) = value.as_ref() {
+            if !credentials.expires_within(REFRESH_BEFORE_EXPIRATION) {
+                return Ok(credentials.clone());
+            }
+        }
+        let credentials = fetch().await?;
+        *value = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+/// 固定的访问凭证
+#[derive(Debug, Clone)]
+pub struct StaticCredentialsProvider {
+    credentials: Credentials,
+}
+
+impl StaticCredentialsProvider {
+    pub fn new(credentials: Credentials) -> Self {
+        Self { credentials }
+    }
+}
+
+impl CredentialsProvider for StaticCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        Box::pin(async move { Ok(self.credentials.clone()) })
+    }
+}
+
+/// 从环境变量读取访问凭证, 每次获取时重新读取
+///
+/// - `OSS_ACCESS_KEY_ID` / `ALIBABA_CLOUD_ACCESS_KEY_ID`
+/// - `OSS_ACCESS_KEY_SECRET` / `ALIBABA_CLOUD_ACCESS_KEY_SECRET`
+/// - `OSS_STS_TOKEN` / `ALIBABA_CLOUD_SECURITY_TOKEN` (可选)
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentCredentialsProvider;
+
+impl EnvironmentCredentialsProvider {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn var(keys: &[&str]) -> Option<String> {
+        keys.iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.is_empty())
+    }
+}
+
+impl CredentialsProvider for EnvironmentCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        Box::pin(async move {
+            let access_key_id = Self::var(&["OSS_ACCESS_KEY_ID", "ALIBABA_CLOUD_ACCESS_KEY_ID"])
+                .ok_or_else(|| CredentialsError::new("access key id is not set"))?;
+            let access_key_secret =
+                Self::var(&["OSS_ACCESS_KEY_SECRET", "ALIBABA_CLOUD_ACCESS_KEY_SECRET"])
+                    .ok_or_else(|| CredentialsError::new("access key secret is not set"))?;
+            let mut credentials = Credentials::new(access_key_id, access_key_secret);
+            if let Some(token) = Self::var(&["OSS_STS_TOKEN", "ALIBABA_CLOUD_SECURITY_TOKEN"]) {
+                credentials = credentials.with_security_token(token);
+            }
+            Ok(credentials)
+        })
+    }
+}
+
+/// 从ini格式的凭证文件读取访问凭证
+///
+/// 文件默认为`ALIBABA_CLOUD_CREDENTIALS_FILE`或`~/.alibabacloud/credentials`,
+/// 配置节默认为`ALIBABA_CLOUD_PROFILE`或`default`
+///
+/// ```ini
+/// [default]
+/// access_key_id = LTAI****************
+/// access_key_secret = yourAccessKeySecret
+/// # security_token = yourSecurityToken
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProfileCredentialsProvider {
+    path: PathBuf,
+    profile: String,
+}
+
+impl Default for ProfileCredentialsProvider {
+    fn default() -> Self {
+        let path = match std::env::var("ALIBABA_CLOUD_CREDENTIALS_FILE") {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => {
+                let home = std::env::var("HOME")
+                    .or_else(|_| std::env::var("USERPROFILE"))
+                    .unwrap_or_default();
+                PathBuf::from(home)
+                    .join(".alibabacloud")
+                    .join("credentials")
+            }
+        };
+        let profile = std::env::var("ALIBABA_CLOUD_PROFILE").unwrap_or("default".to_string());
+        Self { path, profile }
+    }
+}
+
+impl ProfileCredentialsProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_path(mut self, value: impl Into<PathBuf>) -> Self {
+        self.path = value.into();
+        self
+    }
+
+    pub fn with_profile(mut self, value: impl Into<String>) -> Self {
+        self.profile = value.into();
+        self
+    }
+
+    fn parse(content: &str, profile: &str) -> Option<BTreeMap<String, String>> {
+        let mut section: Option<&str> = None;
+        let mut values: Option<BTreeMap<String, String>> = None;
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                section = Some(name.trim());
+                if section == Some(profile) {
+                    values.get_or_insert_with(BTreeMap::new);
+                }
+                continue;
+            }
+            if section != Some(profile) {
+                continue;
+            }
+            if let (Some((key, value)), Some(values)) = (line.split_once('='), values.as_mut()) {
+                values.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+        values
+    }
+}
+
+impl CredentialsProvider for ProfileCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        Box::pin(async move {
+            let content = tokio::fs::read_to_string(&self.path).await?;
+            let values = Self::parse(&content, &self.profile).ok_or_else(|| {
+                CredentialsError::new(format!("profile `{}` not found", self.profile))
+            })?;
+            let get = |key: &str| {
+                values
+                    .get(key)
+                    .cloned()
+                    .ok_or_else(|| CredentialsError::new(format!("`{}` not found", key)))
+            };
+            let mut credentials =
+                Credentials::new(get("access_key_id")?, get("access_key_secret")?);
+            if let Some(token) = values
+                .get("security_token")
+                .or_else(|| values.get("sts_token"))
+            {
+                credentials = credentials.with_security_token(token);
+            }
+            Ok(credentials)
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TemporaryCredentials {
+    access_key_id: String,
+    access_key_secret: String,
+    security_token: String,
+    expiration: DateTime<Utc>,
+}
+
+impl From<TemporaryCredentials> for Credentials {
+    fn from(value: TemporaryCredentials) -> Self {
+        Credentials::new(value.access_key_id, value.access_key_secret)
+            .with_security_token(value.security_token)
+            .with_expiration(value.expiration)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EcsCredentialsResponse {
+    code: String,
+    #[serde(flatten)]
+    credentials: Option<TemporaryCredentials>,
+}
+
+/// 通过ECS实例元数据服务获取实例RAM角色的临时凭证
+///
+/// [doc](https://help.aliyun.com/zh/ecs/user-guide/attach-an-instance-ram-role-to-an-ecs-instance)
+#[derive(Debug)]
+pub struct EcsRamRoleCredentialsProvider {
+    endpoint: String,
+    role_name: Option<String>,
+    imds_v2: bool,
+    client: reqwest::Client,
+    timeout: u64,
+    cache: CredentialsCache,
+}
+
+impl Default for EcsRamRoleCredentialsProvider {
+    fn default() -> Self {
+        Self {
+            endpoint: ECS_METADATA_ENDPOINT.to_string(),
+            role_name: std::env::var("ALIBABA_CLOUD_ECS_METADATA").ok(),
+            imds_v2: false,
+            client: default_client(),
+            timeout: CREDENTIALS_TIMEOUT,
+            cache: CredentialsCache::new(),
+        }
+    }
+}
+
+impl EcsRamRoleCredentialsProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 元数据服务地址, 默认`http://100.100.100.200`
+    pub fn with_endpoint(mut self, value: impl Into<String>) -> Self {
+        self.endpoint = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 实例RAM角色名称, 未设置时从元数据服务查询
+    pub fn with_role_name(mut self, value: impl Into<String>) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// 使用加固模式(先获取元数据访问令牌)
+    pub fn with_imds_v2(mut self, value: bool) -> Self {
+        self.imds_v2 = value;
+        self
+    }
+
+    /// 访问元数据服务的HTTP客户端
+    pub fn with_client(mut self, value: reqwest::Client) -> Self {
+        self.client = value;
+        self
+    }
+
+    /// 每次请求的超时(秒), 默认`CREDENTIALS_TIMEOUT`
+    pub fn with_timeout(mut self, value: u64) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    async fn fetch(&self) -> CredentialsResult {
+        let timeout = std::time::Duration::from_secs(self.timeout);
+        let mut headers = reqwest::header::HeaderMap::new();
+        if self.imds_v2 {
+            let token = self
+                .client
+                .put(format!("{}/latest/api/token", self.endpoint))
+                .timeout(timeout)
+                .header("X-aliyun-ecs-metadata-token-ttl-seconds", "21600")
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?;
+            let token = token
+                .parse()
+                .map_err(|_| CredentialsError::new("invalid metadata token"))?;
+            headers.insert("X-aliyun-ecs-metadata-token", token);
+        }
+        let url = format!(
+            "{}/latest/meta-data/ram/security-credentials/",
+            self.endpoint
+        );
+        let role_name = match &self.role_name {
+            Some(role_name) => role_name.to_owned(),
+            None => self
+                .client
+                .get(&url)
+                .timeout(timeout)
+                .headers(headers.clone())
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?
+                .trim()
+                .to_string(),
+        };
+        let content = self
+            .client
+            .get(format!("{}{}", url, role_name))
+            .timeout(timeout)
+            .headers(headers)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let response: EcsCredentialsResponse = serde_json::from_slice(&content)
+            .map_err(|e| CredentialsError::new(format!("invalid metadata response: {}", e)))?;
+        match (response.code.as_str(), response.credentials) {
+            ("Success", Some(credentials)) => Ok(credentials.into()),
+            (code, _) => Err(CredentialsError::new(format!(
+                "metadata service returned `{}`",
+                code
+            ))),
+        }
+    }
+}
+
+impl CredentialsProvider for EcsRamRoleCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        Box::pin(self.cache.get_or_refresh(|| self.fetch()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AssumeRoleResponse {
+    credentials: Option<TemporaryCredentials>,
+    code: Option<String>,
+    message: Option<String>,
+}
+
+/// 通过STS AssumeRole扮演RAM角色获取临时凭证
+///
+/// [doc](https://help.aliyun.com/zh/ram/developer-reference/api-sts-2015-04-01-assumerole)
+#[derive(Debug)]
+pub struct StsAssumeRoleCredentialsProvider {
+    source: Arc<dyn CredentialsProvider>,
+    role_arn: String,
+    role_session_name: String,
+    policy: Option<String>,
+    duration_seconds: u64,
+    endpoint: String,
+    client: reqwest::Client,
+    timeout: u64,
+    cache: CredentialsCache,
+}
+
+impl StsAssumeRoleCredentialsProvider {
+    /// `source`为调用STS使用的RAM用户凭证
+    pub fn new(
+        source: impl CredentialsProvider + 'static,
+        role_arn: impl Into<String>,
+        role_session_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            source: Arc::new(source),
+            role_arn: role_arn.into(),
+            role_session_name: role_session_name.into(),
+            policy: None,
+            duration_seconds: 3600,
+            endpoint: STS_ENDPOINT.to_string(),
+            client: default_client(),
+            timeout: CREDENTIALS_TIMEOUT,
+            cache: CredentialsCache::new(),
+        }
+    }
+
+    /// 进一步限制权限的策略
+    pub fn with_policy(mut self, value: impl Into<String>) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// 凭证有效时长(秒), 默认3600
+    pub fn with_duration_seconds(mut self, value: u64) -> Self {
+        self.duration_seconds = value;
+        self
+    }
+
+    /// STS服务地址, 默认`https://sts.aliyuncs.com`
+    pub fn with_endpoint(mut self, value: impl Into<String>) -> Self {
+        self.endpoint = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 调用STS的HTTP客户端, 需要代理或私有根证书时可使用`Options::http_client`创建
+    pub fn with_client(mut self, value: reqwest::Client) -> Self {
+        self.client = value;
+        self
+    }
+
+    /// 每次请求的超时(秒), 默认`CREDENTIALS_TIMEOUT`
+    pub fn with_timeout(mut self, value: u64) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    fn nonce() -> String {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        format!("{}{}", nanos, COUNTER.fetch_add(1, Ordering::Relaxed))
+    }
+
+    fn query(&self, source: &Credentials) -> String {
+        let mut params: BTreeMap<&str, String> = BTreeMap::new();
+        params.insert("AccessKeyId", source.access_key_id.to_owned());
+        params.insert("Action", "AssumeRole".to_string());
+        params.insert("DurationSeconds", self.duration_seconds.to_string());
+        params.insert("Format", "JSON".to_string());
+        params.insert("RoleArn", self.role_arn.to_owned());
+        params.insert("RoleSessionName", self.role_session_name.to_owned());
+        params.insert("SignatureMethod", "HMAC-SHA1".to_string());
+        params.insert("SignatureNonce", Self::nonce());
+        params.insert("SignatureVersion", "1.0".to_string());
+        params.insert(
+            "Timestamp",
+            Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        );
+        params.insert("Version", "2015-04-01".to_string());
+        if let Some(policy) = &self.policy {
+            params.insert("Policy", policy.to_owned());
+        }
+        if let Some(token) = &source.security_token {
+            params.insert("SecurityToken", token.to_owned());
+        }
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect::<Vec<String>>()
+            .join("&");
+        let string_to_sign = format!("GET&%2F&{}", urlencoding::encode(&query));
+        let key = format!("{}&", source.access_key_secret);
+        let signature = hmac_sha1::hmac_sha1(key.as_bytes(), string_to_sign.as_bytes());
+        let signature = general_purpose::STANDARD.encode(signature);
+        format!("{}&Signature={}", query, urlencoding::encode(&signature))
+    }
+
+    async fn fetch(&self) -> CredentialsResult {
+        let source = self.source.credentials().await?;
+        let url = format!("{}/?{}", self.endpoint, self.query(&source));
+        let content = self
+            .client
+            .get(url)
+            .timeout(std::time::Duration::from_secs(self.timeout))
+            .send()
+            .await?
+            .bytes()
+            .await?;
+        let response: AssumeRoleResponse = serde_json::from_slice(&content)
+            .map_err(|e| CredentialsError::new(format!("invalid sts response: {}", e)))?;
+        match response.credentials {
+            Some(credentials) => Ok(credentials.into()),
+            None => Err(CredentialsError::new(format!(
+                "sts returned `{}`: {}",
+                response.code.unwrap_or_default(),
+                response.message.unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+impl CredentialsProvider for StsAssumeRoleCredentialsProvider {
+    fn credentials(&self) -> BoxFuture<'_, CredentialsResult> {
+        Box::pin(self.cache.get_or_refresh(|| self.fetch()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::Query, routing::get, Json, Router};
+    use std::{
+        collections::HashMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn temporary(expiration: DateTime<Utc>) -> serde_json::Value {
+        serde_json::json!({
+            "AccessKeyId": "STS.ak",
+            "AccessKeySecret": "sk",
+            "SecurityToken": "token",
+            "Expiration": expiration.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        })
+    }
+
+    #[test]
+    fn credentials_expires_within() {
+        let credentials = Credentials::new("ak", "sk");
+        assert!(!credentials.expires_within(REFRESH_BEFORE_EXPIRATION));
+        let credentials = credentials.with_expiration(Utc::now() + Duration::seconds(60));
+        assert!(credentials.expires_within(REFRESH_BEFORE_EXPIRATION));
+        assert!(!format!("{:?}", credentials).contains("sk"));
+    }
+
+    #[tokio::test]
+    async fn profile_credentials_provider() {
+        let path = std::env::temp_dir().join(format!("xtoss-credentials-{}", std::process::id()));
+        std::fs::write(
+            &path,
+            "[default]\naccess_key_id = ak\naccess_key_secret = sk\n\n[dev]\n# comment\naccess_key_id = dev_ak\naccess_key_secret = dev_sk\nsecurity_token = dev_token\n",
+        )
+        .unwrap();
+        let provider = ProfileCredentialsProvider::new().with_path(&path);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "ak");
+        assert_eq!(credentials.security_token, None);
+        let provider = provider.with_profile("dev");
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.access_key_secret, "dev_sk");
+        assert_eq!(credentials.security_token.as_deref(), Some("dev_token"));
+        let provider = provider.with_profile("missing");
+        assert!(provider.credentials().await.is_err());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn ecs_ram_role_credentials_provider() {
+        static HITS: AtomicUsize = AtomicUsize::new(0);
+        let app = Router::new()
+            .route(
+                "/latest/meta-data/ram/security-credentials/",
+                get(|| async { "EcsRole" }),
+            )
+            .route(
+                "/latest/meta-data/ram/security-credentials/EcsRole",
+                get(|| async {
+                    HITS.fetch_add(1, Ordering::SeqCst);
+                    let mut value = temporary(Utc::now() + Duration::hours(6));
+                    value["Code"] = "Success".into();
+                    Json(value)
+                }),
+            );
+        let endpoint = serve(app).await;
+        let provider = EcsRamRoleCredentialsProvider::new().with_endpoint(endpoint);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.access_key_id, "STS.ak");
+        assert_eq!(credentials.security_token.as_deref(), Some("token"));
+        // 未过期时使用缓存
+        provider.credentials().await.unwrap();
+        assert_eq!(HITS.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn sts_assume_role_credentials_provider() {
+        static HITS: AtomicUsize = AtomicUsize::new(0);
+        let app = Router::new().route(
+            "/",
+            get(|Query(query): Query<HashMap<String, String>>| async move {
+                HITS.fetch_add(1, Ordering::SeqCst);
+                assert_eq!(query["Action"], "AssumeRole");
+                assert_eq!(query["AccessKeyId"], "ak");
+                assert_eq!(query["RoleArn"], "acs:ram::123:role/test");
+                assert!(query.contains_key("Signature"));
+                // 即将过期的凭证每次都会刷新
+                Json(serde_json::json!({
+                    "RequestId": "request_id",
+                    "Credentials": temporary(Utc::now() + Duration::seconds(60)),
+                }))
+            }),
+        );
+        let endpoint = serve(app).await;
+        let source = StaticCredentialsProvider::new(Credentials::new("ak", "sk"));
+        let provider =
+            StsAssumeRoleCredentialsProvider::new(source, "acs:ram::123:role/test", "xtoss")
+                .with_endpoint(endpoint);
+        let credentials = provider.credentials().await.unwrap();
+        assert_eq!(credentials.access_key_secret, "sk");
+        provider.credentials().await.unwrap();
+        assert_eq!(HITS.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn credentials_provider_timeout() {
+        let app = Router::new().fallback(|| async {
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+            "EcsRole"
+        });
+        let endpoint = serve(app).await;
+        let start = std::time::Instant::now();
+        let provider = EcsRamRoleCredentialsProvider::new()
+            .with_endpoint(&endpoint)
+            .with_client(crate::oss::Options::new().http_client().unwrap())
+            .with_timeout(1);
+        assert!(provider.credentials().await.is_err());
+        let source = StaticCredentialsProvider::new(Credentials::new("ak", "sk"));
+        let provider =
+            StsAssumeRoleCredentialsProvider::new(source, "acs:ram::123:role/test", "xtoss")
+                .with_endpoint(&endpoint)
+                .with_timeout(1);
+        assert!(provider.credentials().await.is_err());
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+    }
+}
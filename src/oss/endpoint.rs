@@ -0,0 +1,222 @@
+//! 访问域名
+//!
+//! 默认使用`bucket.region.aliyuncs.com`形式的虚拟主机访问, 也可以选择内网、全球传输加速、
+//! IPv4/IPv6双栈域名, 或直接指定`host[:port]`(例如本地模拟器)。
+//! 指定的域名为IP地址或`localhost`时无法使用虚拟主机, 自动使用路径方式(`host/bucket/object`)
+use std::fmt;
+
+use super::{http::Url, Error};
+
+/// 根据地域生成的访问域名类型
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointType {
+    /// 外网域名, 例如`oss-cn-hangzhou.aliyuncs.com`
+    #[default]
+    Public,
+    /// 内网域名, 例如`oss-cn-hangzhou-internal.aliyuncs.com`
+    Internal,
+    /// 全球加速域名`oss-accelerate.aliyuncs.com`, 需通过`PutBucketTransferAcceleration`开启
+    Accelerate,
+    /// 海外加速域名`oss-accelerate-overseas.aliyuncs.com`, 适用于中国内地以外地域
+    AccelerateOverseas,
+    /// IPv4/IPv6双栈域名, 例如`cn-hangzhou.oss.aliyuncs.com`
+    DualStack,
+}
+
+impl EndpointType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Internal => "internal",
+            Self::Accelerate => "accelerate",
+            Self::AccelerateOverseas => "accelerate-overseas",
+            Self::DualStack => "dual-stack",
+        }
+    }
+
+    /// 地域`region`(例如`oss-cn-hangzhou`)在基础域名`base_domain`下的访问域名
+    pub(crate) fn host(&self, region: &str, base_domain: &str) -> Result<String, Error> {
+        if matches!(self, Self::Public | Self::Internal | Self::DualStack) && region.is_empty() {
+            return Err(Error::Config("region must be provided".to_string()));
+        }
+        Ok(match self {
+            Self::Public => format!("{}.{}", region, base_domain),
+            Self::Internal => format!("{}-internal.{}", region, base_domain),
+            Self::Accelerate => format!("oss-accelerate.{}", base_domain),
+            Self::AccelerateOverseas => format!("oss-accelerate-overseas.{}", base_domain),
+            Self::DualStack => format!(
+                "{}.oss.{}",
+                region.strip_prefix("oss-").unwrap_or(region),
+                base_domain
+            ),
+        })
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 校验`host[:port]`形式的域名, 返回是否只能使用路径方式访问(IP地址或`localhost`)
+pub(crate) fn parse(schema: &str, endpoint: &str) -> Result<bool, Error> {
+    let invalid =
+        |reason: &str| Error::Config(format!("invalid endpoint `{}`: {}", endpoint, reason));
+    let url =
+        Url::parse(&format!("{}://{}", schema, endpoint)).map_err(|e| invalid(&e.to_string()))?;
+    if url.path() != "/" || url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid("expected `host[:port]`"));
+    }
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(invalid("credentials are not allowed"));
+    }
+    Ok(match url.host() {
+        Some(url::Host::Domain(host)) => host.eq_ignore_ascii_case("localhost"),
+        Some(_) => true,
+        None => return Err(invalid("missing host")),
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss;
+
+    #[test]
+    fn endpoint_host() {
+        let region = "oss-cn-shanghai";
+        let cases = [
+            (EndpointType::Public, "oss-cn-shanghai.aliyuncs.com"),
+            (
+                EndpointType::Internal,
+                "oss-cn-shanghai-internal.aliyuncs.com",
+            ),
+            (EndpointType::Accelerate, "oss-accelerate.aliyuncs.com"),
+            (
+                EndpointType::AccelerateOverseas,
+                "oss-accelerate-overseas.aliyuncs.com",
+            ),
+            (EndpointType::DualStack, "cn-shanghai.oss.aliyuncs.com"),
+        ];
+        for (endpoint_type, host) in cases {
+            assert_eq!(endpoint_type.host(region, oss::BASE_URL).unwrap(), host);
+        }
+        assert!(EndpointType::Public.host("", oss::BASE_URL).is_err());
+        assert!(parse("http", "127.0.0.1:9000").unwrap());
+        assert!(parse("http", "[::1]:9000").unwrap());
+        assert!(parse("https", "localhost:9000").unwrap());
+        assert!(!parse("https", "oss-cn-hangzhou.aliyuncs.com").unwrap());
+        assert!(parse("http", "127.0.0.1:9000/bucket").is_err());
+        assert!(parse("http", "user:pass@127.0.0.1").is_err());
+        assert!(parse("http", "").is_err());
+    }
+
+    #[test]
+    fn endpoint_options() {
+        let options = oss::Options::new()
+            .with_bucket("xtoss-ex")
+            .with_region("oss-cn-shanghai")
+            .with_secret(true);
+        assert_eq!(
+            options
+                .clone()
+                .with_path_style(true)
+                .object_url("a/b.txt")
+                .unwrap(),
+            "https://oss-cn-shanghai.aliyuncs.com/xtoss-ex/a/b.txt"
+        );
+        assert_eq!(
+            options
+                .clone()
+                .with_endpoint_type(EndpointType::Accelerate)
+                .base_url()
+                .unwrap(),
+            "https://xtoss-ex.oss-accelerate.aliyuncs.com"
+        );
+        assert_eq!(
+            options
+                .clone()
+                .with_endpoint_type(EndpointType::DualStack)
+                .base_url()
+                .unwrap(),
+            "https://xtoss-ex.cn-shanghai.oss.aliyuncs.com"
+        );
+        assert_eq!(
+            options
+                .clone()
+                .with_base_domain("aliyuncs.example.com")
+                .with_internal(true)
+                .base_url()
+                .unwrap(),
+            "https://xtoss-ex.oss-cn-shanghai-internal.aliyuncs.example.com"
+        );
+        // 服务级接口不经过加速域名
+        assert_eq!(
+            options
+                .clone()
+                .with_endpoint_type(EndpointType::Accelerate)
+                .root_url()
+                .unwrap(),
+            "https://oss-cn-hangzhou.aliyuncs.com"
+        );
+
+        let local = options
+            .clone()
+            .with_secret(false)
+            .with_endpoint("127.0.0.1:9000");
+        assert_eq!(local.base_url().unwrap(), "http://127.0.0.1:9000/xtoss-ex");
+        assert_eq!(local.root_url().unwrap(), "http://127.0.0.1:9000");
+        assert_eq!(
+            options
+                .clone()
+                .with_endpoint("oss.example.com:8443")
+                .object_url("ex1.txt")
+                .unwrap(),
+            "https://xtoss-ex.oss.example.com:8443/ex1.txt"
+        );
+
+        assert!(matches!(
+            options.clone().with_bucket("").base_url(),
+            Err(oss::Error::Config(_))
+        ));
+        assert!(matches!(
+            options.clone().with_cname(true).base_url(),
+            Err(oss::Error::Config(_))
+        ));
+        assert!(matches!(
+            options.with_endpoint("127.0.0.1:9000/path").base_url(),
+            Err(oss::Error::Config(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn endpoint_path_style_request() {
+        use crate::oss::transport::tests::MemoryTransport;
+        use std::sync::Arc;
+        let transport = Arc::new(MemoryTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_endpoint("localhost:9000")
+            .with_transport(transport.clone())
+            .client();
+        client.GetObject("ex1.txt").execute().await.unwrap();
+        let request = transport.requests.lock().unwrap().pop().unwrap();
+        assert_eq!(
+            request.url.as_str(),
+            "http://localhost:9000/xtoss-ex/ex1.txt"
+        );
+
+        let result = client
+            .options()
+            .clone()
+            .with_bucket("")
+            .client()
+            .GetObject("ex1.txt")
+            .execute()
+            .await;
+        assert!(matches!(result, Err(oss::Error::Config(_))));
+    }
+}
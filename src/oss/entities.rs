@@ -9,10 +9,12 @@ pub mod callback;
 pub mod cname;
 pub mod cors;
 pub mod encryption;
+pub mod error;
 pub mod lifecycle;
 pub mod log;
 pub mod multi_upload;
 pub mod object;
+pub mod post;
 pub mod referer;
 pub mod region;
 pub mod style;
@@ -85,10 +85,12 @@ pub struct ListAllMyBucketsResult {
     pub buckets: Buckets,
 }
 
-impl From<oss::Bytes> for ListAllMyBucketsResult {
-    fn from(data: oss::Bytes) -> Self {
+impl TryFrom<oss::Bytes> for ListAllMyBucketsResult {
+    type Error = oss::Error;
+
+    fn try_from(data: oss::Bytes) -> Result<Self, Self::Error> {
         let content = String::from_utf8_lossy(&data);
-        quick_xml::de::from_str::<Self>(&content).unwrap()
+        Ok(quick_xml::de::from_str::<Self>(&content)?)
     }
 }
 
@@ -1,5 +1,8 @@
+use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+
+use crate::oss::Error;
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
 pub enum CallbackBodyType {
     FormUrlEncoded,
@@ -10,8 +13,8 @@ pub enum CallbackBodyType {
 impl fmt::Display for CallbackBodyType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::FormUrlEncoded => write!(f, "{}", "a"),
-            Self::JSON => write!(f, "{}", "b"),
+            Self::FormUrlEncoded => write!(f, "application/x-www-form-urlencoded"),
+            Self::JSON => write!(f, "application/json"),
         }
     }
 }
@@ -56,3 +59,90 @@ pub struct Callback {
     pub callback_sni: Option<String>,
     pub callback_body_type: Option<CallbackBodyType>,
 }
+
+/// `CallbackBody`字段与OSS系统变量的对应关系
+const CALLBACK_BODY_VARS: [(&str, &str); 14] = [
+    ("bucket", "bucket"),
+    ("object", "object"),
+    ("etag", "etag"),
+    ("size", "size"),
+    ("mime_type", "mimeType"),
+    ("imageinfo_height", "imageInfo.height"),
+    ("imageinfo_width", "imageInfo.width"),
+    ("imageinfo_format", "imageInfo.format"),
+    ("crc64", "crc64"),
+    ("content_md5", "contentMd5"),
+    ("vpc_id", "vpcId"),
+    ("client_ip", "clientIp"),
+    ("req_id", "reqId"),
+    ("operation", "operation"),
+];
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CallbackParam<'a> {
+    callback_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_host: Option<&'a str>,
+    callback_body: String,
+    #[serde(rename = "callbackSNI", skip_serializing_if = "Option::is_none")]
+    callback_sni: Option<&'a str>,
+    callback_body_type: String,
+}
+
+impl Callback {
+    /// 回调请求体模板, 包含全部系统变量, 字段名与`CallbackBody`一致
+    pub fn body_template(&self) -> String {
+        match self.callback_body_type.unwrap_or_default() {
+            CallbackBodyType::JSON => format!(
+                "{{{}}}",
+                CALLBACK_BODY_VARS
+                    .iter()
+                    .map(|(field, var)| format!("\"{}\":${{{}}}", field, var))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            CallbackBodyType::FormUrlEncoded => CALLBACK_BODY_VARS
+                .iter()
+                .map(|(field, var)| format!("{}=${{{}}}", field, var))
+                .collect::<Vec<String>>()
+                .join("&"),
+        }
+    }
+
+    /// base64编码后的回调参数, 用于`x-oss-callback`头部或PostObject表单的`callback`字段
+    pub fn to_base64(&self) -> Result<String, Error> {
+        let param = CallbackParam {
+            callback_url: &self.callback_url,
+            callback_host: self.callback_host.as_deref(),
+            callback_body: self.body_template(),
+            callback_sni: self.callback_sni.as_deref(),
+            callback_body_type: self.callback_body_type.unwrap_or_default().to_string(),
+        };
+        let value = serde_json::to_string(&param)
+            .map_err(|e| Error::Config(format!("invalid callback: {}", e)))?;
+        Ok(general_purpose::STANDARD.encode(value))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_to_base64() {
+        let callback = Callback {
+            callback_url: "http://example.com/callback".to_string(),
+            callback_body_type: Some(CallbackBodyType::FormUrlEncoded),
+            ..Callback::default()
+        };
+        let value = general_purpose::STANDARD
+            .decode(callback.to_base64().unwrap())
+            .unwrap();
+        let value = String::from_utf8(value).unwrap();
+        assert!(value.starts_with(
+            r#"{"callbackUrl":"http://example.com/callback","callbackBody":"bucket=${bucket}&object=${object}&"#
+        ));
+        assert!(value.ends_with(r#""callbackBodyType":"application/x-www-form-urlencoded"}"#));
+    }
+}
@@ -0,0 +1,216 @@
+use std::{fmt, str::FromStr};
+
+macro_rules! error_codes {
+    ($($(#[$doc:meta])* $name:ident,)*) => {
+        /// OSS错误码
+        ///
+        /// 未列出的错误码解析为`Unknown`, 保留原始字符串
+        ///
+        /// [doc](https://help.aliyun.com/zh/oss/support/http-status-code)
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $($(#[$doc])* $name,)*
+            /// 未知错误码
+            Unknown(String),
+        }
+
+        impl ErrorCode {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$name => stringify!($name),)*
+                    Self::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl FromStr for ErrorCode {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $(stringify!($name) => Self::$name,)*
+                    _ => Self::Unknown(s.to_string()),
+                })
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// 拒绝访问
+    AccessDenied,
+    /// Bucket已存在
+    BucketAlreadyExists,
+    /// Bucket不为空
+    BucketNotEmpty,
+    /// 上传回调失败
+    CallbackFailed,
+    /// 上传文件大小超过限制
+    EntityTooLarge,
+    /// 上传文件大小小于限制
+    EntityTooSmall,
+    /// Content-MD5校验失败
+    InvalidDigest,
+    /// AccessKey ID不存在
+    InvalidAccessKeyId,
+    /// 参数格式错误
+    InvalidArgument,
+    /// Bucket名称无效
+    InvalidBucketName,
+    /// 文件名称无效
+    InvalidObjectName,
+    /// 文件状态不允许当前操作, 例如读取归档存储的文件
+    InvalidObjectState,
+    /// 分片无效
+    InvalidPart,
+    /// 分片顺序错误
+    InvalidPartOrder,
+    /// Policy文档无效
+    InvalidPolicyDocument,
+    /// OSS内部错误
+    InternalError,
+    /// XML格式错误
+    MalformedXML,
+    /// 请求方法不被允许
+    MethodNotAllowed,
+    /// 缺少参数
+    MissingArgument,
+    /// 缺少Content-Length
+    MissingContentLength,
+    /// Bucket不存在
+    NoSuchBucket,
+    /// Bucket未设置授权策略
+    NoSuchBucketPolicy,
+    /// Bucket未设置跨域规则
+    NoSuchCORSConfiguration,
+    /// 文件不存在
+    NoSuchKey,
+    /// Bucket未设置生命周期规则
+    NoSuchLifecycle,
+    /// Bucket未设置服务端加密规则
+    NoSuchServerSideEncryptionRule,
+    /// 分片上传事件不存在
+    NoSuchUpload,
+    /// 文件版本不存在
+    NoSuchVersion,
+    /// Bucket未设置静态网站托管
+    NoSuchWebsiteConfiguration,
+    /// 功能未实现
+    NotImplemented,
+    /// 追加上传的文件类型不是Appendable
+    ObjectNotAppendable,
+    /// 追加上传的位置与文件长度不一致
+    PositionNotEqualToLength,
+    /// 预处理条件不满足
+    PreconditionFailed,
+    /// 请求超时
+    RequestTimeout,
+    /// 请求时间与服务器时间相差超过15分钟
+    RequestTimeTooSkewed,
+    /// STS临时凭证已过期
+    SecurityTokenExpired,
+    /// 服务暂不可用
+    ServiceUnavailable,
+    /// 签名不匹配
+    SignatureDoesNotMatch,
+    /// 请求频率过高
+    SlowDown,
+    /// 软链接的目标文件不存在
+    SymlinkTargetNotExist,
+    /// Bucket数量超过限制
+    TooManyBuckets,
+}
+
+impl ErrorCode {
+    /// 根据`EC`错误码解析, 仅用于错误消息中不包含`Code`的情况(例如`HEAD`请求)
+    pub fn from_ec(ec: &str) -> Option<Self> {
+        match ec {
+            "0003-00000001" => Some(Self::AccessDenied),
+            "0015-00000101" => Some(Self::NoSuchBucket),
+            "0026-00000001" => Some(Self::NoSuchKey),
+            "0002-00000504" => Some(Self::RequestTimeTooSkewed),
+            _ => None,
+        }
+    }
+
+    /// 目标资源或配置不存在
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::NoSuchBucket
+                | Self::NoSuchBucketPolicy
+                | Self::NoSuchCORSConfiguration
+                | Self::NoSuchKey
+                | Self::NoSuchLifecycle
+                | Self::NoSuchServerSideEncryptionRule
+                | Self::NoSuchUpload
+                | Self::NoSuchVersion
+                | Self::NoSuchWebsiteConfiguration
+                | Self::SymlinkTargetNotExist
+        )
+    }
+
+    /// 请求被限流
+    pub fn is_throttled(&self) -> bool {
+        matches!(self, Self::SlowDown | Self::ServiceUnavailable)
+    }
+
+    /// 临时性错误, 可以重试
+    pub fn is_retryable(&self) -> bool {
+        self.is_throttled() || matches!(self, Self::InternalError | Self::RequestTimeout)
+    }
+
+    /// 身份验证或授权失败
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            Self::AccessDenied
+                | Self::InvalidAccessKeyId
+                | Self::SecurityTokenExpired
+                | Self::SignatureDoesNotMatch
+        )
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(value: &str) -> Self {
+        match value.parse() {
+            Ok(code) => code,
+            Err(never) => match never {},
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn error_code_parse() {
+        assert_eq!(ErrorCode::from("NoSuchKey"), ErrorCode::NoSuchKey);
+        assert_eq!(ErrorCode::from("SlowDown").to_string(), "SlowDown");
+        let code = ErrorCode::from("QosExceeded");
+        assert_eq!(code, ErrorCode::Unknown("QosExceeded".to_string()));
+        assert_eq!(code.as_str(), "QosExceeded");
+        assert!(!code.is_retryable());
+        assert_eq!(
+            ErrorCode::from_ec("0026-00000001"),
+            Some(ErrorCode::NoSuchKey)
+        );
+    }
+
+    #[test]
+    fn error_code_classify() {
+        assert!(ErrorCode::NoSuchUpload.is_not_found());
+        assert!(ErrorCode::SlowDown.is_throttled());
+        assert!(ErrorCode::InternalError.is_retryable());
+        assert!(!ErrorCode::AccessDenied.is_retryable());
+        assert!(ErrorCode::SignatureDoesNotMatch.is_auth_error());
+    }
+}
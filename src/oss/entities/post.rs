@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+/// PostObject Policy表单域条件
+///
+/// [doc](https://help.aliyun.com/zh/oss/developer-reference/postobject#section-d5z-1ww-wdb)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostPolicyCondition {
+    /// 上传的Bucket名称, `{"bucket": "examplebucket"}`
+    Bucket(String),
+    /// 表单域的值必须与指定值完全匹配, `["eq", "$key", "user/a.png"]`
+    Eq(String, String),
+    /// 表单域的值必须以指定前缀开始, `["starts-with", "$key", "user/"]`
+    StartsWith(String, String),
+    /// 表单域的值必须在列表之中, `["in", "$content-type", ["image/jpg"]]`
+    In(String, Vec<String>),
+    /// 表单域的值不能在列表之中, `["not-in", "$cache-control", ["no-cache"]]`
+    NotIn(String, Vec<String>),
+    /// 上传文件大小范围(字节), `["content-length-range", 1, 10]`
+    ContentLengthRange(u64, u64),
+}
+
+impl PostPolicyCondition {
+    fn field(name: &str) -> String {
+        match name.starts_with('$') {
+            true => name.to_string(),
+            false => format!("${}", name),
+        }
+    }
+}
+
+impl Serialize for PostPolicyCondition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Self::Bucket(bucket) => json!({ "bucket": bucket }),
+            Self::Eq(name, value) => json!(["eq", Self::field(name), value]),
+            Self::StartsWith(name, value) => json!(["starts-with", Self::field(name), value]),
+            Self::In(name, values) => json!(["in", Self::field(name), values]),
+            Self::NotIn(name, values) => json!(["not-in", Self::field(name), values]),
+            Self::ContentLengthRange(min, max) => json!(["content-length-range", min, max]),
+        };
+        value.serialize(serializer)
+    }
+}
+
+/// PostObject Policy
+#[derive(Debug, Clone, Serialize)]
+pub struct PostPolicy {
+    /// 过期时间, ISO8601 GMT格式
+    #[serde(serialize_with = "serialize_expiration")]
+    pub expiration: DateTime<Utc>,
+    pub conditions: Vec<PostPolicyCondition>,
+}
+
+fn serialize_expiration<S: Serializer>(
+    value: &DateTime<Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+}
+
+/// 浏览器表单上传所需的全部数据
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PostObjectForm {
+    /// 表单提交地址
+    pub url: String,
+    /// base64编码后的Policy
+    pub policy: String,
+    /// Policy签名
+    pub signature: String,
+    /// 表单域, 按顺序放在`file`表单域之前
+    pub fields: Vec<(String, String)>,
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn post_policy_serialize() {
+        let policy = PostPolicy {
+            expiration: Utc.with_ymd_and_hms(2023, 12, 3, 13, 0, 0).unwrap(),
+            conditions: vec![
+                PostPolicyCondition::Bucket("examplebucket".to_string()),
+                PostPolicyCondition::ContentLengthRange(1, 10),
+                PostPolicyCondition::Eq("success_action_status".to_string(), "201".to_string()),
+                PostPolicyCondition::StartsWith("$key".to_string(), "user/eric/".to_string()),
+                PostPolicyCondition::In(
+                    "content-type".to_string(),
+                    vec!["image/jpg".to_string(), "image/png".to_string()],
+                ),
+            ],
+        };
+        let left = r#"{"expiration":"2023-12-03T13:00:00.000Z","conditions":[{"bucket":"examplebucket"},["content-length-range",1,10],["eq","$success_action_status","201"],["starts-with","$key","user/eric/"],["in","$content-type",["image/jpg","image/png"]]]}"#;
+        assert_eq!(left, serde_json::to_string(&policy).unwrap());
+    }
+}
@@ -1,5 +1,8 @@
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
+
+use crate::oss;
+
 /// OSS 区域信息
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RegionInfo {
@@ -19,9 +22,34 @@ pub struct RegionInfoList {
     pub region_info: Vec<RegionInfo>,
 }
 
-impl From<Bytes> for RegionInfoList {
-    fn from(item: Bytes) -> Self {
+impl TryFrom<Bytes> for RegionInfoList {
+    type Error = oss::Error;
+
+    fn try_from(item: Bytes) -> Result<Self, Self::Error> {
         let content = String::from_utf8_lossy(&item);
-        quick_xml::de::from_str(&content).unwrap()
+        Ok(quick_xml::de::from_str(&content)?)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn region_info_list_try_from() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<RegionInfoList>
+  <RegionInfo>
+     <Region>oss-cn-hangzhou</Region>
+     <InternetEndpoint>oss-cn-hangzhou.aliyuncs.com</InternetEndpoint>
+     <InternalEndpoint>oss-cn-hangzhou-internal.aliyuncs.com</InternalEndpoint>
+     <AccelerateEndpoint>oss-accelerate.aliyuncs.com</AccelerateEndpoint>
+  </RegionInfo>
+</RegionInfoList>"#;
+        let list = RegionInfoList::try_from(Bytes::from(xml)).unwrap();
+        assert_eq!(list.region_info[0].region, "oss-cn-hangzhou");
+
+        let result = RegionInfoList::try_from(Bytes::from("<RegionInfoList>"));
+        assert!(matches!(result, Err(oss::Error::Decode(_))));
     }
 }
@@ -0,0 +1,206 @@
+use std::{fmt, sync::Arc};
+
+use super::{
+    http::{HeaderMap, Method},
+    transport::HttpRequest,
+    Error, Response,
+};
+
+/// 拦截器可见的请求信息
+#[derive(Debug, Clone, Copy)]
+pub struct Context<'a> {
+    /// API名称, 例如`PutObject`
+    pub operation: Option<&'a str>,
+    pub method: &'a Method,
+    pub url: &'a str,
+    /// 参与签名的资源路径, 例如`/bucket/object?acl`
+    pub resource: Option<&'a str>,
+    /// 第几次尝试, 从1开始
+    pub attempt: u32,
+}
+
+/// 请求拦截器
+///
+/// 每次尝试(包括重试)依次经过三个阶段:
+///
+/// - `before_sign`: 签名前, 新增的请求头参与签名, 例如`x-oss-traffic-limit`
+/// - `after_sign`: 签名后、发送前, 可查看已签名的完整请求; 修改参与签名的请求头会导致签名失败
+/// - `after_response`: 收到响应后, 可查看或替换响应
+///
+/// `before_sign`、`after_sign`返回`Some`时不再发送请求, 直接以该响应作为结果(例如从缓存读取),
+/// 该响应仍会经过`after_response`。`before_sign`、`after_sign`按注册顺序调用,
+/// `after_response`按相反顺序调用; 任一阶段返回错误时请求失败
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # use xt_oss::oss::interceptor::{Context, Interceptor};
+/// #[derive(Debug)]
+/// struct TraceId;
+///
+/// impl Interceptor for TraceId {
+///     fn before_sign(
+///         &self,
+///         _context: &Context<'_>,
+///         headers: &mut oss::http::HeaderMap,
+///     ) -> Result<Option<oss::Response>, oss::Error> {
+///         headers.insert("x-trace-id", oss::http::HeaderValue::from_static("abc"));
+///         Ok(None)
+///     }
+/// }
+///
+/// let client = oss::Options::new()
+///     .with_bucket("xtoss-ex")
+///     .with_interceptor(TraceId)
+///     .client();
+/// ```
+pub trait Interceptor: fmt::Debug + Send + Sync {
+    fn before_sign(
+        &self,
+        _context: &Context<'_>,
+        _headers: &mut HeaderMap,
+    ) -> Result<Option<Response>, Error> {
+        Ok(None)
+    }
+
+    fn after_sign(
+        &self,
+        _context: &Context<'_>,
+        _request: &mut HttpRequest,
+    ) -> Result<Option<Response>, Error> {
+        Ok(None)
+    }
+
+    fn after_response(
+        &self,
+        _context: &Context<'_>,
+        _response: &mut Response,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<I: Interceptor + ?Sized> Interceptor for Arc<I> {
+    fn before_sign(
+        &self,
+        context: &Context<'_>,
+        headers: &mut HeaderMap,
+    ) -> Result<Option<Response>, Error> {
+        (**self).before_sign(context, headers)
+    }
+
+    fn after_sign(
+        &self,
+        context: &Context<'_>,
+        request: &mut HttpRequest,
+    ) -> Result<Option<Response>, Error> {
+        (**self).after_sign(context, request)
+    }
+
+    fn after_response(&self, context: &Context<'_>, response: &mut Response) -> Result<(), Error> {
+        (**self).after_response(context, response)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        http::{header::AUTHORIZATION, HeaderValue, StatusCode},
+        transport::tests::MemoryTransport,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        stages: Mutex<Vec<String>>,
+        cached: bool,
+    }
+
+    impl Interceptor for Recorder {
+        fn before_sign(
+            &self,
+            context: &Context<'_>,
+            headers: &mut HeaderMap,
+        ) -> Result<Option<Response>, Error> {
+            self.stages.lock().unwrap().push(format!(
+                "before_sign {}",
+                context.resource.unwrap_or_default()
+            ));
+            headers.insert("x-oss-traffic-limit", HeaderValue::from_static("819200"));
+            Ok(self.cached.then(|| {
+                Response::from_bytes(
+                    context.url.parse().unwrap(),
+                    StatusCode::OK,
+                    HeaderMap::new(),
+                    "cached",
+                )
+            }))
+        }
+
+        fn after_sign(
+            &self,
+            _context: &Context<'_>,
+            request: &mut HttpRequest,
+        ) -> Result<Option<Response>, Error> {
+            assert!(request.headers.contains_key(AUTHORIZATION));
+            self.stages.lock().unwrap().push("after_sign".to_string());
+            Ok(None)
+        }
+
+        fn after_response(
+            &self,
+            _context: &Context<'_>,
+            response: &mut Response,
+        ) -> Result<(), Error> {
+            self.stages
+                .lock()
+                .unwrap()
+                .push(format!("after_response {}", response.status()));
+            Ok(())
+        }
+    }
+
+    fn client(recorder: Arc<Recorder>, transport: Arc<MemoryTransport>) -> oss::Client {
+        oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_interceptor(recorder)
+            .with_transport(transport)
+            .client()
+    }
+
+    #[tokio::test]
+    async fn interceptor_stages() {
+        let recorder = Arc::new(Recorder::default());
+        let transport = Arc::new(MemoryTransport::default());
+        let client = client(recorder.clone(), transport.clone());
+        let data = client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(data.content(), "hello world");
+        assert_eq!(
+            *recorder.stages.lock().unwrap(),
+            [
+                "before_sign /xtoss-ex/ex1.txt",
+                "after_sign",
+                "after_response 200 OK"
+            ]
+        );
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[0].headers["x-oss-traffic-limit"], "819200");
+    }
+
+    #[tokio::test]
+    async fn interceptor_short_circuit() {
+        let recorder = Arc::new(Recorder {
+            cached: true,
+            ..Recorder::default()
+        });
+        let transport = Arc::new(MemoryTransport::default());
+        let client = client(recorder.clone(), transport.clone());
+        let data = client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(data.content(), "cached");
+        assert_eq!(recorder.stages.lock().unwrap().len(), 2);
+        assert!(transport.requests.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+//! 客户端带宽限速
+//!
+//! 令牌桶按固定速率补充令牌(字节), 同一客户端的所有并发请求共享令牌桶,
+//! 因此限制的是总带宽而非单个请求的带宽。与`x-oss-traffic-limit`(服务端单链接限速)可同时使用
+use std::{
+    cmp,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::{StreamExt, TryStreamExt};
+use tokio::time::Instant;
+
+use super::{transport::BodyStream, Bytes};
+
+/// 限速时请求体按块发送的大小
+const CHUNK_SIZE: usize = 16 * 1024;
+
+#[derive(Debug)]
+struct State {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// 令牌桶限速器, 单位字节/秒
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// // 上传与下载各限制为2 MB/s
+/// let client = oss::Options::new()
+///     .with_bucket("xtoss-ex")
+///     .with_upload_limit(2 * 1024 * 1024)
+///     .with_download_limit(2 * 1024 * 1024)
+///     .client();
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    /// 每秒`bytes_per_sec`字节, 突发上限默认为一秒的流量
+    pub fn new(bytes_per_sec: u64) -> Self {
+        let rate = bytes_per_sec.max(1) as f64;
+        Self {
+            rate,
+            burst: rate,
+            state: Mutex::new(State {
+                tokens: rate,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// 突发上限(字节), 即空闲后可立即发送的数据量
+    pub fn with_burst(mut self, bytes: u64) -> Self {
+        self.burst = bytes.max(1) as f64;
+        let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        state.tokens = state.tokens.min(self.burst);
+        self
+    }
+
+    pub fn rate(&self) -> u64 {
+        self.rate as u64
+    }
+
+    /// 预留`bytes`个令牌, 返回需要等待的时间
+    ///
+    /// 令牌不足时允许透支, 后续请求按透支量排队等待, 保证总速率不超过限制
+    fn reserve(&self, bytes: u64) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.rate).min(self.burst) - bytes as f64;
+        state.updated_at = now;
+        match state.tokens < 0.0 {
+            true => Duration::from_secs_f64(-state.tokens / self.rate),
+            false => Duration::ZERO,
+        }
+    }
+
+    /// 等待直到可以传输`bytes`字节
+    pub async fn acquire(&self, bytes: u64) {
+        let wait = self.reserve(bytes);
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 按限速读取数据流, 每块数据传出前等待相应的令牌
+    pub(crate) fn throttle(self: Arc<Self>, stream: BodyStream) -> BodyStream {
+        stream
+            .and_then(move |chunk| {
+                let limiter = self.clone();
+                async move {
+                    limiter.acquire(chunk.len() as u64).await;
+                    Ok(chunk)
+                }
+            })
+            .boxed()
+    }
+}
+
+/// 将请求体拆分为小块, 以便限速时平滑发送
+pub(crate) fn chunks(bytes: Bytes) -> BodyStream {
+    let len = bytes.len();
+    futures::stream::iter((0..len).step_by(CHUNK_SIZE).map(move |start| {
+        let end = cmp::min(start + CHUNK_SIZE, len);
+        Ok(bytes.slice(start..end))
+    }))
+    .boxed()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn limiter_throttle() {
+        let limiter = Arc::new(RateLimiter::new(64 * 1024).with_burst(CHUNK_SIZE as u64));
+        let data = Bytes::from(vec![7u8; 64 * 1024 + 1]);
+        let start = std::time::Instant::now();
+        let stream = limiter.clone().throttle(chunks(data.clone()));
+        let received: Vec<Bytes> = stream.try_collect().await.unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(received.len(), 5);
+        assert_eq!(received.concat(), data);
+        // 突发16 KiB后, 剩余48 KiB按64 KiB/s发送
+        assert!(elapsed >= Duration::from_millis(700), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(3), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn limiter_shared() {
+        let limiter = Arc::new(RateLimiter::new(32 * 1024).with_burst(1));
+        let start = std::time::Instant::now();
+        let tasks = (0..4).map(|_| {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire(4 * 1024).await })
+        });
+        futures::future::join_all(tasks).await;
+        // 4个任务共16 KiB, 总速率32 KiB/s
+        assert!(start.elapsed() >= Duration::from_millis(450));
+    }
+
+    #[tokio::test]
+    async fn limiter_upload_body() {
+        use crate::oss::{self, http::header::CONTENT_LENGTH, transport::tests::MemoryTransport};
+        let transport = Arc::new(MemoryTransport::default());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(transport.clone())
+            .with_upload_limit(1024 * 1024)
+            .client();
+        client
+            .PutObject("ex1.txt")
+            .with_content(Bytes::from(vec![0u8; 40 * 1024]))
+            .execute()
+            .await
+            .unwrap();
+
+        let request = transport.requests.lock().unwrap().pop().unwrap();
+        assert_eq!(request.headers[CONTENT_LENGTH], "40960");
+        let chunks: Vec<Bytes> = request.body.into_stream().try_collect().await.unwrap();
+        assert_eq!(
+            chunks.iter().map(Bytes::len).collect::<Vec<_>>(),
+            [16384, 16384, 8192]
+        );
+    }
+}
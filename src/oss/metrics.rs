@@ -0,0 +1,429 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use super::{
+    concurrency::Plane,
+    entities::error::ErrorCode,
+    http::{Method, StatusCode},
+};
+
+/// 指标标签
+#[derive(Debug, Clone, Copy)]
+pub struct Labels<'a> {
+    /// API名称, 例如`PutObject`
+    pub operation: &'a str,
+    pub bucket: &'a str,
+}
+
+/// 请求结果分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    /// 2xx、3xx
+    Success,
+    /// 4xx
+    ClientError,
+    /// 5xx
+    ServerError,
+    /// 未收到响应, 例如连接失败、超时
+    TransportError,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::ClientError => "client_error",
+            Self::ServerError => "server_error",
+            Self::TransportError => "transport_error",
+        }
+    }
+
+    pub(crate) fn from_status(status: StatusCode) -> Self {
+        if status.is_server_error() {
+            Self::ServerError
+        } else if status.is_client_error() {
+            Self::ClientError
+        } else {
+            Self::Success
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 一次请求尝试(包括重试)的指标
+#[derive(Debug, Clone, Copy)]
+pub struct Attempt<'a> {
+    pub labels: Labels<'a>,
+    pub method: &'a Method,
+    /// 第几次尝试, 从1开始
+    pub attempt: u32,
+    pub outcome: Outcome,
+    /// 响应状态码, 未收到响应时为`None`
+    pub status: Option<StatusCode>,
+    /// 发送请求到收到响应头的耗时
+    pub latency: Duration,
+    pub bytes_sent: u64,
+}
+
+/// 请求指标
+///
+/// 通过`Options::with_metrics`设置, 请求流程中按尝试调用`record_attempt`,
+/// 读取响应体后调用`record_bytes_received`, 解析到OSS错误码后调用`record_error_code`;
+/// 设置并发请求数限制时, 每次尝试获取许可后调用`record_queue_wait`
+pub trait Metrics: fmt::Debug + Send + Sync {
+    fn record_attempt(&self, attempt: &Attempt<'_>);
+
+    fn record_bytes_received(&self, _labels: &Labels<'_>, _bytes: u64) {}
+
+    fn record_error_code(&self, _labels: &Labels<'_>, _code: &ErrorCode) {}
+
+    fn record_queue_wait(&self, _labels: &Labels<'_>, _plane: Plane, _wait: Duration) {}
+}
+
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn record_attempt(&self, attempt: &Attempt<'_>) {
+        (**self).record_attempt(attempt)
+    }
+
+    fn record_bytes_received(&self, labels: &Labels<'_>, bytes: u64) {
+        (**self).record_bytes_received(labels, bytes)
+    }
+
+    fn record_error_code(&self, labels: &Labels<'_>, code: &ErrorCode) {
+        (**self).record_error_code(labels, code)
+    }
+
+    fn record_queue_wait(&self, labels: &Labels<'_>, plane: Plane, wait: Duration) {
+        (**self).record_queue_wait(labels, plane, wait)
+    }
+}
+
+/// 请求耗时直方图的默认分桶(秒)
+pub const DEFAULT_LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+#[derive(Debug, Default, Clone)]
+struct Histogram {
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, buckets: &[f64], seconds: f64) {
+        self.counts.resize(buckets.len(), 0);
+        if let Some(index) = buckets.iter().position(|le| seconds <= *le) {
+            self.counts[index] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str, buckets: &[f64]) {
+        let mut cumulative = 0;
+        for (le, count) in buckets.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{},le=\"{}\"}} {}",
+                name, labels, le, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{},le=\"+Inf\"}} {}",
+            name, labels, self.count
+        );
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, self.sum);
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, self.count);
+    }
+}
+
+type Key = (String, String);
+
+#[derive(Debug, Default)]
+struct State {
+    requests: BTreeMap<(String, String, Outcome), u64>,
+    latency: BTreeMap<Key, Histogram>,
+    bytes_sent: BTreeMap<Key, u64>,
+    bytes_received: BTreeMap<Key, u64>,
+    errors: BTreeMap<(String, String, String), u64>,
+    queue_wait: BTreeMap<(String, String, Plane), Histogram>,
+}
+
+/// 以Prometheus文本格式输出的内存指标
+///
+/// - `oss_requests_total{operation,bucket,outcome}` 请求尝试次数
+/// - `oss_request_duration_seconds{operation,bucket}` 请求耗时直方图
+/// - `oss_sent_bytes_total{operation,bucket}`、`oss_received_bytes_total{operation,bucket}` 流量
+/// - `oss_errors_total{operation,bucket,code}` OSS错误码次数
+/// - `oss_queue_wait_seconds{operation,bucket,plane}` 等待并发许可的时间直方图
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # use std::sync::Arc;
+/// # use xt_oss::oss::metrics::PrometheusMetrics;
+/// let metrics = Arc::new(PrometheusMetrics::new());
+/// let client = oss::Options::new()
+///     .with_bucket("xtoss-ex")
+///     .with_metrics(metrics.clone())
+///     .client();
+/// // GET /metrics
+/// let body = metrics.render();
+/// ```
+#[derive(Debug)]
+pub struct PrometheusMetrics {
+    buckets: Vec<f64>,
+    state: Mutex<State>,
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_LATENCY_BUCKETS)
+    }
+
+    /// 自定义耗时直方图分桶(秒), 需按升序排列
+    pub fn with_buckets(buckets: &[f64]) -> Self {
+        Self {
+            buckets: buckets.to_vec(),
+            state: Mutex::default(),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 输出Prometheus文本格式
+    pub fn render(&self) -> String {
+        let state = self.state();
+        let mut out = String::new();
+
+        out.push_str("# HELP oss_requests_total Number of OSS request attempts.\n");
+        out.push_str("# TYPE oss_requests_total counter\n");
+        for ((operation, bucket, outcome), value) in &state.requests {
+            let _ = writeln!(
+                out,
+                "oss_requests_total{{operation=\"{}\",bucket=\"{}\",outcome=\"{}\"}} {}",
+                escape(operation),
+                escape(bucket),
+                outcome,
+                value
+            );
+        }
+
+        out.push_str("# HELP oss_request_duration_seconds OSS request latency in seconds.\n");
+        out.push_str("# TYPE oss_request_duration_seconds histogram\n");
+        for ((operation, bucket), histogram) in &state.latency {
+            let labels = format!(
+                "operation=\"{}\",bucket=\"{}\"",
+                escape(operation),
+                escape(bucket)
+            );
+            histogram.render(
+                &mut out,
+                "oss_request_duration_seconds",
+                &labels,
+                &self.buckets,
+            );
+        }
+
+        for (name, help, values) in [
+            (
+                "oss_sent_bytes_total",
+                "Bytes sent in OSS request bodies.",
+                &state.bytes_sent,
+            ),
+            (
+                "oss_received_bytes_total",
+                "Bytes received in OSS response bodies.",
+                &state.bytes_received,
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} counter", name);
+            for ((operation, bucket), value) in values {
+                let _ = writeln!(
+                    out,
+                    "{}{{operation=\"{}\",bucket=\"{}\"}} {}",
+                    name,
+                    escape(operation),
+                    escape(bucket),
+                    value
+                );
+            }
+        }
+
+        out.push_str("# HELP oss_errors_total Number of OSS error responses by error code.\n");
+        out.push_str("# TYPE oss_errors_total counter\n");
+        for ((operation, bucket, code), value) in &state.errors {
+            let _ = writeln!(
+                out,
+                "oss_errors_total{{operation=\"{}\",bucket=\"{}\",code=\"{}\"}} {}",
+                escape(operation),
+                escape(bucket),
+                escape(code),
+                value
+            );
+        }
+
+        out.push_str(
+            "# HELP oss_queue_wait_seconds Time spent waiting for a concurrency permit.\n",
+        );
+        out.push_str("# TYPE oss_queue_wait_seconds histogram\n");
+        for ((operation, bucket, plane), histogram) in &state.queue_wait {
+            let labels = format!(
+                "operation=\"{}\",bucket=\"{}\",plane=\"{}\"",
+                escape(operation),
+                escape(bucket),
+                plane
+            );
+            histogram.render(&mut out, "oss_queue_wait_seconds", &labels, &self.buckets);
+        }
+        out
+    }
+}
+
+impl Metrics for PrometheusMetrics {
+    fn record_attempt(&self, attempt: &Attempt<'_>) {
+        let labels = &attempt.labels;
+        let key = (labels.operation.to_string(), labels.bucket.to_string());
+        let seconds = attempt.latency.as_secs_f64();
+        let mut state = self.state();
+        *state
+            .requests
+            .entry((key.0.clone(), key.1.clone(), attempt.outcome))
+            .or_default() += 1;
+        *state.bytes_sent.entry(key.clone()).or_default() += attempt.bytes_sent;
+        state
+            .latency
+            .entry(key)
+            .or_default()
+            .observe(&self.buckets, seconds);
+    }
+
+    fn record_bytes_received(&self, labels: &Labels<'_>, bytes: u64) {
+        let key = (labels.operation.to_string(), labels.bucket.to_string());
+        *self.state().bytes_received.entry(key).or_default() += bytes;
+    }
+
+    fn record_error_code(&self, labels: &Labels<'_>, code: &ErrorCode) {
+        let key = (
+            labels.operation.to_string(),
+            labels.bucket.to_string(),
+            code.to_string(),
+        );
+        *self.state().errors.entry(key).or_default() += 1;
+    }
+
+    fn record_queue_wait(&self, labels: &Labels<'_>, plane: Plane, wait: Duration) {
+        let key = (
+            labels.operation.to_string(),
+            labels.bucket.to_string(),
+            plane,
+        );
+        self.state()
+            .queue_wait
+            .entry(key)
+            .or_default()
+            .observe(&self.buckets, wait.as_secs_f64());
+    }
+}
+
+/// 转义标签值中的`\`、`"`与换行
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn prometheus_render() {
+        let metrics = PrometheusMetrics::with_buckets(&[0.1, 1.0]);
+        let labels = Labels {
+            operation: "GetObject",
+            bucket: "xtoss-ex",
+        };
+        for (status, latency) in [(StatusCode::OK, 50), (StatusCode::SERVICE_UNAVAILABLE, 500)] {
+            metrics.record_attempt(&Attempt {
+                labels,
+                method: &Method::GET,
+                attempt: 1,
+                outcome: Outcome::from_status(status),
+                status: Some(status),
+                latency: Duration::from_millis(latency),
+                bytes_sent: 0,
+            });
+        }
+        metrics.record_bytes_received(&labels, 1024);
+        metrics.record_error_code(&labels, &ErrorCode::NoSuchKey);
+        metrics.record_queue_wait(&labels, Plane::Data, Duration::from_millis(200));
+
+        let text = metrics.render();
+        for line in [
+            r#"oss_requests_total{operation="GetObject",bucket="xtoss-ex",outcome="success"} 1"#,
+            r#"oss_requests_total{operation="GetObject",bucket="xtoss-ex",outcome="server_error"} 1"#,
+            r#"oss_request_duration_seconds_bucket{operation="GetObject",bucket="xtoss-ex",le="0.1"} 1"#,
+            r#"oss_request_duration_seconds_bucket{operation="GetObject",bucket="xtoss-ex",le="1"} 2"#,
+            r#"oss_request_duration_seconds_count{operation="GetObject",bucket="xtoss-ex"} 2"#,
+            r#"oss_received_bytes_total{operation="GetObject",bucket="xtoss-ex"} 1024"#,
+            r#"oss_errors_total{operation="GetObject",bucket="xtoss-ex",code="NoSuchKey"} 1"#,
+            r#"oss_queue_wait_seconds_bucket{operation="GetObject",bucket="xtoss-ex",plane="data",le="0.1"} 0"#,
+            r#"oss_queue_wait_seconds_count{operation="GetObject",bucket="xtoss-ex",plane="data"} 1"#,
+        ] {
+            assert!(text.contains(line), "missing `{}` in\n{}", line, text);
+        }
+        assert_eq!(escape("a\"b\\c"), "a\\\"b\\\\c");
+    }
+
+    #[tokio::test]
+    async fn prometheus_client() {
+        use crate::oss::{self, transport::tests::MemoryTransport};
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(MemoryTransport::default())
+            .with_metrics(metrics.clone())
+            .client();
+        client
+            .PutObject("ex1.txt")
+            .with_content(oss::Bytes::from("hello"))
+            .execute()
+            .await
+            .unwrap();
+        client.GetObject("ex1.txt").execute().await.unwrap();
+
+        let text = metrics.render();
+        for line in [
+            r#"oss_requests_total{operation="PutObject",bucket="xtoss-ex",outcome="success"} 1"#,
+            r#"oss_requests_total{operation="GetObject",bucket="xtoss-ex",outcome="success"} 1"#,
+            r#"oss_sent_bytes_total{operation="PutObject",bucket="xtoss-ex"} 5"#,
+            r#"oss_received_bytes_total{operation="GetObject",bucket="xtoss-ex"} 11"#,
+        ] {
+            assert!(text.contains(line), "missing `{}` in\n{}", line, text);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! 传输进度
+//!
+//! `PutObject`、`AppendObject`、`UploadPart`在发送请求体时, `GetObject`在接收响应体时,
+//! 每发送或收到一块数据报告一次已传输字节数。失败重试时从0重新开始。
+//! 可传入闭包、通过`channel`以watch通道接收最新进度, 或用`MultipartProgress`汇总各分片的进度
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use futures::{StreamExt, TryStreamExt};
+use tokio::sync::watch;
+
+use super::transport::BodyStream;
+
+/// 一次传输的进度
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    transferred: u64,
+    total: Option<u64>,
+    part_number: Option<u32>,
+}
+
+impl Progress {
+    /// 已传输字节数
+    pub fn transferred(&self) -> u64 {
+        self.transferred
+    }
+
+    /// 总字节数, 下载时响应没有`Content-Length`则为`None`
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// 分片上传的分片号, `MultipartProgress`汇总的进度中为最近更新的分片
+    pub fn part_number(&self) -> Option<u32> {
+        self.part_number
+    }
+
+    /// 完成比例, 取值0.0 ~ 1.0
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| match total {
+            0 => 1.0,
+            total => self.transferred as f64 / total as f64,
+        })
+    }
+}
+
+/// 进度监听
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # async fn run(client: oss::Client) -> Result<(), oss::Error> {
+/// client
+///     .GetObject("large.bin")
+///     .with_progress(|progress: oss::Progress| {
+///         println!("{} / {:?}", progress.transferred(), progress.total())
+///     })
+///     .download_to_file("large.bin")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait ProgressListener: Send + Sync {
+    fn on_progress(&self, progress: Progress);
+}
+
+impl<F> ProgressListener for F
+where
+    F: Fn(Progress) + Send + Sync,
+{
+    fn on_progress(&self, progress: Progress) {
+        self(progress)
+    }
+}
+
+/// 将进度发送到watch通道, 见`channel`
+#[derive(Debug)]
+pub struct ProgressSender(watch::Sender<Progress>);
+
+impl ProgressListener for ProgressSender {
+    fn on_progress(&self, progress: Progress) {
+        self.0.send_replace(progress);
+    }
+}
+
+/// 创建watch通道, 接收端只保留最新的进度, 适合定时刷新的进度条
+pub fn channel() -> (ProgressSender, watch::Receiver<Progress>) {
+    let (sender, receiver) = watch::channel(Progress::default());
+    (ProgressSender(sender), receiver)
+}
+
+/// 分片上传的整体进度与各分片进度, 克隆后共享
+///
+/// 作为各分片`UploadPart::with_progress`的监听, 每次分片进度更新时向`with_listener`设置的监听
+/// 报告所有分片已传输字节数之和
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # async fn run(client: oss::Client, upload_id: &str) -> Result<(), oss::Error> {
+/// let progress = oss::MultipartProgress::new(Some(10 << 20))
+///     .with_listener(|total: oss::Progress| println!("{:?}", total.fraction()));
+/// for part_number in 1..=2 {
+///     let body = oss::UploadBody::from_path_range("large.bin", (part_number - 1) * (5 << 20), 5 << 20);
+///     client
+///         .UploadPart("large.bin")
+///         .with_upload_id(upload_id)
+///         .with_part_number(part_number as u32)
+///         .with_body(body)
+///         .with_progress(progress.clone())
+///         .execute()
+///         .await?;
+/// }
+/// println!("{:?}", progress.parts());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct MultipartProgress {
+    total: Option<u64>,
+    parts: Arc<Mutex<BTreeMap<u32, Progress>>>,
+    listener: Option<Arc<dyn ProgressListener>>,
+}
+
+impl MultipartProgress {
+    /// `total`为所有分片的总字节数
+    pub fn new(total: Option<u64>) -> Self {
+        Self {
+            total,
+            ..Self::default()
+        }
+    }
+
+    /// 接收整体进度
+    pub fn with_listener(mut self, value: impl ProgressListener + 'static) -> Self {
+        self.listener = Some(Arc::new(value));
+        self
+    }
+
+    /// 所有分片的整体进度
+    pub fn progress(&self) -> Progress {
+        Progress {
+            transferred: self.lock().values().map(|part| part.transferred).sum(),
+            total: self.total,
+            part_number: None,
+        }
+    }
+
+    /// 各分片的进度, 按分片号排列
+    pub fn parts(&self) -> Vec<Progress> {
+        self.lock().values().copied().collect()
+    }
+
+    pub fn part(&self, part_number: u32) -> Option<Progress> {
+        self.lock().get(&part_number).copied()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<u32, Progress>> {
+        self.parts.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ProgressListener for MultipartProgress {
+    fn on_progress(&self, progress: Progress) {
+        let total = {
+            let mut parts = self.lock();
+            parts.insert(progress.part_number.unwrap_or_default(), progress);
+            Progress {
+                transferred: parts.values().map(|part| part.transferred).sum(),
+                total: self.total,
+                part_number: progress.part_number,
+            }
+        };
+        if let Some(listener) = &self.listener {
+            listener.on_progress(total);
+        }
+    }
+}
+
+impl fmt::Debug for MultipartProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultipartProgress")
+            .field("total", &self.total)
+            .field("parts", &*self.lock())
+            .finish_non_exhaustive()
+    }
+}
+
+/// builder中保存的进度监听
+#[derive(Clone)]
+pub(crate) struct ProgressSink {
+    listener: Arc<dyn ProgressListener>,
+    part_number: Option<u32>,
+}
+
+impl ProgressSink {
+    pub(crate) fn new(listener: impl ProgressListener + 'static) -> Self {
+        Self {
+            listener: Arc::new(listener),
+            part_number: None,
+        }
+    }
+
+    pub(crate) fn with_part_number(mut self, value: u32) -> Self {
+        self.part_number = Some(value);
+        self
+    }
+
+    fn report(&self, transferred: u64, total: Option<u64>) {
+        self.listener.on_progress(Progress {
+            transferred,
+            total,
+            part_number: self.part_number,
+        });
+    }
+
+    /// 先报告0, 之后每传输一块数据报告一次
+    pub(crate) fn track(self, stream: BodyStream, total: Option<u64>) -> BodyStream {
+        self.report(0, total);
+        let mut transferred = 0;
+        stream
+            .inspect_ok(move |chunk| {
+                transferred += chunk.len() as u64;
+                self.report(transferred, total);
+            })
+            .boxed()
+    }
+}
+
+impl fmt::Debug for ProgressSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressSink")
+            .field("part_number", &self.part_number)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        self,
+        http::{header::CONTENT_LENGTH, HeaderMap, StatusCode},
+        transport::{HttpRequest, Response, Transport, TransportError},
+        Bytes,
+    };
+    use futures::future::BoxFuture;
+
+    /// 读取完请求体, 返回分块的100 KiB响应体
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    impl Transport for EchoTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let _: Vec<Bytes> = request.body.into_stream().try_collect().await?;
+                let chunks = (0..10).map(|_| Ok(Bytes::from(vec![0; 10 * 1024])));
+                let mut headers = HeaderMap::new();
+                headers.insert(CONTENT_LENGTH, (100 * 1024).into());
+                Ok(Response::new(
+                    request.url,
+                    StatusCode::OK,
+                    headers,
+                    futures::stream::iter(chunks).boxed(),
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn progress_listeners() {
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(EchoTransport)
+            .client();
+        let data = Bytes::from(vec![0; 100 * 1024]);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let record = {
+            let events = events.clone();
+            move |progress: Progress| events.lock().unwrap().push(progress)
+        };
+        client
+            .PutObject("ex1.bin")
+            .with_content(data.clone())
+            .with_progress(record.clone())
+            .execute()
+            .await
+            .unwrap();
+        let uploaded = std::mem::take(&mut *events.lock().unwrap());
+        assert!(uploaded.len() > 2);
+        assert_eq!(uploaded[0].transferred(), 0);
+        assert!(uploaded
+            .windows(2)
+            .all(|w| w[0].transferred() < w[1].transferred()));
+        let last = uploaded.last().unwrap();
+        assert_eq!((last.transferred(), last.total()), (102400, Some(102400)));
+        assert_eq!(last.fraction(), Some(1.0));
+
+        let (sender, receiver) = channel();
+        let data = client
+            .GetObject("ex1.bin")
+            .with_progress(sender)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(data.content().len(), 102400);
+        let last = *receiver.borrow();
+        assert_eq!((last.transferred(), last.total()), (102400, Some(102400)));
+
+        let multipart = MultipartProgress::new(Some(150 * 1024)).with_listener(record);
+        for (part_number, len) in [(1, 100 * 1024), (2, 50 * 1024)] {
+            client
+                .UploadPart("ex1.bin")
+                .with_upload_id("upload_id")
+                .with_part_number(part_number)
+                .with_content(Bytes::from(vec![0; len]))
+                .with_progress(multipart.clone())
+                .execute()
+                .await
+                .unwrap();
+        }
+        let parts = multipart.parts();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[1].part_number(), Some(2));
+        assert_eq!(parts[1].transferred(), 50 * 1024);
+        assert_eq!(multipart.part(1).unwrap().total(), Some(100 * 1024));
+        assert_eq!(multipart.progress().transferred(), 150 * 1024);
+        let last = *events.lock().unwrap().last().unwrap();
+        assert_eq!((last.transferred(), last.total()), (153600, Some(153600)));
+        assert_eq!(last.part_number(), Some(2));
+    }
+}
@@ -0,0 +1,389 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use super::{
+    api::{Error, ErrorMessage},
+    entities::error::ErrorCode,
+    http::StatusCode,
+    transport::TransportError,
+    Response,
+};
+
+/// 默认最大尝试次数(包括首次请求)
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// 默认初始退避时长(毫秒)
+pub const DEFAULT_BASE_DELAY: u64 = 200;
+/// 默认最大退避时长(毫秒)
+pub const DEFAULT_MAX_DELAY: u64 = 20_000;
+
+/// 请求重试策略
+///
+/// 第`n`次重试前等待`min(max_delay, base_delay * 2^(n-1))`, 开启`jitter`时在`[0, 退避时长]`
+/// 之间随机取值。每次重试都会重新签名(使用新的`Date`)。
+///
+/// 除状态码外也根据响应中的OSS错误码判断, `ErrorCode::is_retryable`为真的错误(例如状态码为400的
+/// `RequestTimeout`)同样重试
+///
+/// 非幂等请求(`POST`, 例如`AppendObject`、`CompleteMultipartUpload`)只在连接未建立时重试,
+/// 避免服务端已处理的请求被重复执行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    /// 重试5xx服务端错误
+    server_error: bool,
+    /// 重试429、503限流
+    throttled: bool,
+    /// 重试请求超时
+    timeout: bool,
+    /// 重试连接失败
+    connect: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: Duration::from_millis(DEFAULT_BASE_DELAY),
+            max_delay: Duration::from_millis(DEFAULT_MAX_DELAY),
+            jitter: true,
+            server_error: true,
+            throttled: true,
+            timeout: true,
+            connect: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 不重试
+    pub fn none() -> Self {
+        Self::default().with_max_attempts(1)
+    }
+
+    /// 最大尝试次数(包括首次请求), 最小为1
+    pub fn with_max_attempts(mut self, value: u32) -> Self {
+        self.max_attempts = value.max(1);
+        self
+    }
+
+    pub fn with_base_delay(mut self, value: Duration) -> Self {
+        self.base_delay = value;
+        self
+    }
+
+    pub fn with_max_delay(mut self, value: Duration) -> Self {
+        self.max_delay = value;
+        self
+    }
+
+    pub fn with_jitter(mut self, value: bool) -> Self {
+        self.jitter = value;
+        self
+    }
+
+    pub fn with_retry_server_error(mut self, value: bool) -> Self {
+        self.server_error = value;
+        self
+    }
+
+    pub fn with_retry_throttled(mut self, value: bool) -> Self {
+        self.throttled = value;
+        self
+    }
+
+    pub fn with_retry_timeout(mut self, value: bool) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    pub fn with_retry_connect(mut self, value: bool) -> Self {
+        self.connect = value;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// 第`attempt`次请求失败后的等待时长
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exp = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1u32 << exp)
+            .min(self.max_delay);
+        match self.jitter {
+            true => delay.mul_f64(random()),
+            false => delay,
+        }
+    }
+
+    fn retry_status(&self, status: StatusCode) -> bool {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE if self.throttled => {
+                true
+            }
+            StatusCode::NOT_IMPLEMENTED => false,
+            _ => self.server_error && status.is_server_error(),
+        }
+    }
+
+    fn retry_code(&self, code: &ErrorCode) -> bool {
+        match code {
+            _ if code.is_throttled() => self.throttled,
+            ErrorCode::RequestTimeout => self.timeout,
+            _ => self.server_error && code.is_retryable(),
+        }
+    }
+
+    /// 判断请求结果是否需要重试, `idempotent`为`false`时仅重试连接失败
+    ///
+    /// `code`为错误响应中的OSS错误码, 临时性错误即使状态码为4xx(例如`RequestTimeout`)也重试
+    pub(crate) fn should_retry(
+        &self,
+        result: &Result<Response, Error>,
+        code: Option<&ErrorCode>,
+        idempotent: bool,
+    ) -> bool {
+        match result {
+            Err(Error::Transport(e)) if e.is_connect() => self.connect,
+            _ if !idempotent => false,
+            Ok(resp) => {
+                self.retry_status(resp.status()) || code.is_some_and(|code| self.retry_code(code))
+            }
+            Err(Error::Transport(e)) if e.is_timeout() => self.timeout,
+            Err(Error::Transport(e)) => self.connect && e.is_request(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// 错误响应中的OSS错误码
+///
+/// 优先使用响应头`x-oss-ec`; 否则读取错误消息, 并以缓存的响应体重建响应
+pub(crate) async fn error_code(
+    resp: Response,
+) -> Result<(Response, Option<ErrorCode>), TransportError> {
+    let status = resp.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        return Ok((resp, None));
+    }
+    let code = resp
+        .headers()
+        .get("x-oss-ec")
+        .and_then(|ec| ec.to_str().ok())
+        .and_then(ErrorCode::from_ec);
+    if code.is_some() {
+        return Ok((resp, code));
+    }
+    let url = resp.url().clone();
+    let headers = resp.headers().clone();
+    let body = resp.bytes().await?;
+    let code = quick_xml::de::from_str::<ErrorMessage>(&String::from_utf8_lossy(&body))
+        .ok()
+        .map(|message| message.error_code());
+    Ok((Response::from_bytes(url, status, headers, body), code))
+}
+
+/// `[0, 1)`之间的随机数, 仅用于退避抖动
+fn random() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64,
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_delay() {
+        let policy = RetryPolicy::new()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(1000))
+            .with_jitter(false);
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(10), Duration::from_millis(1000));
+        let policy = policy.with_jitter(true);
+        for attempt in 1..10 {
+            assert!(policy.delay(attempt) <= Duration::from_millis(1000));
+        }
+        assert_eq!(RetryPolicy::none().max_attempts(), 1);
+    }
+
+    #[test]
+    fn retry_policy_status() {
+        let policy = RetryPolicy::new();
+        assert!(policy.retry_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(policy.retry_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(policy.retry_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!policy.retry_status(StatusCode::NOT_IMPLEMENTED));
+        assert!(!policy.retry_status(StatusCode::NOT_FOUND));
+        let policy = policy.with_retry_server_error(false);
+        assert!(!policy.retry_status(StatusCode::BAD_GATEWAY));
+        assert!(policy.retry_status(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    /// 前`fails`次请求返回503
+    async fn server(
+        fails: usize,
+    ) -> (
+        crate::oss::Client,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) {
+        error_server(fails, 503, "").await
+    }
+
+    /// 前`fails`次请求返回状态码为`status`、消息体为`body`的错误
+    async fn error_server(
+        fails: usize,
+        status: u16,
+        body: &'static str,
+    ) -> (
+        crate::oss::Client,
+        std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) {
+        use axum::{http::StatusCode, routing::get, Router};
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let handler = move || {
+            let counter = counter.clone();
+            async move {
+                match counter.fetch_add(1, Ordering::SeqCst) < fails {
+                    true => (StatusCode::from_u16(status).unwrap(), body),
+                    false => (StatusCode::OK, ""),
+                }
+            }
+        };
+        let app = Router::new().route("/", get(handler.clone()).post(handler));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        let client = crate::oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_cname(true)
+            .with_endpoint(addr.to_string())
+            .with_retry_policy(RetryPolicy::new().with_base_delay(Duration::from_millis(1)))
+            .client();
+        (client, hits)
+    }
+
+    #[tokio::test]
+    async fn retry_idempotent_request() {
+        use std::sync::atomic::Ordering;
+        let (client, hits) = server(2).await;
+        let url = client.base_url().unwrap();
+        let resp = client
+            .request
+            .task()
+            .with_url(&url)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        let (client, hits) = server(5).await;
+        let url = client.base_url().unwrap();
+        let resp = client
+            .request
+            .task()
+            .with_url(&url)
+            .with_retry_policy(Some(RetryPolicy::none()))
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retry_skip_non_idempotent() {
+        use std::sync::atomic::Ordering;
+        let (client, hits) = server(1).await;
+        let url = client.base_url().unwrap();
+        let resp = client
+            .request
+            .task()
+            .with_url(&url)
+            .with_method(crate::oss::http::Method::POST)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn retry_policy_code() {
+        let policy = RetryPolicy::new();
+        assert!(policy.retry_code(&ErrorCode::RequestTimeout));
+        assert!(policy.retry_code(&ErrorCode::SlowDown));
+        assert!(policy.retry_code(&ErrorCode::InternalError));
+        assert!(!policy.retry_code(&ErrorCode::NoSuchKey));
+        assert!(!policy
+            .with_retry_timeout(false)
+            .retry_code(&ErrorCode::RequestTimeout));
+        assert!(!policy
+            .with_retry_throttled(false)
+            .retry_code(&ErrorCode::SlowDown));
+    }
+
+    #[tokio::test]
+    async fn retry_error_code() {
+        use std::sync::atomic::Ordering;
+        const REQUEST_TIMEOUT: &str =
+            "<Error><Code>RequestTimeout</Code><Message>timeout</Message>\
+            <RequestId>1</RequestId><HostId>localhost</HostId></Error>";
+        let (client, hits) = error_server(1, 400, REQUEST_TIMEOUT).await;
+        let url = client.base_url().unwrap();
+        let resp = client
+            .request
+            .task()
+            .with_url(&url)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        const INVALID_ARGUMENT: &str =
+            "<Error><Code>InvalidArgument</Code><Message>invalid</Message>\
+            <RequestId>1</RequestId><HostId>localhost</HostId></Error>";
+        let (client, hits) = error_server(1, 400, INVALID_ARGUMENT).await;
+        let url = client.base_url().unwrap();
+        let resp = client
+            .request
+            .task()
+            .with_url(&url)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        // 读取错误码后重建的响应仍保留消息体
+        assert_eq!(resp.text().await.unwrap(), INVALID_ARGUMENT);
+    }
+}
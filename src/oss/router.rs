@@ -0,0 +1,261 @@
+//! 多Bucket客户端
+//!
+//! 按Bucket名称路由请求: 首次访问某个Bucket时通过`GetBucketLocation`查询所在区域,
+//! 访问域名与区域不符时根据错误消息中提示的`Endpoint`确定区域, 缓存后生成该Bucket的客户端。
+//! 所有Bucket的客户端共享连接池、凭证、限速、并发限制与时钟偏差
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+#[cfg(feature = "blocking")]
+use super::blocking;
+use super::{Async, Client, EndpointType, Error};
+use crate::oss;
+
+/// 多Bucket客户端, 克隆后共享区域缓存
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # async fn run() -> Result<(), oss::Error> {
+/// let router = oss::BucketRouter::new(oss::Options::new().client());
+/// // 查询xtoss-ex所在区域并缓存, 再次访问时不再查询
+/// let ex1 = router.bucket("xtoss-ex").await?.GetObject("ex1.txt").execute().await?;
+/// let ex2 = router.bucket("xtoss-ex-sh").await?.GetObject("ex2.txt").execute().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BucketRouter<M = Async> {
+    client: Client<M>,
+    buckets: Arc<Mutex<HashMap<String, Client<M>>>>,
+}
+
+impl<M: Clone> BucketRouter<M> {
+    /// `client`的配置(凭证、域名类型、传输层等)用于所有Bucket, 其`bucket`与`region`被忽略
+    pub fn new(client: Client<M>) -> Self {
+        Self {
+            client,
+            buckets: Arc::default(),
+        }
+    }
+
+    pub fn client(&self) -> &Client<M> {
+        &self.client
+    }
+
+    /// 已缓存的Bucket所在区域
+    pub fn region(&self, bucket: &str) -> Option<String> {
+        self.cached(bucket)
+            .map(|client| client.region().to_string())
+    }
+
+    /// 指定Bucket所在区域, 不再查询
+    pub fn set_region(&self, bucket: &str, region: impl Into<String>) -> Client<M> {
+        let client = self.client.bucket_client(bucket, region);
+        self.lock().insert(bucket.to_string(), client.clone());
+        client
+    }
+
+    /// 清除缓存的区域, 下次访问时重新查询
+    pub fn forget(&self, bucket: &str) {
+        self.lock().remove(bucket);
+    }
+
+    /// 根据请求错误中提示的`Endpoint`更新Bucket所在区域, 返回是否更新
+    pub fn update_from_error(&self, bucket: &str, error: &Error) -> bool {
+        match error.endpoint().and_then(region_from_endpoint) {
+            Some(region) => {
+                self.set_region(bucket, region);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Client<M>>> {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn cached(&self, bucket: &str) -> Option<Client<M>> {
+        self.lock().get(bucket).cloned()
+    }
+
+    async fn resolve(&self, bucket: &str) -> oss::Result<Client<M>> {
+        if let Some(client) = self.cached(bucket) {
+            return Ok(client);
+        }
+        let options = self.client.options();
+        // 指定了endpoint(例如本地模拟器)时所有Bucket使用同一域名, 不需要查询
+        if options.endpoint_type == EndpointType::Public
+            && !options.cname
+            && !options.endpoint.is_empty()
+        {
+            return Ok(self.set_region(bucket, options.region.clone()));
+        }
+        let client = Client::<Async> {
+            options: self.client.options.clone(),
+            request: self.client.request.clone(),
+            mode: PhantomData,
+        };
+        let region = match client
+            .GetBucketLocation()
+            .with_bucket(bucket)
+            .execute()
+            .await
+        {
+            Ok(data) => data.content().0,
+            Err(error) => match error.endpoint().and_then(region_from_endpoint) {
+                Some(region) => region,
+                None => return Err(error),
+            },
+        };
+        Ok(self.set_region(bucket, region))
+    }
+}
+
+impl BucketRouter {
+    /// 指定Bucket的客户端, 首次访问时查询所在区域
+    pub async fn bucket(&self, name: &str) -> oss::Result<Client> {
+        self.resolve(name).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BucketRouter<blocking::Blocking> {
+    /// 指定Bucket的客户端, 首次访问时查询所在区域, 不能在异步运行时中调用
+    pub fn bucket(&self, name: &str) -> oss::Result<blocking::Client> {
+        blocking::block_on(self.resolve(name))
+    }
+}
+
+/// 由域名得到区域, 例如`oss-cn-shanghai.aliyuncs.com`、`oss-cn-shanghai-internal.aliyuncs.com`
+/// 与双栈域名`cn-shanghai.oss.aliyuncs.com`均为`oss-cn-shanghai`
+fn region_from_endpoint(endpoint: &str) -> Option<String> {
+    let host = endpoint
+        .trim_start_matches("http://")
+        .trim_start_matches("https://");
+    let label = host.split(['.', ':', '/']).next()?;
+    let label = label.strip_suffix("-internal").unwrap_or(label);
+    match label {
+        "" | "oss-accelerate" | "oss-accelerate-overseas" => None,
+        label if label.starts_with("oss-") => Some(label.to_string()),
+        label => Some(format!("oss-{}", label)),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::oss::{
+        http::{HeaderMap, StatusCode},
+        transport::{HttpRequest, Response, Transport, TransportError},
+    };
+    use futures::future::BoxFuture;
+
+    /// `bkt-sh`位于上海, 可在任意区域查询; `bkt-bj`位于北京, 只能通过北京域名访问
+    #[derive(Debug, Default)]
+    struct RegionServer {
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl Transport for RegionServer {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                self.urls.lock().unwrap().push(request.url.to_string());
+                let host = request.url.host_str().unwrap_or_default().to_string();
+                let (status, body) = if host.starts_with("bkt-bj.")
+                    && !host.ends_with("oss-cn-beijing.aliyuncs.com")
+                {
+                    (
+                        StatusCode::FORBIDDEN,
+                        "<Error><Code>AccessDenied</Code>\
+                         <Message>The bucket you are attempting to access must be addressed using the specified endpoint.</Message>\
+                         <RequestId>1</RequestId><HostId>bkt-bj.oss-cn-hangzhou.aliyuncs.com</HostId>\
+                         <Endpoint>oss-cn-beijing.aliyuncs.com</Endpoint></Error>",
+                    )
+                } else if request.url.query() == Some("location") {
+                    (
+                        StatusCode::OK,
+                        "<LocationConstraint>oss-cn-shanghai</LocationConstraint>",
+                    )
+                } else {
+                    (StatusCode::OK, "hello world")
+                };
+                Ok(Response::from_bytes(
+                    request.url,
+                    status,
+                    HeaderMap::new(),
+                    body,
+                ))
+            })
+        }
+    }
+
+    #[test]
+    fn router_region_from_endpoint() {
+        for endpoint in [
+            "oss-cn-shanghai.aliyuncs.com",
+            "https://oss-cn-shanghai-internal.aliyuncs.com",
+            "cn-shanghai.oss.aliyuncs.com",
+        ] {
+            assert_eq!(
+                region_from_endpoint(endpoint).as_deref(),
+                Some("oss-cn-shanghai")
+            );
+        }
+        assert_eq!(region_from_endpoint("oss-accelerate.aliyuncs.com"), None);
+        assert_eq!(region_from_endpoint(""), None);
+    }
+
+    #[tokio::test]
+    async fn router_discover_region() {
+        let server = Arc::new(RegionServer::default());
+        let router = BucketRouter::new(
+            oss::Options::new()
+                .with_access_key_id("access_key_id")
+                .with_access_key_secret("access_key_secret")
+                .with_transport(server.clone())
+                .client(),
+        );
+
+        for _ in 0..2 {
+            let client = router.bucket("bkt-sh").await.unwrap();
+            let data = client.GetObject("ex1.txt").execute().await.unwrap();
+            assert_eq!(data.content(), "hello world");
+        }
+        assert_eq!(router.region("bkt-sh").as_deref(), Some("oss-cn-shanghai"));
+
+        let client = router.bucket("bkt-bj").await.unwrap();
+        assert_eq!(client.region(), "oss-cn-beijing");
+        client.GetObject("ex1.txt").execute().await.unwrap();
+
+        assert_eq!(
+            *server.urls.lock().unwrap(),
+            [
+                "http://bkt-sh.oss-cn-hangzhou.aliyuncs.com/?location",
+                "http://bkt-sh.oss-cn-shanghai.aliyuncs.com/ex1.txt",
+                "http://bkt-sh.oss-cn-shanghai.aliyuncs.com/ex1.txt",
+                "http://bkt-bj.oss-cn-hangzhou.aliyuncs.com/?location",
+                "http://bkt-bj.oss-cn-beijing.aliyuncs.com/ex1.txt",
+            ]
+        );
+
+        // 缓存的区域失效时根据错误更新
+        router.set_region("bkt-bj", "oss-cn-hangzhou");
+        let error = router
+            .bucket("bkt-bj")
+            .await
+            .unwrap()
+            .GetObject("ex1.txt")
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(router.update_from_error("bkt-bj", &error));
+        assert_eq!(router.region("bkt-bj").as_deref(), Some("oss-cn-beijing"));
+
+        router.forget("bkt-sh");
+        assert_eq!(router.region("bkt-sh"), None);
+    }
+}
@@ -0,0 +1,330 @@
+//! 请求追踪与指标
+//!
+//! 启用`tracing`特性时为每次API调用创建`oss.request`span, 未启用时为空实现。
+//! span只记录操作名称、bucket、key、状态码、request id、字节数、耗时与重试次数,
+//! 不记录请求头、url参数、凭证与签名
+use std::{future::Future, sync::Arc, time::Duration};
+
+use super::{
+    api::Error,
+    concurrency::Plane,
+    http::Method,
+    metrics::{Attempt, Labels, Metrics, Outcome},
+    Response,
+};
+
+/// 从签名资源路径`/bucket/key?sub`中解析bucket与key
+fn split_resource(resource: Option<&str>) -> (&str, &str) {
+    let path = resource
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('/');
+    match path.split_once('/') {
+        Some((bucket, key)) => (bucket, key),
+        None => (path, ""),
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+struct Span(tracing::Span);
+
+#[cfg(feature = "tracing")]
+impl Span {
+    fn new(operation: &str, method: &Method, bucket: &str, key: &str, bytes_sent: usize) -> Self {
+        Self(tracing::info_span!(
+            "oss.request",
+            operation,
+            method = %method,
+            bucket,
+            key,
+            bytes_sent,
+            status = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            bytes_received = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            retries = tracing::field::Empty,
+        ))
+    }
+
+    fn none() -> Self {
+        Self(tracing::Span::none())
+    }
+
+    fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        tracing::Instrument::instrument(future, self.0.clone())
+    }
+
+    fn record_retry(&self, attempt: u32, delay: Duration) {
+        self.0.in_scope(|| {
+            tracing::debug!(
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "retrying oss request"
+            )
+        });
+    }
+
+    fn record_result(&self, result: &Result<Response, Error>, latency: Duration, retries: u32) {
+        let span = &self.0;
+        span.record("latency_ms", latency.as_millis() as u64);
+        span.record("retries", retries);
+        match result {
+            Ok(resp) => {
+                span.record("status", resp.status().as_u16());
+                if let Some(request_id) = resp
+                    .headers()
+                    .get("x-oss-request-id")
+                    .and_then(|value| value.to_str().ok())
+                {
+                    span.record("request_id", request_id);
+                }
+            }
+            Err(error) => self.record_error(error),
+        }
+    }
+
+    fn record_body(&self, bytes_received: usize) {
+        self.0.record("bytes_received", bytes_received);
+    }
+
+    fn record_error(&self, error: &Error) {
+        self.0
+            .in_scope(|| tracing::warn!(error = %error, "oss request failed"));
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+#[derive(Debug, Clone, Default)]
+struct Span;
+
+#[cfg(not(feature = "tracing"))]
+impl Span {
+    fn new(
+        _operation: &str,
+        _method: &Method,
+        _bucket: &str,
+        _key: &str,
+        _bytes_sent: usize,
+    ) -> Self {
+        Self
+    }
+
+    fn none() -> Self {
+        Self
+    }
+
+    fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    fn record_retry(&self, _attempt: u32, _delay: Duration) {}
+
+    fn record_result(&self, _result: &Result<Response, Error>, _latency: Duration, _retries: u32) {}
+
+    fn record_body(&self, _bytes_received: usize) {}
+
+    fn record_error(&self, _error: &Error) {}
+}
+
+/// 单次API调用的追踪与指标, 随响应传递以便在读取响应体后记录
+#[derive(Debug, Clone)]
+pub(crate) struct Telemetry {
+    span: Span,
+    metrics: Option<Arc<dyn Metrics>>,
+    operation: String,
+    bucket: String,
+}
+
+impl Telemetry {
+    pub(crate) fn new(
+        metrics: Option<Arc<dyn Metrics>>,
+        operation: Option<&str>,
+        method: &Method,
+        resource: Option<&str>,
+        bytes_sent: usize,
+    ) -> Self {
+        let operation = operation.unwrap_or_default();
+        let (bucket, key) = split_resource(resource);
+        Self {
+            span: Span::new(operation, method, bucket, key, bytes_sent),
+            metrics,
+            operation: operation.to_string(),
+            bucket: bucket.to_string(),
+        }
+    }
+
+    pub(crate) fn none() -> Self {
+        Self {
+            span: Span::none(),
+            metrics: None,
+            operation: String::new(),
+            bucket: String::new(),
+        }
+    }
+
+    fn labels(&self) -> Labels<'_> {
+        Labels {
+            operation: &self.operation,
+            bucket: &self.bucket,
+        }
+    }
+
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        self.span.instrument(future)
+    }
+
+    pub(crate) fn record_attempt(
+        &self,
+        method: &Method,
+        attempt: u32,
+        result: &Result<Response, Error>,
+        latency: Duration,
+        bytes_sent: usize,
+    ) {
+        if let Some(metrics) = &self.metrics {
+            let status = result.as_ref().ok().map(|resp| resp.status());
+            metrics.record_attempt(&Attempt {
+                labels: self.labels(),
+                method,
+                attempt,
+                outcome: status.map_or(Outcome::TransportError, Outcome::from_status),
+                status,
+                latency,
+                bytes_sent: bytes_sent as u64,
+            });
+        }
+    }
+
+    pub(crate) fn record_queue_wait(&self, plane: Plane, wait: Duration) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_queue_wait(&self.labels(), plane, wait);
+        }
+    }
+
+    pub(crate) fn record_retry(&self, attempt: u32, delay: Duration) {
+        self.span.record_retry(attempt, delay);
+    }
+
+    pub(crate) fn record_result(
+        &self,
+        result: &Result<Response, Error>,
+        latency: Duration,
+        retries: u32,
+    ) {
+        self.span.record_result(result, latency, retries);
+    }
+
+    pub(crate) fn record_body(&self, bytes_received: usize) {
+        self.span.record_body(bytes_received);
+        if let Some(metrics) = &self.metrics {
+            metrics.record_bytes_received(&self.labels(), bytes_received as u64);
+        }
+    }
+
+    pub(crate) fn record_error(&self, error: &Error) {
+        self.span.record_error(error);
+        if let (Some(metrics), Some(code)) = (&self.metrics, error.code()) {
+            metrics.record_error_code(&self.labels(), &code);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn telemetry_split_resource() {
+        assert_eq!(
+            split_resource(Some("/xtoss-ex/dir/ex1.txt?acl")),
+            ("xtoss-ex", "dir/ex1.txt")
+        );
+        assert_eq!(split_resource(Some("/xtoss-ex/?cors")), ("xtoss-ex", ""));
+        assert_eq!(split_resource(None), ("", ""));
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+pub mod tracing_tests {
+    use crate::oss::{self, transport::tests::MemoryTransport};
+    use std::{
+        fmt,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    /// 记录所有span字段与事件字段
+    #[derive(Debug, Default, Clone)]
+    struct Recorder(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            span.record(&mut self.clone());
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &span::Id, values: &span::Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    #[tokio::test]
+    async fn telemetry_request_span() {
+        let recorder = Recorder::default();
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+        let client = oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_transport(MemoryTransport::default())
+            .client();
+        client.GetObject("dir/ex1.txt").execute().await.unwrap();
+
+        let fields = recorder.0.lock().unwrap();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(field("operation"), Some("\"GetObject\""));
+        assert_eq!(field("bucket"), Some("\"xtoss-ex\""));
+        assert_eq!(field("key"), Some("\"dir/ex1.txt\""));
+        assert_eq!(field("status"), Some("200"));
+        assert_eq!(field("retries"), Some("0"));
+        assert_eq!(field("bytes_received"), Some("11"));
+        assert!(field("latency_ms").is_some());
+        assert!(fields
+            .iter()
+            .all(|(_, value)| !value.contains("access_key_secret") && !value.contains("OSS ")));
+    }
+}
@@ -0,0 +1,1018 @@
+use std::{
+    error::Error as StdError,
+    fmt,
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{future::BoxFuture, stream::BoxStream, Stream, StreamExt, TryStreamExt};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf},
+    sync::OwnedSemaphorePermit,
+};
+
+use super::{
+    crc64::{self, Crc64, Crc64Mismatch},
+    http::{header::CONTENT_LENGTH, HeaderMap, Method, StatusCode, Url},
+    limiter::RateLimiter,
+    progress::ProgressSink,
+    telemetry::Telemetry,
+    Bytes, BytesMut, Error,
+};
+
+/// 响应体数据流
+pub type BodyStream = BoxStream<'static, Result<Bytes, TransportError>>;
+
+/// 请求体
+pub enum Body {
+    /// 内存中的数据
+    Bytes(Bytes),
+    /// 数据流, 例如启用上传限速时按块发送; 长度由`Content-Length`请求头给出
+    Stream(BodyStream),
+}
+
+impl Body {
+    /// 内存中的数据, 数据流返回`None`
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Bytes(bytes) => Some(bytes),
+            Self::Stream(_) => None,
+        }
+    }
+
+    pub fn into_stream(self) -> BodyStream {
+        match self {
+            Self::Bytes(bytes) => futures::stream::once(async move { Ok(bytes) }).boxed(),
+            Self::Stream(stream) => stream,
+        }
+    }
+}
+
+impl Default for Body {
+    fn default() -> Self {
+        Self::Bytes(Bytes::new())
+    }
+}
+
+impl From<Bytes> for Body {
+    fn from(value: Bytes) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl fmt::Debug for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream(_) => f.write_str("Stream(..)"),
+        }
+    }
+}
+
+impl From<Body> for reqwest::Body {
+    fn from(value: Body) -> Self {
+        match value {
+            Body::Bytes(bytes) => bytes.into(),
+            Body::Stream(stream) => reqwest::Body::wrap_stream(stream),
+        }
+    }
+}
+
+/// 最近一次发送或收到数据的时间, 用于数据流传输的空闲超时
+#[derive(Debug, Clone)]
+pub(crate) struct Activity {
+    start: Instant,
+    /// 距`start`的毫秒数
+    last: Arc<AtomicU64>,
+}
+
+impl Activity {
+    pub(crate) fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            last: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    fn touch(&self) {
+        let elapsed = self.start.elapsed().as_millis() as u64;
+        self.last.store(elapsed, Ordering::Relaxed);
+    }
+
+    fn idle(&self) -> Duration {
+        let last = Duration::from_millis(self.last.load(Ordering::Relaxed));
+        self.start.elapsed().saturating_sub(last)
+    }
+
+    /// 数据流每产生一块数据记录一次
+    pub(crate) fn track(&self, stream: BodyStream) -> BodyStream {
+        let activity = self.clone();
+        stream.inspect(move |_| activity.touch()).boxed()
+    }
+
+    /// 等待`future`完成, 连续`timeout`未发送数据时以`TransportErrorKind::Timeout`失败
+    pub(crate) async fn timeout<T>(
+        &self,
+        timeout: Duration,
+        future: impl Future<Output = Result<T, TransportError>>,
+    ) -> Result<T, TransportError> {
+        tokio::pin!(future);
+        loop {
+            let idle = self.idle();
+            if idle >= timeout {
+                let message = format!("no data transferred for {:?}", timeout);
+                return Err(TransportError::new(TransportErrorKind::Timeout, message));
+            }
+            tokio::select! {
+                result = &mut future => return result,
+                _ = tokio::time::sleep(timeout - idle) => {}
+            }
+        }
+    }
+}
+
+/// 已签名的请求, 由`Transport`负责发送
+#[derive(Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Body,
+    /// 整个请求(包括读取响应体)的超时时间, `None`时不限制总时长(大文件数据流传输)
+    pub timeout: Option<Duration>,
+}
+
+/// 传输层返回的响应, 响应体以数据流形式读取
+pub struct Response {
+    url: Url,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: BodyStream,
+    telemetry: Telemetry,
+}
+
+impl Response {
+    pub fn new(url: Url, status: StatusCode, headers: HeaderMap, body: BodyStream) -> Self {
+        Self {
+            url,
+            status,
+            headers,
+            body,
+            telemetry: Telemetry::none(),
+        }
+    }
+
+    /// 使用内存中的数据构造响应, 便于测试中模拟OSS返回
+    pub fn from_bytes(
+        url: Url,
+        status: StatusCode,
+        headers: HeaderMap,
+        body: impl Into<Bytes>,
+    ) -> Self {
+        let body: Bytes = body.into();
+        Self::new(
+            url,
+            status,
+            headers,
+            futures::stream::once(async move { Ok(body) }).boxed(),
+        )
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    pub(crate) fn telemetry(&self) -> &Telemetry {
+        &self.telemetry
+    }
+
+    pub(crate) fn with_telemetry(mut self, value: Telemetry) -> Self {
+        self.telemetry = value;
+        self
+    }
+
+    /// 按限速读取响应体
+    pub(crate) fn with_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.body = limiter.throttle(self.body);
+        self
+    }
+
+    /// 报告响应体的接收进度, 总长度取自`Content-Length`
+    pub(crate) fn with_progress(mut self, progress: ProgressSink) -> Self {
+        let total = self
+            .headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        self.body = progress.track(self.body, total);
+        self
+    }
+
+    /// 读取完响应体或丢弃响应后释放并发许可
+    pub(crate) fn with_permit(mut self, permit: OwnedSemaphorePermit) -> Self {
+        self.body = self
+            .body
+            .map(move |chunk| {
+                let _permit = &permit;
+                chunk
+            })
+            .boxed();
+        self
+    }
+
+    /// 连续`timeout`未收到响应体数据时以`TransportErrorKind::Timeout`结束数据流
+    pub(crate) fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.body = futures::stream::unfold(Some(self.body), move |body| async move {
+            let mut body = body?;
+            match tokio::time::timeout(timeout, body.next()).await {
+                Ok(Some(chunk)) => Some((chunk, Some(body))),
+                Ok(None) => None,
+                Err(_) => {
+                    let message = format!("no data received for {:?}", timeout);
+                    let error = TransportError::new(TransportErrorKind::Timeout, message);
+                    Some((Err(error), None))
+                }
+            }
+        })
+        .boxed();
+        self
+    }
+
+    /// 响应体数据流
+    pub fn into_stream(self) -> BodyStream {
+        self.body
+    }
+
+    /// 读取全部响应体
+    pub async fn bytes(self) -> Result<Bytes, TransportError> {
+        let mut body = self.body;
+        let mut buf = BytesMut::new();
+        while let Some(chunk) = body.try_next().await? {
+            buf.extend_from_slice(&chunk);
+        }
+        self.telemetry.record_body(buf.len());
+        Ok(buf.freeze())
+    }
+
+    /// 读取全部响应体并按UTF-8解码, 无效字符以`U+FFFD`替换
+    pub async fn text(self) -> Result<String, TransportError> {
+        let bytes = self.bytes().await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// 边下载边读取的响应体, 不在内存中缓存全部数据
+///
+/// 实现了`Stream<Item = Result<Bytes, TransportError>>`, 也可通过`into_async_read`转换为
+/// `AsyncRead`, 或通过`write_to`写入任意`AsyncWrite`。丢弃数据流会中断下载并释放连接
+pub struct ByteStream {
+    body: BodyStream,
+    /// 读取完成后记录接收字节数
+    telemetry: Option<Telemetry>,
+    received: usize,
+    /// 已接收数据的CRC64与响应头中的CRC64
+    crc64: Option<(Crc64, u64)>,
+}
+
+impl ByteStream {
+    pub(crate) fn new(resp: Response) -> Self {
+        Self {
+            body: resp.body,
+            telemetry: Some(resp.telemetry),
+            received: 0,
+            crc64: None,
+        }
+    }
+
+    /// 读取完成后与`server_crc`比较, 不一致时数据流最后返回错误, 转换为`Error::Crc64Mismatch`
+    pub(crate) fn with_crc64_check(mut self, server_crc: u64) -> Self {
+        self.crc64 = Some((Crc64::new(), server_crc));
+        self
+    }
+
+    /// 读取全部数据
+    pub async fn collect(mut self) -> Result<Bytes, TransportError> {
+        let mut buf = BytesMut::new();
+        while let Some(chunk) = self.try_next().await? {
+            buf.extend_from_slice(&chunk);
+        }
+        Ok(buf.freeze())
+    }
+
+    /// 写入`writer`, 返回写入的字节数
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.try_next().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+
+    pub fn into_async_read(self) -> ByteStreamReader {
+        ByteStreamReader {
+            stream: self,
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+impl Stream for ByteStream {
+    type Item = Result<Bytes, TransportError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.body.poll_next_unpin(cx));
+        match &item {
+            Some(Ok(chunk)) => {
+                self.received += chunk.len();
+                if let Some((crc, _)) = &mut self.crc64 {
+                    crc.update(chunk);
+                }
+            }
+            Some(Err(_)) => {}
+            None => {
+                if let Some(telemetry) = self.telemetry.take() {
+                    telemetry.record_body(self.received);
+                }
+                if let Some((crc, server_crc)) = self.crc64.take() {
+                    if let Err(mismatch) = crc64::check(crc.value(), server_crc) {
+                        self.body = futures::stream::empty().boxed();
+                        let error = TransportError::new(TransportErrorKind::Body, mismatch);
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                }
+            }
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteStream")
+            .field("received", &self.received)
+            .finish()
+    }
+}
+
+/// `ByteStream`的`AsyncRead`适配
+#[derive(Debug)]
+pub struct ByteStreamReader {
+    stream: ByteStream,
+    chunk: Bytes,
+}
+
+impl AsyncRead for ByteStreamReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        while self.chunk.is_empty() {
+            match ready!(self.stream.poll_next_unpin(cx)) {
+                Some(Ok(chunk)) => self.chunk = chunk,
+                Some(Err(e)) => return Poll::Ready(Err(io::Error::new(io::ErrorKind::Other, e))),
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+        let len = self.chunk.len().min(buf.remaining());
+        buf.put_slice(&self.chunk.split_to(len));
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl fmt::Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Response")
+            .field("url", &self.url)
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 传输错误类型, 用于判断是否可以重试
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// 连接未建立, 请求一定没有发送到服务端
+    Connect,
+    /// 请求超时
+    Timeout,
+    /// 发送请求失败, 例如连接被重置
+    Request,
+    /// 读取响应体失败
+    Body,
+    Other,
+}
+
+/// 网络传输错误
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn StdError + Send + Sync>,
+}
+
+impl TransportError {
+    pub fn new(
+        kind: TransportErrorKind,
+        source: impl Into<Box<dyn StdError + Send + Sync>>,
+    ) -> Self {
+        Self {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    pub fn is_connect(&self) -> bool {
+        self.kind == TransportErrorKind::Connect
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.kind == TransportErrorKind::Timeout
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.kind == TransportErrorKind::Request
+    }
+
+    pub fn is_body(&self) -> bool {
+        self.kind == TransportErrorKind::Body
+    }
+
+    /// 数据流中的CRC64校验失败
+    pub(crate) fn into_crc64_mismatch(self) -> Result<Crc64Mismatch, Self> {
+        match self.source.downcast::<Crc64Mismatch>() {
+            Ok(mismatch) => Ok(*mismatch),
+            Err(source) => Err(Self {
+                kind: self.kind,
+                source,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl StdError for TransportError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(value: reqwest::Error) -> Self {
+        let kind = if value.is_timeout() {
+            TransportErrorKind::Timeout
+        } else if value.is_connect() {
+            TransportErrorKind::Connect
+        } else if value.is_body() || value.is_decode() {
+            TransportErrorKind::Body
+        } else if value.is_request() {
+            TransportErrorKind::Request
+        } else {
+            TransportErrorKind::Other
+        };
+        Self::new(kind, value)
+    }
+}
+
+/// HTTP传输层
+///
+/// 接收已签名的请求, 返回状态码、响应头与响应体数据流。默认使用`ReqwestTransport`,
+/// 可通过`Options::with_transport`替换, 例如在测试中注入内存实现
+///
+/// ```no_run
+/// # use xt_oss::prelude::*;
+/// # use xt_oss::oss::transport::{HttpRequest, Response, Transport, TransportError};
+/// # use futures::future::BoxFuture;
+/// #[derive(Debug)]
+/// struct MockTransport;
+///
+/// impl Transport for MockTransport {
+///     fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+///         Box::pin(async move {
+///             let headers = oss::http::HeaderMap::new();
+///             Ok(Response::from_bytes(request.url, oss::http::StatusCode::OK, headers, "hello"))
+///         })
+///     }
+/// }
+///
+/// let client = oss::Options::new()
+///     .with_bucket("xtoss-ex")
+///     .with_transport(MockTransport)
+///     .client();
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>>;
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+        (**self).send(request)
+    }
+}
+
+/// TLS最低版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsVersion {
+    Tls1_0,
+    Tls1_1,
+    Tls1_2,
+    Tls1_3,
+}
+
+/// PEM格式的客户端证书与私钥
+#[derive(Clone)]
+#[cfg_attr(not(any(feature = "native-tls", feature = "rustls")), allow(dead_code))]
+struct ClientIdentity {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+}
+
+impl fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientIdentity").finish_non_exhaustive()
+    }
+}
+
+/// 代理地址与认证信息可能包含凭证, `Debug`只输出是否设置
+#[derive(Clone, Default)]
+struct ProxyOptions {
+    all: Option<String>,
+    http: Option<String>,
+    https: Option<String>,
+    auth: Option<(String, String)>,
+    no_proxy: Option<String>,
+}
+
+impl fmt::Debug for ProxyOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyOptions")
+            .field("all", &self.all.is_some())
+            .field("http", &self.http.is_some())
+            .field("https", &self.https.is_some())
+            .field("auth", &self.auth.is_some())
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+/// 默认传输层`ReqwestTransport`的代理与TLS配置, 由`Options`设置
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientOptions {
+    proxy: ProxyOptions,
+    /// PEM格式的根证书, 可包含多个证书
+    root_certificates: Vec<Vec<u8>>,
+    disable_builtin_roots: bool,
+    identity: Option<ClientIdentity>,
+    min_tls_version: Option<TlsVersion>,
+    connect_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    tcp_nodelay: Option<bool>,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: Option<bool>,
+}
+
+impl ClientOptions {
+    /// 代理所有请求, 支持`http://`、`https://`与`socks5://`(需启用`socks`特性)
+    pub(crate) fn with_proxy(mut self, value: String) -> Self {
+        self.proxy.all = Some(value);
+        self
+    }
+
+    pub(crate) fn with_http_proxy(mut self, value: String) -> Self {
+        self.proxy.http = Some(value);
+        self
+    }
+
+    pub(crate) fn with_https_proxy(mut self, value: String) -> Self {
+        self.proxy.https = Some(value);
+        self
+    }
+
+    pub(crate) fn with_proxy_auth(mut self, username: String, password: String) -> Self {
+        self.proxy.auth = Some((username, password));
+        self
+    }
+
+    pub(crate) fn with_no_proxy(mut self, value: String) -> Self {
+        self.proxy.no_proxy = Some(value);
+        self
+    }
+
+    pub(crate) fn with_root_certificate(mut self, pem: Vec<u8>) -> Self {
+        self.root_certificates.push(pem);
+        self
+    }
+
+    pub(crate) fn with_builtin_roots(mut self, value: bool) -> Self {
+        self.disable_builtin_roots = !value;
+        self
+    }
+
+    pub(crate) fn with_client_certificate(mut self, cert: Vec<u8>, key: Vec<u8>) -> Self {
+        self.identity = Some(ClientIdentity { cert, key });
+        self
+    }
+
+    pub(crate) fn with_min_tls_version(mut self, value: TlsVersion) -> Self {
+        self.min_tls_version = Some(value);
+        self
+    }
+
+    pub(crate) fn with_connect_timeout(mut self, value: Duration) -> Self {
+        self.connect_timeout = Some(value);
+        self
+    }
+
+    pub(crate) fn with_pool_max_idle_per_host(mut self, value: usize) -> Self {
+        self.pool_max_idle_per_host = Some(value);
+        self
+    }
+
+    pub(crate) fn with_pool_idle_timeout(mut self, value: Duration) -> Self {
+        self.pool_idle_timeout = Some(value);
+        self
+    }
+
+    pub(crate) fn with_tcp_keepalive(mut self, value: Duration) -> Self {
+        self.tcp_keepalive = Some(value);
+        self
+    }
+
+    pub(crate) fn with_tcp_nodelay(mut self, value: bool) -> Self {
+        self.tcp_nodelay = Some(value);
+        self
+    }
+
+    pub(crate) fn with_http2_prior_knowledge(mut self, value: bool) -> Self {
+        self.http2_prior_knowledge = value;
+        self
+    }
+
+    pub(crate) fn with_http2_adaptive_window(mut self, value: bool) -> Self {
+        self.http2_adaptive_window = Some(value);
+        self
+    }
+
+    fn proxies(&self) -> Result<Vec<reqwest::Proxy>, String> {
+        let no_proxy = self
+            .proxy
+            .no_proxy
+            .as_deref()
+            .and_then(reqwest::NoProxy::from_string);
+        let mut proxies = Vec::new();
+        if let Some(url) = &self.proxy.http {
+            proxies.push(reqwest::Proxy::http(url.as_str()));
+        }
+        if let Some(url) = &self.proxy.https {
+            proxies.push(reqwest::Proxy::https(url.as_str()));
+        }
+        if let Some(url) = &self.proxy.all {
+            proxies.push(reqwest::Proxy::all(url.as_str()));
+        }
+        proxies
+            .into_iter()
+            .map(|proxy| {
+                // 错误消息中不包含代理地址, 避免泄露其中的认证信息
+                let mut proxy = proxy.map_err(|e| format!("invalid proxy: {}", e))?;
+                if let Some((username, password)) = &self.proxy.auth {
+                    proxy = proxy.basic_auth(username, password);
+                }
+                Ok(proxy.no_proxy(no_proxy.clone()))
+            })
+            .collect()
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    fn tls(&self, mut builder: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder, String> {
+        #[cfg(feature = "rustls")]
+        {
+            builder = builder.use_rustls_tls();
+        }
+        for pem in &self.root_certificates {
+            let certificates = reqwest::Certificate::from_pem_bundle(pem)
+                .map_err(|e| format!("invalid root certificate: {}", e))?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if self.disable_builtin_roots {
+            builder = builder.tls_built_in_root_certs(false);
+        }
+        if let Some(identity) = &self.identity {
+            #[cfg(feature = "rustls")]
+            let identity =
+                reqwest::Identity::from_pem(&[&identity.cert[..], &identity.key].concat());
+            #[cfg(not(feature = "rustls"))]
+            let identity = reqwest::Identity::from_pkcs8_pem(&identity.cert, &identity.key);
+            let identity = identity.map_err(|e| format!("invalid client certificate: {}", e))?;
+            builder = builder.identity(identity);
+        }
+        if let Some(version) = self.min_tls_version {
+            builder = builder.min_tls_version(match version {
+                TlsVersion::Tls1_0 => reqwest::tls::Version::TLS_1_0,
+                TlsVersion::Tls1_1 => reqwest::tls::Version::TLS_1_1,
+                TlsVersion::Tls1_2 => reqwest::tls::Version::TLS_1_2,
+                TlsVersion::Tls1_3 => reqwest::tls::Version::TLS_1_3,
+            });
+        }
+        Ok(builder)
+    }
+
+    #[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+    fn tls(&self, builder: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder, String> {
+        match self.root_certificates.is_empty()
+            && !self.disable_builtin_roots
+            && self.identity.is_none()
+            && self.min_tls_version.is_none()
+        {
+            true => Ok(builder),
+            false => Err("TLS options require the `native-tls` or `rustls` feature".to_string()),
+        }
+    }
+
+    /// 创建`reqwest::Client`, 配置无效时返回错误消息
+    pub(crate) fn build(&self) -> Result<reqwest::Client, String> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(super::USER_AGENT)
+            .connect_timeout(
+                self.connect_timeout
+                    .unwrap_or(Duration::from_secs(super::DEFAULT_CONNECT_TIMEOUT)),
+            );
+        if let Some(value) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(value);
+        }
+        if let Some(value) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(value);
+        }
+        if let Some(value) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(value);
+        }
+        if let Some(value) = self.tcp_nodelay {
+            builder = builder.tcp_nodelay(value);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(value) = self.http2_adaptive_window {
+            builder = builder.http2_adaptive_window(value);
+        }
+        for proxy in self.proxies()? {
+            builder = builder.proxy(proxy);
+        }
+        self.tls(builder)?
+            .build()
+            .map_err(|e| format!("failed to build http client: {}", e))
+    }
+}
+
+/// 基于`reqwest`的默认传输层, 可传入预先配置好(代理、TLS根证书、连接池等)的`reqwest::Client`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        let client = ClientOptions::default().build().unwrap_or_default();
+        Self { client }
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(value: reqwest::Client) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(timeout) = request.timeout {
+                builder = builder.timeout(timeout);
+            }
+            let resp = builder.body(request.body).send().await?;
+            Ok(Response::new(
+                resp.url().clone(),
+                resp.status(),
+                resp.headers().clone(),
+                resp.bytes_stream().map_err(TransportError::from).boxed(),
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn response_read_stream() {
+        let chunks = vec![Ok(Bytes::from("hello ")), Ok(Bytes::from("world"))];
+        let resp = Response::new(
+            Url::parse("http://localhost/").unwrap(),
+            StatusCode::OK,
+            HeaderMap::new(),
+            futures::stream::iter(chunks).boxed(),
+        );
+        assert_eq!(resp.text().await.unwrap(), "hello world");
+
+        let chunks = vec![
+            Ok(Bytes::from("hello")),
+            Err(TransportError::new(TransportErrorKind::Body, "reset")),
+        ];
+        let resp = Response::new(
+            Url::parse("http://localhost/").unwrap(),
+            StatusCode::OK,
+            HeaderMap::new(),
+            futures::stream::iter(chunks).boxed(),
+        );
+        assert!(resp.bytes().await.unwrap_err().is_body());
+    }
+
+    /// 记录请求并返回固定内容的传输层
+    #[derive(Debug, Default)]
+    pub(crate) struct MemoryTransport {
+        pub(crate) requests: std::sync::Mutex<Vec<HttpRequest>>,
+    }
+
+    impl Transport for MemoryTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let url = request.url.clone();
+                self.requests.lock().unwrap().push(request);
+                Ok(Response::from_bytes(
+                    url,
+                    StatusCode::OK,
+                    HeaderMap::new(),
+                    "hello world",
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn transport_memory() {
+        let transport = std::sync::Arc::new(MemoryTransport::default());
+        let request = crate::oss::Request::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_transport(transport.clone());
+        let resp = request
+            .task()
+            .with_url("https://xtoss-ex.oss-cn-hangzhou.aliyuncs.com/ex1.txt")
+            .with_resource("/xtoss-ex/ex1.txt")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(resp.text().await.unwrap(), "hello world");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let headers = &requests[0].headers;
+        assert!(headers
+            .get(crate::oss::http::header::AUTHORIZATION)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("OSS access_key_id:"));
+        assert_eq!(
+            headers.get(crate::oss::http::header::CONTENT_TYPE).unwrap(),
+            crate::oss::DEFAULT_CONTENT_TYPE
+        );
+    }
+
+    #[tokio::test]
+    async fn transport_proxy() {
+        use axum::{extract::Request, Router};
+        let app = Router::new().fallback(|request: Request| async move {
+            format!(
+                "{} {}",
+                request.uri(),
+                request.headers()["proxy-authorization"].to_str().unwrap()
+            )
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = crate::oss::Options::new()
+            .with_access_key_id("access_key_id")
+            .with_access_key_secret("access_key_secret")
+            .with_bucket("xtoss-ex")
+            .with_proxy(format!("http://{}", addr))
+            .with_proxy_auth("user", "pass")
+            .with_no_proxy("localhost")
+            .client();
+        let data = client.GetObject("ex1.txt").execute().await.unwrap();
+        assert_eq!(
+            data.content(),
+            "http://xtoss-ex.oss-cn-hangzhou.aliyuncs.com/ex1.txt Basic dXNlcjpwYXNz"
+        );
+    }
+
+    #[tokio::test]
+    async fn transport_invalid_options() {
+        let options = crate::oss::Options::new().with_bucket("xtoss-ex");
+        for options in [
+            options.clone().with_proxy("::invalid"),
+            options
+                .clone()
+                .with_client_certificate("not a certificate", "not a key"),
+        ] {
+            let client = options.client();
+            let error = client.GetObject("ex1.txt").execute().await.unwrap_err();
+            assert!(matches!(error, crate::oss::Error::Config(_)), "{:?}", error);
+        }
+    }
+
+    /// 返回部分响应体后停止发送数据
+    #[derive(Debug)]
+    struct StalledTransport;
+
+    impl Transport for StalledTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<Response, TransportError>> {
+            Box::pin(async move {
+                let body = futures::stream::once(async { Ok(Bytes::from("hello")) })
+                    .chain(futures::stream::pending());
+                Ok(Response::new(
+                    request.url,
+                    StatusCode::OK,
+                    HeaderMap::new(),
+                    body.boxed(),
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn transport_read_timeout() {
+        let client = crate::oss::Options::new()
+            .with_bucket("xtoss-ex")
+            .with_transport(StalledTransport)
+            .with_read_timeout(Duration::from_millis(50))
+            .client();
+        let error = client.GetObject("ex1.txt").execute().await.unwrap_err();
+        match error {
+            crate::oss::Error::Transport(e) => assert!(e.is_timeout()),
+            error => panic!("{:?}", error),
+        }
+    }
+
+    #[test]
+    fn transport_client_options() {
+        let options = ClientOptions::default()
+            .with_connect_timeout(Duration::from_secs(5))
+            .with_pool_max_idle_per_host(32)
+            .with_pool_idle_timeout(Duration::from_secs(30))
+            .with_tcp_keepalive(Duration::from_secs(60))
+            .with_tcp_nodelay(true)
+            .with_http2_prior_knowledge(true)
+            .with_http2_adaptive_window(true);
+        assert!(options.build().is_ok());
+    }
+}
@@ -101,18 +101,39 @@ impl fmt::Display for AllowedHeaderItem {
     }
 }
 
-pub fn options_from_env() -> oss::Options<'static> {
+/// 从环境变量读取配置
+///
+/// 访问凭证在调用时读取一次, 未设置`OSS_*`时使用`ALIBABA_CLOUD_*`; 都未设置时为匿名访问。
+/// 需要在运行中轮换凭证时使用`with_credentials_provider(EnvironmentCredentialsProvider::new())`
+pub fn options_from_env() -> oss::Options {
     oss::Options::new()
-        .with_access_key_id(get_env("OSS_ACCESS_KEY_ID", "").leak())
-        .with_access_key_secret(get_env("OSS_ACCESS_KEY_SECRET", "").leak())
-        .with_region(get_env("OSS_REGION", oss::DEFAULT_REGION).leak())
-        .with_endpoint(get_env("OSS_ENDPOINT", "").leak())
-        .with_bucket(get_env("OSS_BUCKET", "").leak())
-        .with_sts_token(get_env("OSS_STS_TOKEN", "").leak())
+        .with_access_key_id(get_env(
+            "OSS_ACCESS_KEY_ID",
+            &get_env("ALIBABA_CLOUD_ACCESS_KEY_ID", ""),
+        ))
+        .with_access_key_secret(get_env(
+            "OSS_ACCESS_KEY_SECRET",
+            &get_env("ALIBABA_CLOUD_ACCESS_KEY_SECRET", ""),
+        ))
+        .with_region(get_env("OSS_REGION", oss::DEFAULT_REGION))
+        .with_endpoint(get_env("OSS_ENDPOINT", ""))
+        .with_bucket(get_env("OSS_BUCKET", ""))
+        .with_sts_token(get_env(
+            "OSS_STS_TOKEN",
+            &get_env("ALIBABA_CLOUD_SECURITY_TOKEN", ""),
+        ))
         .with_internal(get_env_bool("OSS_INTERNAL", false))
         .with_cname(get_env_bool("OSS_CNAME", false))
+        .with_path_style(get_env_bool("OSS_PATH_STYLE", false))
+        .with_base_domain(get_env("OSS_BASE_DOMAIN", oss::BASE_URL))
         // .with_is_request_pay(get_env_bool("OSS_IS_REQUEST_PAY", false))
         .with_secret(get_env_bool("OSS_SECURE", false))
+        .with_crc64_check(get_env_bool("OSS_CRC64_CHECK", false))
+        .with_compute_content_md5(get_env_bool("OSS_COMPUTE_CONTENT_MD5", false))
+        .with_signature_version(match get_env("OSS_SIGNATURE_VERSION", "v1").as_str() {
+            "v4" | "V4" => oss::SignatureVersion::V4,
+            _ => oss::SignatureVersion::V1,
+        })
         .with_timeout(
             get_env("OSS_TIMEOUT", "60")
                 .parse::<u64>()
@@ -145,6 +166,25 @@ pub fn oss_md5<'a>(content: &'a [u8]) -> Result<String, io::Error> {
     Ok(general_purpose::STANDARD.encode(&bytes))
 }
 
+/// 获取文件CRC64值, 与响应头`x-oss-hash-crc64ecma`相同
+pub fn oss_file_crc64(file: &str) -> Result<u64, io::Error> {
+    let mut file = File::open(file)?;
+    let mut crc = oss::Crc64::new();
+    let mut buffer = [0; 8192];
+    loop {
+        let bytes_read = file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        crc.update(&buffer[..bytes_read]);
+    }
+    Ok(crc.value())
+}
+
+pub fn oss_crc64(content: &[u8]) -> u64 {
+    oss::crc64::checksum(content)
+}
+
 /// 获取字节范围描述
 ///
 /// - `start`  开始位置